@@ -0,0 +1,162 @@
+//! Benchmarks for filtering entities before inspecting them.
+//!
+//! Compares finding matching entities per archetype with [`entities_matching_inspection_filter`]
+//! against the previous approach of checking every entity individually,
+//! in a world where only a small fraction of the entities match.
+//! [`filter_entity_list_for_inspection`], which filters an explicit list of entities, is included too.
+//! All of them apply the same component presence, name, change and predicate filters.
+//!
+//! Run with `cargo bench --bench inspect_multiple`.
+
+use bevy::prelude::*;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use feathers_inspector::{
+    entity_name_resolution::NameResolutionRegistry,
+    extension_methods::WorldInspectionExtensionTrait,
+    inspection::{
+        component_inspection::ComponentMetadataMap,
+        entity_inspection::{
+            MultipleEntityInspectionSettings, NameFilter, entities_matching_inspection_filter,
+            filter_entity_list_for_inspection,
+        },
+    },
+};
+
+#[derive(Component)]
+struct Rare;
+
+#[derive(Component)]
+struct Excluded;
+
+#[derive(Component)]
+struct A;
+
+#[derive(Component)]
+struct B;
+
+#[derive(Component)]
+struct C;
+
+/// Spawns `entity_count` entities spread over several archetypes,
+/// where one in a hundred entities has the [`Rare`] component,
+/// and every entity is named.
+fn setup_world(entity_count: usize) -> (World, Vec<Entity>, MultipleEntityInspectionSettings) {
+    let mut world = World::new();
+    // Required to generate component metadata
+    world.init_resource::<AppTypeRegistry>();
+    world.init_resource::<NameResolutionRegistry>();
+    let rare = world.register_component::<Rare>();
+    let excluded = world.register_component::<Excluded>();
+
+    for i in 0..entity_count {
+        let mut entity = world.spawn((Transform::default(), Name::new(format!("Entity {i}"))));
+        match i % 4 {
+            0 => entity.insert(A),
+            1 => entity.insert((A, B)),
+            2 => entity.insert((B, C)),
+            _ => entity.insert(Excluded),
+        };
+        if i % 100 == 0 {
+            entity.insert(Rare);
+        }
+    }
+
+    let entities = world.query::<Entity>().iter(&world).collect();
+    let settings = MultipleEntityInspectionSettings {
+        name_filter: Some(NameFilter::from("entity")),
+        with_component_filter: vec![rare],
+        without_component_filter: vec![excluded],
+        changed_component_filter: vec![rare],
+        ..default()
+    };
+    (world, entities, settings)
+}
+
+/// The previous approach: retains the entities that pass every filter, checking each entity individually.
+fn filter_per_entity(
+    world: &World,
+    entities: &mut Vec<Entity>,
+    settings: &MultipleEntityInspectionSettings,
+) {
+    let (last_run, this_run) = (world.last_change_tick(), world.read_change_tick());
+    entities.retain(|entity| {
+        let Ok(entity_ref) = world.get_entity(*entity) else {
+            return false;
+        };
+        let matches_name = settings.name_filter.as_ref().is_none_or(|filter| {
+            entity_ref
+                .get::<Name>()
+                .is_some_and(|name| filter.matches(name.as_str()))
+        });
+
+        matches_name
+            && settings
+                .with_component_filter
+                .iter()
+                .all(|id| entity_ref.contains_id(*id))
+            && !settings
+                .without_component_filter
+                .iter()
+                .any(|id| entity_ref.contains_id(*id))
+            && settings.changed_component_filter.iter().all(|id| {
+                entity_ref
+                    .get_change_ticks_by_id(*id)
+                    .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+            })
+            && settings
+                .value_predicates
+                .iter()
+                .all(|predicate| predicate.matches(world, *entity))
+    });
+}
+
+fn filter_entities(c: &mut Criterion) {
+    let mut group = c.benchmark_group("filter_entities");
+    for entity_count in [10_000, 100_000] {
+        let (world, entities, settings) = setup_world(entity_count);
+
+        group.bench_function(BenchmarkId::new("per_archetype", entity_count), |b| {
+            b.iter(|| entities_matching_inspection_filter(&world, &settings));
+        });
+        group.bench_function(BenchmarkId::new("entity_list", entity_count), |b| {
+            b.iter(|| {
+                let mut entities = entities.clone();
+                filter_entity_list_for_inspection(&world, &mut entities, &settings);
+                entities
+            });
+        });
+        group.bench_function(BenchmarkId::new("per_entity", entity_count), |b| {
+            b.iter(|| {
+                let mut entities = entities.clone();
+                filter_per_entity(&world, &mut entities, &settings);
+                entities
+            });
+        });
+    }
+    group.finish();
+}
+
+fn inspect_multiple(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inspect_multiple");
+    for entity_count in [10_000, 100_000] {
+        let (world, entities, settings) = setup_world(entity_count);
+        let mut metadata_map = ComponentMetadataMap::generate(&world);
+
+        group.bench_function(BenchmarkId::new("inspect_matching", entity_count), |b| {
+            b.iter(|| world.inspect_matching(settings.clone(), &mut metadata_map));
+        });
+        group.bench_function(BenchmarkId::new("inspect_multiple", entity_count), |b| {
+            b.iter(|| {
+                world.inspect_multiple(
+                    entities.iter().copied(),
+                    settings.clone(),
+                    &mut metadata_map,
+                )
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, filter_entities, inspect_multiple);
+criterion_main!(benches);
@@ -16,13 +16,13 @@ use feathers_inspector::inspection::component_inspection::{
     ComponentTypeInspection,
 };
 use feathers_inspector::inspection::entity_inspection::{
-    EntityInspection, EntityInspectionError, EntityInspectionSettings,
-    MultipleEntityInspectionSettings,
+    EntityInspectionPage, EntityInspectionSettings, MultipleEntityInspectionSettings,
 };
 use feathers_inspector::inspection::resource_inspection::{
     ResourceInspection, ResourceInspectionSettings,
 };
 use feathers_inspector::inspection::world_summary::{SummarySettings, WorldSummary};
+use feathers_inspector::memory_size::MemoryDetailLevel;
 
 use crate::helper::{construct_request, post_request, query};
 
@@ -113,11 +113,10 @@ fn inspect_all_entities_when_space_pressed(
             entities: entities.into_iter().collect::<Vec<Entity>>(),
             settings,
             metadata_map,
+            query: None,
         };
         let request = construct_request(brp::inspect_multiple::METHOD, params);
-        let inspections = post_request::<Vec<Result<EntityInspection, EntityInspectionError>>>(
-            request, &brp_url.0,
-        );
+        let inspections = post_request::<EntityInspectionPage>(request, &brp_url.0);
 
         for result in inspections {
             if let Ok(inspection) = result {
@@ -147,6 +146,7 @@ fn inspect_specific_component_when_c_pressed(
             detail_level: ComponentDetailLevel::Values,
             full_type_names: true,
             store_reflected_value: false,
+            memory_detail_level: MemoryDetailLevel::Deep,
         };
         for entity in entities {
             let params = brp::inspect_component::Params {
@@ -169,6 +169,7 @@ fn inspect_resource_when_r_pressed(
     if keyboard_input.just_pressed(KeyCode::KeyR) {
         let settings = ResourceInspectionSettings {
             full_type_names: true,
+            ..default()
         };
         let params = brp::inspect_resource::Params {
             component_name: TIME_RESOURCE_NAME.to_string(),
@@ -188,6 +189,7 @@ fn inspect_all_resources_when_a_pressed(
     if keyboard_input.just_pressed(KeyCode::KeyA) {
         let settings = ResourceInspectionSettings {
             full_type_names: false,
+            ..default()
         };
         let params = brp::inspect_all_resources::Params { settings };
         let request = construct_request(brp::inspect_all_resources::METHOD, params);
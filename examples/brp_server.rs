@@ -11,12 +11,20 @@ use bevy::{
     prelude::*,
     remote::{RemotePlugin, http::RemoteHttpPlugin},
 };
-use feathers_inspector::{brp::InspectorBrpPlugin, entity_name_resolution::NameResolutionPlugin};
+use feathers_inspector::{
+    brp::InspectorBrpPlugin, entity_categories::CategoryPlugin,
+    entity_name_resolution::NameResolutionPlugin,
+    inspection::message_inspection::MessageInspectionPlugin,
+};
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(NameResolutionPlugin)
+        .add_plugins((
+            NameResolutionPlugin,
+            CategoryPlugin,
+            MessageInspectionPlugin,
+        ))
         .add_plugins((
             RemotePlugin::default(),
             RemoteHttpPlugin::default(),
@@ -5,10 +5,17 @@
 
 use bevy::prelude::*;
 use feathers_inspector::{
+    entity_categories::{CategoryPlugin, CategoryRegistry},
     entity_name_resolution::{
         NameDefinitionPriority, NameResolutionPlugin, NameResolutionRegistry,
     },
+    extension_methods::OneShotSystemExtensionTrait,
     gui::{config::InspectorConfig, plugin::InspectorWindowPlugin},
+    inspection::{
+        change_profiler::ChangeProfilerPlugin, message_inspection::MessageInspectionPlugin,
+        schedule_inspection::ScheduleInspectionPlugin, state_inspection::StateInspectionPlugin,
+        summary_history::SummaryHistoryPlugin,
+    },
 };
 
 fn main() {
@@ -16,7 +23,17 @@ fn main() {
 
     app.add_plugins(DefaultPlugins)
         // NOTE: will not be required once this crate is upstreamed
-        .add_plugins(NameResolutionPlugin)
+        .add_plugins((
+            NameResolutionPlugin,
+            CategoryPlugin,
+            ScheduleInspectionPlugin,
+            MessageInspectionPlugin,
+            StateInspectionPlugin,
+        ))
+        // Records the entity count over time, shown in the Trends tab
+        .add_plugins(SummaryHistoryPlugin)
+        // Counts how often each component type is changed, shown in the Changes tab
+        .add_plugins(ChangeProfilerPlugin)
         // Add the inspector window plugin
         .add_plugins(InspectorWindowPlugin)
         .insert_resource(InspectorConfig {
@@ -24,6 +41,9 @@ fn main() {
             open_on_startup: true,
             ..default()
         })
+        // States registered for reflection can be inspected and changed in the States tab
+        .init_state::<DemoState>()
+        .register_type_mutable_state::<DemoState>()
         .add_systems(Startup, setup)
         .add_systems(Update, fluctuating_entity_counts);
 
@@ -31,8 +51,14 @@ fn main() {
     let mut name_registry = app.world_mut().resource_mut::<NameResolutionRegistry>();
     name_registry.register_name_defining_type::<Chaff>(NameDefinitionPriority::USER);
 
-    // Register a one-shot system to be shown in the inspector
-    let one_shot_system_id = app.world_mut().register_system(example_one_shot_system);
+    // Categories get their own tab in the object list
+    let mut category_registry = app.world_mut().resource_mut::<CategoryRegistry>();
+    category_registry.register_component_category::<Chaff>("Chaff");
+
+    // Register a one-shot system to be shown (and run) in the inspector
+    let one_shot_system_id = app
+        .world_mut()
+        .register_inspected_system(example_one_shot_system);
     app.world_mut()
         .entity_mut(one_shot_system_id.entity())
         .insert(Name::new("Example One-Shot System"));
@@ -95,6 +121,7 @@ The inspector window shows:
 - Components tab with reflected values
 - Relationships tab showing parent/child hierarchy
 - One-Shot systems tab showing registered systems
+- States tab to view and change the current state
 - Click entities in the Relationships tab to navigate"
         .to_string();
 
@@ -113,6 +140,15 @@ The inspector window shows:
     ));
 }
 
+/// A state that can be changed from the States tab of the inspector.
+#[derive(States, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+enum DemoState {
+    #[default]
+    Idle,
+    Running,
+    Paused,
+}
+
 /// Marker component for entities that should be
 /// spawned and despawned dynamically in [`fluctuating_entity_counts`].
 #[derive(Component)]
@@ -138,7 +174,7 @@ fn fluctuating_entity_counts(
     }
 }
 
-/// One-shot system registered just to be shown in the inspector.
+/// One-shot system registered to be shown and run from the inspector.
 fn example_one_shot_system() {
     info!("This is an example one-shot system.");
 }
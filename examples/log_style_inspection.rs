@@ -6,21 +6,30 @@
 
 use bevy::prelude::*;
 use feathers_inspector::{
+    entity_categories::CategoryPlugin,
     entity_name_resolution::NameResolutionPlugin,
     extension_methods::{
         CommandsExtensionTrait, EntityCommandsInspectionTrait, WorldInspectionExtensionTrait,
     },
     inspection::component_inspection::{ComponentInspectionSettings, ComponentMetadataMap},
     inspection::entity_inspection::{EntityInspectionSettings, MultipleEntityInspectionSettings},
+    inspection::message_inspection::{MessageInspectionPlugin, MessageInspectionSettings},
     inspection::resource_inspection::ResourceInspectionSettings,
+    inspection::schedule_inspection::ScheduleInspectionPlugin,
     inspection::world_summary::{CommandsSummaryExt, SummarySettings},
+    memory_size::MemoryDetailLevel,
 };
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         // NOTE: will not be required once this crate is upstreamed
-        .add_plugins(NameResolutionPlugin)
+        .add_plugins((
+            NameResolutionPlugin,
+            CategoryPlugin,
+            ScheduleInspectionPlugin,
+            MessageInspectionPlugin,
+        ))
         .add_systems(Startup, setup)
         .add_systems(
             Update,
@@ -32,6 +41,8 @@ fn main() {
                 inspect_specific_component_when_c_pressed,
                 inspect_all_entities_when_space_pressed,
                 summarize_when_s_pressed,
+                inspect_schedules_when_l_pressed,
+                inspect_messages_when_i_pressed,
             ),
         )
         .run();
@@ -53,7 +64,9 @@ Press 'A' to inspect all resources
 Press 'C' to inspect the Sprite component on all Sprite entities
 Press `Space` to inspect all entities
 Press 'M' to inspect the Sprite component type metadata
-Press 'S' to obtain summary statistics"
+Press 'S' to obtain summary statistics
+Press 'L' to list the systems in each schedule
+Press 'I' to list the buffered messages, including this key press"
         .to_string();
 
     commands.spawn((
@@ -91,11 +104,7 @@ fn inspect_all_entities_when_space_pressed(
         .resource::<ButtonInput<KeyCode>>()
         .just_pressed(KeyCode::Space)
     {
-        let mut entity_query = world.try_query::<Entity>().unwrap();
-        let entities = entity_query.iter(world);
-
-        let inspection_results = world.inspect_multiple(
-            entities,
+        let inspection_results = world.inspect_matching(
             MultipleEntityInspectionSettings::default(),
             &mut metadata_map,
         );
@@ -116,6 +125,8 @@ fn inspect_resource_when_r_pressed(
     if keyboard_input.just_pressed(KeyCode::KeyR) {
         commands.inspect_resource::<Time>(ResourceInspectionSettings {
             full_type_names: true,
+            // Measure the heap memory owned by the resource as well
+            memory_detail_level: MemoryDetailLevel::Deep,
         });
     }
 }
@@ -160,3 +171,30 @@ fn inspect_sprite_component_type_when_m_pressed(
         });
     }
 }
+
+fn inspect_schedules_when_l_pressed(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyL) {
+        // Commands are applied while `Update` is running, so it will not be listed
+        commands.queue(|world: &mut World| {
+            for schedule_inspection in world.inspect_schedules() {
+                info!("{schedule_inspection}");
+            }
+        });
+    }
+}
+
+fn inspect_messages_when_i_pressed(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyI) {
+        commands.queue(|world: &mut World| {
+            for message_inspection in world.inspect_messages(MessageInspectionSettings::default()) {
+                info!("{message_inspection}");
+            }
+        });
+    }
+}
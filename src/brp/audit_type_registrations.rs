@@ -0,0 +1,24 @@
+//! Handles a `world.audit_type_registrations` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult},
+};
+use serde_json::Value;
+
+use crate::extension_methods::WorldInspectionExtensionTrait;
+
+pub const METHOD: &str = "world.audit_type_registrations";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+pub fn process_remote_request(In(_params): In<Option<Value>>, world: &World) -> BrpResult {
+    let missing = world.audit_type_registrations();
+    serde_json::to_value(missing).map_err(BrpError::internal)
+}
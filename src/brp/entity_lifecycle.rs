@@ -0,0 +1,31 @@
+//! Handles a `world.entity_lifecycle` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::extension_methods::WorldInspectionExtensionTrait;
+
+pub const METHOD: &str = "world.entity_lifecycle";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    pub entity: Entity,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params { entity } = parse_some(params)?;
+    let records = world.entity_lifecycle(entity);
+    serde_json::to_value(records).map_err(BrpError::internal)
+}
@@ -0,0 +1,94 @@
+//! Handles a `world.export_relationship_graph` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some, error_codes},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::inspection::{
+    component_inspection::ComponentMetadataMap,
+    graph_export::{ExportedGraph, GraphExportError, GraphFormat, RelationshipGraphSettings},
+};
+
+pub const METHOD: &str = "world.export_relationship_graph";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    /// The entity at the root of the exported subtree.
+    pub root: Entity,
+    /// The full type name of the relationship target component to follow.
+    ///
+    /// If `None`, the entity hierarchy is followed through [`Children`].
+    pub relationship: Option<String>,
+    /// The text format of the exported graph.
+    #[serde(default)]
+    pub format: GraphFormat,
+    #[serde(default)]
+    pub settings: RelationshipGraphSettings,
+    pub metadata_map: Option<ComponentMetadataMap>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Response {
+    /// The graph, written in the requested format.
+    pub text: String,
+    /// Whether entities were left out because [`RelationshipGraphSettings::max_nodes`] was reached.
+    pub truncated: bool,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params {
+        root,
+        relationship,
+        format,
+        settings,
+        metadata_map,
+    } = parse_some(params)?;
+
+    let graph = match relationship {
+        Some(component_name) => {
+            let metadata_map =
+                metadata_map.unwrap_or_else(|| ComponentMetadataMap::generate(world));
+            let Some((component_id, _)) =
+                metadata_map.get_component_metadata_by_name(&component_name)
+            else {
+                return Err(super::component_name_not_in_metadata_brp_error(
+                    &component_name,
+                ));
+            };
+            ExportedGraph::relationships(world, root, component_id, settings)
+        }
+        None => ExportedGraph::hierarchy(world, root, settings),
+    }
+    .map_err(determine_error)?;
+
+    let response = Response {
+        text: graph.export(format),
+        truncated: graph.truncated,
+    };
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+fn determine_error(error: GraphExportError) -> BrpError {
+    let message = error.to_string();
+    match error {
+        GraphExportError::EntityNotFound(entity) => BrpError::entity_not_found(entity),
+        GraphExportError::ComponentNotFound(_) | GraphExportError::NotARelationshipTarget(_) => {
+            BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message,
+                data: None,
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Handles a `world.export_required_components_graph` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::inspection::{
+    component_inspection::ComponentMetadataMap,
+    graph_export::{ExportedGraph, GraphFormat},
+};
+
+pub const METHOD: &str = "world.export_required_components_graph";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    /// The full type names of the component types to start from.
+    pub component_names: Vec<String>,
+    /// The text format of the exported graph.
+    #[serde(default)]
+    pub format: GraphFormat,
+    pub metadata_map: Option<ComponentMetadataMap>,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params {
+        component_names,
+        format,
+        metadata_map,
+    } = parse_some(params)?;
+    let metadata_map = metadata_map.unwrap_or_else(|| ComponentMetadataMap::generate(world));
+    let component_ids = component_names
+        .iter()
+        .map(|component_name| {
+            metadata_map
+                .get_component_metadata_by_name(component_name)
+                .map(|(component_id, _)| component_id)
+                .ok_or_else(|| super::component_name_not_in_metadata_brp_error(component_name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let graph = ExportedGraph::required_components(world, &component_ids);
+    serde_json::to_value(graph.export(format)).map_err(BrpError::internal)
+}
@@ -8,7 +8,9 @@ use serde_json::Value;
 
 use crate::{
     brp::fuzzy_id_to_name_result,
-    entity_name_resolution::fuzzy_name_mapping::fuzzy_component_name_to_id,
+    entity_name_resolution::fuzzy_name_mapping::{
+        DEFAULT_FUZZY_MATCH_THRESHOLD, fuzzy_component_name_to_id,
+    },
     inspection::component_inspection::ComponentMetadataMap,
 };
 
@@ -34,6 +36,8 @@ pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> B
         fuzzy_name,
         metadata_map,
     } = parse_some(params)?;
-    let matched_id = fuzzy_component_name_to_id(world, &fuzzy_name);
+    let matched_id = fuzzy_component_name_to_id(world, &fuzzy_name, DEFAULT_FUZZY_MATCH_THRESHOLD)
+        .first()
+        .map(|(_score, id)| *id);
     fuzzy_id_to_name_result(world, &fuzzy_name, matched_id, metadata_map)
 }
@@ -0,0 +1,52 @@
+//! Handles a `world.inspect_archetype` request coming from a client.
+use bevy::{
+    ecs::archetype::ArchetypeId,
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some, error_codes},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    extension_methods::WorldInspectionExtensionTrait,
+    inspection::archetype_inspection::{ArchetypeInspectionError, ArchetypeInspectionSettings},
+};
+
+pub const METHOD: &str = "world.inspect_archetype";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    #[serde(with = "crate::serde_conversions::archetype_id")]
+    pub archetype: ArchetypeId,
+    pub settings: ArchetypeInspectionSettings,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params {
+        archetype,
+        settings,
+    } = parse_some(params)?;
+    match world.inspect_archetype(archetype, settings) {
+        Ok(inspection) => serde_json::to_value(inspection).map_err(BrpError::internal),
+        Err(error) => Err(determine_error(error)),
+    }
+}
+
+fn determine_error(error: ArchetypeInspectionError) -> BrpError {
+    match error {
+        ArchetypeInspectionError::ArchetypeNotFound(_) => BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: error.to_string(),
+            data: None,
+        },
+    }
+}
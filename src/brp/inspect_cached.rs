@@ -1,7 +1,7 @@
 //! Handles a `world.inspect_cached` request coming from a client.
 use bevy::{
     prelude::*,
-    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+    remote::{BrpError, BrpResult, builtin_methods::parse_some, error_codes},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -50,6 +50,11 @@ pub(super) fn determine_error(entity: Entity, inspection_error: EntityInspection
     use bevy::ecs::query::QueryEntityError::*;
     match inspection_error {
         EntityNotFound(_) => BrpError::entity_not_found(entity),
+        Despawned(record) => BrpError {
+            code: error_codes::ENTITY_NOT_FOUND,
+            message: record.to_string(),
+            data: serde_json::to_value(record).ok(),
+        },
         UnexpectedQueryError(query_entity_error) => match query_entity_error {
             QueryDoesNotMatch(_, _) => {
                 BrpError::internal("Reached invalid state: `QueryDoesNotMatch` on `SpawnDetails`")
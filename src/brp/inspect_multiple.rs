@@ -1,4 +1,9 @@
 //! Handles a `world.inspect_multiple` request coming from a client.
+//!
+//! Entities can be filtered using a textual [`EntityQuery`] in addition to the provided settings.
+//! The response is an [`EntityInspectionPage`](crate::inspection::entity_inspection::EntityInspectionPage):
+//! use [`MultipleEntityInspectionSettings::offset`] and [`MultipleEntityInspectionSettings::limit`]
+//! to request large result sets one page at a time.
 use bevy::{
     prelude::*,
     remote::{BrpError, BrpResult, builtin_methods::parse_some},
@@ -10,6 +15,7 @@ use crate::{
     extension_methods::WorldInspectionExtensionTrait,
     inspection::component_inspection::ComponentMetadataMap,
     inspection::entity_inspection::MultipleEntityInspectionSettings,
+    inspection::entity_query::EntityQuery,
 };
 
 pub const METHOD: &str = "world.inspect_multiple";
@@ -28,14 +34,26 @@ pub struct Params {
     pub entities: Vec<Entity>,
     pub settings: MultipleEntityInspectionSettings,
     pub metadata_map: ComponentMetadataMap,
+    /// An optional [`EntityQuery`] string, like `with:Sprite without:Camera where:Health.0==0`.
+    ///
+    /// The filters it describes are added to those in `settings`.
+    #[serde(default)]
+    pub query: Option<String>,
 }
 
 pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
     let Params {
         entities,
-        settings,
+        mut settings,
         mut metadata_map,
+        query,
     } = parse_some(params)?;
+    if let Some(query) = query {
+        EntityQuery::parse(&query)
+            .map_err(Into::into)
+            .and_then(|parsed| parsed.apply(world, &mut settings))
+            .map_err(|err| super::invalid_entity_query_brp_error(&query, err))?;
+    }
     let inspection = world.inspect_multiple(entities, settings, &mut metadata_map);
     serde_json::to_value(inspection).map_err(BrpError::internal)
 }
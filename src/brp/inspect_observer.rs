@@ -0,0 +1,45 @@
+//! Handles a `world.inspect_observer` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    extension_methods::WorldInspectionExtensionTrait,
+    inspection::observer_inspection::ObserverInspectionError,
+};
+
+pub const METHOD: &str = "world.inspect_observer";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    pub entity: Entity,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params { entity } = parse_some(params)?;
+    match world.inspect_observer(entity) {
+        Ok(inspection) => serde_json::to_value(inspection).map_err(BrpError::internal),
+        Err(error) => Err(determine_error(entity, error)),
+    }
+}
+
+fn determine_error(entity: Entity, error: ObserverInspectionError) -> BrpError {
+    match error {
+        ObserverInspectionError::EntityNotFound(_) => BrpError::entity_not_found(entity),
+        ObserverInspectionError::NotAnObserver(_) => {
+            BrpError::component_error(format!("Entity {entity} is not an observer"))
+        }
+    }
+}
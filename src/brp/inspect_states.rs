@@ -0,0 +1,34 @@
+//! Handles a `world.inspect_states` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    extension_methods::WorldInspectionExtensionTrait,
+    inspection::state_inspection::StateInspectionSettings,
+};
+
+pub const METHOD: &str = "world.inspect_states";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    pub settings: StateInspectionSettings,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params { settings } = parse_some(params)?;
+    let inspections = world.inspect_states(settings);
+    serde_json::to_value(inspections).map_err(BrpError::internal)
+}
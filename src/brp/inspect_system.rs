@@ -0,0 +1,45 @@
+//! Handles a `world.inspect_system` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    extension_methods::WorldInspectionExtensionTrait,
+    inspection::system_inspection::SystemInspectionError,
+};
+
+pub const METHOD: &str = "world.inspect_system";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    pub entity: Entity,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params { entity } = parse_some(params)?;
+    match world.inspect_system(entity) {
+        Ok(inspection) => serde_json::to_value(inspection).map_err(BrpError::internal),
+        Err(error) => Err(determine_error(entity, error)),
+    }
+}
+
+pub(super) fn determine_error(entity: Entity, error: SystemInspectionError) -> BrpError {
+    match error {
+        SystemInspectionError::EntityNotFound(_) => BrpError::entity_not_found(entity),
+        SystemInspectionError::NotASystem(_) => {
+            BrpError::component_error(format!("Entity {entity} is not a one-shot system"))
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Handles a `world.inspect_type_registry` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::extension_methods::WorldInspectionExtensionTrait;
+
+pub const METHOD: &str = "world.inspect_type_registry";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    /// Only types whose path contains this text, ignoring case, are returned.
+    ///
+    /// If `None`, every registered type is returned.
+    pub filter: Option<String>,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params { filter } = parse_some(params)?;
+    let filter = filter.map(|filter| filter.to_lowercase());
+    let inspections: Vec<_> = world
+        .inspect_type_registry()
+        .into_iter()
+        .filter(|inspection| {
+            filter
+                .as_ref()
+                .is_none_or(|filter| inspection.type_path.to_lowercase().contains(filter))
+        })
+        .collect();
+    serde_json::to_value(inspections).map_err(BrpError::internal)
+}
@@ -17,19 +17,34 @@ use bevy::{
 };
 use serde_json::Value;
 
-use crate::inspection::component_inspection::ComponentMetadataMap;
+use crate::inspection::{
+    component_inspection::ComponentMetadataMap, entity_query::EntityQueryError,
+};
 
+pub mod audit_type_registrations;
 pub mod component_metadata_map_generate;
+pub mod entity_lifecycle;
+pub mod export_relationship_graph;
+pub mod export_required_components_graph;
 pub mod fuzzy_component_name_to_name;
 pub mod fuzzy_resource_name_to_name;
 pub mod inspect;
 pub mod inspect_all_resources;
+pub mod inspect_archetype;
 pub mod inspect_cached;
 pub mod inspect_component;
 pub mod inspect_component_type;
+pub mod inspect_messages;
 pub mod inspect_multiple;
+pub mod inspect_observer;
 pub mod inspect_resource;
+pub mod inspect_states;
+pub mod inspect_system;
+pub mod inspect_type_registry;
+pub mod queue_state_transition;
+pub mod run_one_shot_system;
 pub mod summarize_world;
+pub mod summary_history;
 
 /// Provides BRP verbs for calling functions and methods defined in this crate.
 ///
@@ -43,34 +58,53 @@ pub struct InspectorBrpPlugin;
 
 impl Plugin for InspectorBrpPlugin {
     fn build(&self, app: &mut App) {
+        // Plugin tuples are limited in size, so the verbs are split into groups
         app.add_plugins((
+            audit_type_registrations::VerbPlugin,
             component_metadata_map_generate::VerbPlugin,
+            entity_lifecycle::VerbPlugin,
+            export_relationship_graph::VerbPlugin,
+            export_required_components_graph::VerbPlugin,
             fuzzy_component_name_to_name::VerbPlugin,
             fuzzy_resource_name_to_name::VerbPlugin,
             inspect::VerbPlugin,
             inspect_all_resources::VerbPlugin,
+            inspect_archetype::VerbPlugin,
             inspect_cached::VerbPlugin,
             inspect_component::VerbPlugin,
             inspect_component_type::VerbPlugin,
             inspect_multiple::VerbPlugin,
+        ))
+        .add_plugins((
+            inspect_messages::VerbPlugin,
+            inspect_observer::VerbPlugin,
             inspect_resource::VerbPlugin,
+            inspect_states::VerbPlugin,
+            inspect_system::VerbPlugin,
+            inspect_type_registry::VerbPlugin,
+            queue_state_transition::VerbPlugin,
+            run_one_shot_system::VerbPlugin,
             summarize_world::VerbPlugin,
+            summary_history::VerbPlugin,
         ));
     }
 }
 
 /// Registers an instant BRP method system under the given `method` name.
 ///
+/// The system may be exclusive (taking `&mut World`) if it needs to modify the world,
+/// for example to run a system.
+///
 /// ## Panics
 ///
 /// - If the [`RemotePlugin`] hasn't been added to the app
 ///   (i.e., [`RemoteMethods`] resource is missing).
 ///
 /// [`RemotePlugin`]: bevy::remote::RemotePlugin
-pub(crate) fn register_remote_method(
+pub(crate) fn register_remote_method<M>(
     world: &mut World,
     method: &str,
-    system: fn(bevy::prelude::In<Option<Value>>, &World) -> BrpResult,
+    system: impl IntoSystem<In<Option<Value>>, BrpResult, M> + 'static,
 ) {
     let system_id = world.register_system(system);
 
@@ -88,6 +122,10 @@ pub mod error_codes {
     ///
     /// [`ComponentMetadataMap`]: crate::inspection::component_inspection::ComponentMetadataMap
     pub const COMPONENT_NAME_NOT_IN_METADATA: i16 = 2;
+    /// A textual [`EntityQuery`] could not be parsed or resolved.
+    ///
+    /// [`EntityQuery`]: crate::inspection::entity_query::EntityQuery
+    pub const INVALID_ENTITY_QUERY: i16 = 3;
 }
 
 /// Resolves a fuzzy id lookup result into a BRP response carrying the matched type's name.
@@ -129,3 +167,12 @@ fn component_name_not_in_metadata_brp_error(component_name: &str) -> BrpError {
         data,
     }
 }
+
+fn invalid_entity_query_brp_error(query: &str, error: EntityQueryError) -> BrpError {
+    let data = serde_json::to_value(&error).ok();
+    BrpError {
+        code: error_codes::INVALID_ENTITY_QUERY,
+        message: format!("Invalid entity query \"{query}\": {error}"),
+        data,
+    }
+}
@@ -0,0 +1,47 @@
+//! Handles a `world.queue_state_transition` request coming from a client.
+//!
+//! Only unit variants of states registered using
+//! [`register_type_mutable_state`](bevy::state::app::AppExtStates::register_type_mutable_state)
+//! can be transitioned to this way.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    extension_methods::WorldInspectionExtensionTrait,
+    inspection::state_inspection::StateTransitionError,
+};
+
+pub const METHOD: &str = "world.queue_state_transition";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    /// The full or short type name of the state type.
+    pub state: String,
+    /// The name of the unit variant to transition to.
+    pub variant: String,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let Params { state, variant } = parse_some(params)?;
+    match world.queue_state_transition(&state, &variant) {
+        Ok(()) => Ok(Value::Null),
+        Err(
+            error @ (StateTransitionError::NotConstructible(_)
+            | StateTransitionError::TypeRegistryMissing),
+        ) => Err(BrpError::internal(error)),
+        Err(error) => Err(BrpError::resource_error(error)),
+    }
+}
@@ -0,0 +1,39 @@
+//! Handles a `world.run_one_shot_system` request coming from a client.
+//!
+//! Only systems without input or output can be run this way.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    brp::inspect_system::determine_error, extension_methods::OneShotSystemExtensionTrait,
+    inspection::system_inspection::RunOneShotSystemError,
+};
+
+pub const METHOD: &str = "world.run_one_shot_system";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    pub entity: Entity,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let Params { entity } = parse_some(params)?;
+    match world.run_one_shot_system(entity) {
+        Ok(()) => Ok(Value::Null),
+        Err(RunOneShotSystemError::Inspection(error)) => Err(determine_error(entity, error)),
+        Err(error) => Err(BrpError::internal(error)),
+    }
+}
@@ -0,0 +1,52 @@
+//! Handles a `world.summary_history` request coming from a client.
+use bevy::{
+    prelude::*,
+    remote::{BrpError, BrpResult, builtin_methods::parse_some},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::inspection::summary_history::{SummaryHistory, SummarySample, SummaryTrend};
+
+pub const METHOD: &str = "world.summary_history";
+
+pub(crate) struct VerbPlugin;
+
+impl Plugin for VerbPlugin {
+    fn build(&self, app: &mut App) {
+        let world = app.world_mut();
+        super::register_remote_method(world, METHOD, process_remote_request);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Params {
+    /// Whether to include every recorded sample in the response, rather than only the trend.
+    pub include_samples: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Response {
+    /// The recorded samples, oldest first.
+    ///
+    /// Empty unless [`Params::include_samples`] is set.
+    pub samples: Vec<SummarySample>,
+    /// The trend across the recorded samples, if at least two samples have been recorded.
+    pub trend: Option<SummaryTrend>,
+}
+
+pub fn process_remote_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let Params { include_samples } = parse_some(params)?;
+    let history = world.get_resource::<SummaryHistory>().ok_or_else(|| {
+        BrpError::resource_error("`SummaryHistory` not found: add the `SummaryHistoryPlugin`")
+    })?;
+    let response = Response {
+        samples: if include_samples {
+            history.samples().cloned().collect()
+        } else {
+            Vec::new()
+        },
+        trend: history.trend(),
+    };
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
@@ -0,0 +1,273 @@
+//! User-extensible categories for sorting entities into broad groups, like "UI" or "Audio".
+//!
+//! Categories are defined by registering rules in the [`CategoryRegistry`] resource,
+//! typically in the plugin that defines the relevant components.
+//! An entity belongs to every category with at least one matching rule,
+//! so entities can be part of several categories at once (or none at all).
+//!
+//! Categories are reported in [`EntityInspection::categories`](crate::inspection::entity_inspection::EntityInspection::categories),
+//! can be used to filter entities via [`MultipleEntityInspectionSettings::category_filter`](crate::inspection::entity_inspection::MultipleEntityInspectionSettings::category_filter),
+//! and each registered category gets its own tab in the inspector's object list.
+
+use bevy::audio::{AudioSink, SpatialListener};
+use bevy::prelude::*;
+use bevy::window::Monitor;
+use core::any::TypeId;
+use core::fmt::Display;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// A named category of entities, such as [`EntityCategory::UI`].
+///
+/// For convenience, [`From`] has been implemented for [`String`] and `&'static str`,
+/// so you can construct a category using `EntityCategory::from("Physics")`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityCategory(pub Cow<'static, str>);
+
+impl EntityCategory {
+    /// User interface entities, such as [`Node`]s.
+    pub const UI: Self = Self::new_static("UI");
+    /// Entities which play or listen to sounds.
+    pub const AUDIO: Self = Self::new_static("Audio");
+    /// Cameras.
+    pub const CAMERAS: Self = Self::new_static("Cameras");
+    /// Light sources.
+    pub const LIGHTS: Self = Self::new_static("Lights");
+    /// Visible 2D and 3D objects, such as sprites and meshes.
+    pub const RENDERING: Self = Self::new_static("Rendering");
+    /// Windows and monitors.
+    pub const WINDOWING: Self = Self::new_static("Windowing");
+
+    /// Creates a category with a name known at compile time.
+    pub const fn new_static(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    /// The name of this category.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for EntityCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for EntityCategory {
+    fn from(value: String) -> Self {
+        Self(Cow::Owned(value))
+    }
+}
+
+impl From<&'static str> for EntityCategory {
+    fn from(value: &'static str) -> Self {
+        Self::new_static(value)
+    }
+}
+
+/// A rule which decides whether an entity belongs to a category.
+#[derive(Clone)]
+pub enum CategoryRule {
+    /// Matches entities that have all of the component types.
+    Components(Vec<TypeId>),
+    /// Matches entities for which the function returns `true`.
+    Predicate(Arc<dyn Fn(EntityRef) -> bool + Send + Sync>),
+}
+
+impl CategoryRule {
+    /// Checks whether the entity satisfies this rule.
+    pub fn matches(&self, entity: EntityRef) -> bool {
+        match self {
+            Self::Components(type_ids) => type_ids
+                .iter()
+                .all(|type_id| entity.contains_type_id(*type_id)),
+            Self::Predicate(predicate) => predicate(entity),
+        }
+    }
+}
+
+impl core::fmt::Debug for CategoryRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Components(type_ids) => f.debug_tuple("Components").field(type_ids).finish(),
+            Self::Predicate(_) => f.debug_tuple("Predicate").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Stores the rules used to sort entities into [`EntityCategory`]s.
+///
+/// # Usage
+///
+/// Rules should be registered using [`CategoryRegistry::register_component_category`],
+/// [`CategoryRegistry::register_component_set_category`] or [`CategoryRegistry::register_predicate_category`],
+/// typically in the plugin that defines the relevant components.
+///
+/// Categories are listed in the order in which they were first registered.
+#[derive(Debug, Resource, Default)]
+pub struct CategoryRegistry {
+    /// The registered rules, in registration order.
+    rules: Vec<(EntityCategory, CategoryRule)>,
+}
+
+impl CategoryRegistry {
+    /// Creates a new, empty [`CategoryRegistry`].
+    pub const fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Adds all entities with the component `T` to the `category`.
+    pub fn register_component_category<T: Component>(
+        &mut self,
+        category: impl Into<EntityCategory>,
+    ) {
+        self.register_component_set_category(category, [TypeId::of::<T>()]);
+    }
+
+    /// Adds all entities with every one of the component types to the `category`.
+    pub fn register_component_set_category(
+        &mut self,
+        category: impl Into<EntityCategory>,
+        type_ids: impl IntoIterator<Item = TypeId>,
+    ) {
+        self.register_rule(
+            category,
+            CategoryRule::Components(type_ids.into_iter().collect()),
+        );
+    }
+
+    /// Adds all entities for which the `predicate` returns `true` to the `category`.
+    pub fn register_predicate_category(
+        &mut self,
+        category: impl Into<EntityCategory>,
+        predicate: impl Fn(EntityRef) -> bool + Send + Sync + 'static,
+    ) {
+        self.register_rule(category, CategoryRule::Predicate(Arc::new(predicate)));
+    }
+
+    /// Adds a rule for the `category`.
+    pub fn register_rule(&mut self, category: impl Into<EntityCategory>, rule: CategoryRule) {
+        self.rules.push((category.into(), rule));
+    }
+
+    /// Removes all rules for the `category`.
+    pub fn unregister_category(&mut self, category: &EntityCategory) {
+        self.rules.retain(|(registered, _)| registered != category);
+    }
+
+    /// Returns every category with at least one rule, in registration order.
+    pub fn categories(&self) -> Vec<EntityCategory> {
+        let mut categories: Vec<EntityCategory> = Vec::new();
+        for (category, _) in &self.rules {
+            if !categories.contains(category) {
+                categories.push(category.clone());
+            }
+        }
+        categories
+    }
+
+    /// Checks whether the entity belongs to the `category`.
+    pub fn is_in_category(&self, entity: EntityRef, category: &EntityCategory) -> bool {
+        self.rules
+            .iter()
+            .any(|(registered, rule)| registered == category && rule.matches(entity))
+    }
+
+    /// Returns every category that the entity belongs to, in registration order.
+    pub fn categorize(&self, entity: EntityRef) -> Vec<EntityCategory> {
+        let mut categories: Vec<EntityCategory> = Vec::new();
+        for (category, rule) in &self.rules {
+            if !categories.contains(category) && rule.matches(entity) {
+                categories.push(category.clone());
+            }
+        }
+        categories
+    }
+}
+
+/// A plugin which sorts Bevy's first-party types into categories
+/// in the [`CategoryRegistry`] resource.
+///
+/// When upstreamed, this plugin should not be necessary,
+/// as each plugin can register categories for its own components.
+pub struct CategoryPlugin;
+
+impl Plugin for CategoryPlugin {
+    fn build(&self, app: &mut App) {
+        let mut registry = app.world_mut().get_resource_or_init::<CategoryRegistry>();
+
+        registry.register_component_category::<Node>(EntityCategory::UI);
+
+        registry.register_component_category::<AudioPlayer>(EntityCategory::AUDIO);
+        registry.register_component_category::<AudioSink>(EntityCategory::AUDIO);
+        registry.register_component_category::<SpatialListener>(EntityCategory::AUDIO);
+
+        registry.register_component_category::<Camera>(EntityCategory::CAMERAS);
+
+        registry.register_component_category::<DirectionalLight>(EntityCategory::LIGHTS);
+        registry.register_component_category::<PointLight>(EntityCategory::LIGHTS);
+        registry.register_component_category::<SpotLight>(EntityCategory::LIGHTS);
+
+        registry.register_component_category::<Sprite>(EntityCategory::RENDERING);
+        registry.register_component_category::<Mesh2d>(EntityCategory::RENDERING);
+        registry.register_component_category::<Mesh3d>(EntityCategory::RENDERING);
+
+        registry.register_component_category::<Window>(EntityCategory::WINDOWING);
+        registry.register_component_category::<Monitor>(EntityCategory::WINDOWING);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Health;
+
+    #[derive(Component)]
+    struct Player;
+
+    #[test]
+    fn entities_are_categorized_by_rules() {
+        let mut world = World::new();
+        let mut registry = CategoryRegistry::new();
+        registry.register_component_category::<Node>(EntityCategory::UI);
+        registry.register_component_set_category(
+            "Gameplay",
+            [TypeId::of::<Health>(), TypeId::of::<Player>()],
+        );
+        registry.register_predicate_category("Named", |entity| entity.contains::<Name>());
+
+        let player = world.spawn((Health, Player, Name::new("Player"))).id();
+        let enemy = world.spawn(Health).id();
+
+        assert_eq!(
+            registry.categorize(world.entity(player)),
+            vec![
+                EntityCategory::from("Gameplay"),
+                EntityCategory::from("Named")
+            ]
+        );
+        assert!(registry.categorize(world.entity(enemy)).is_empty());
+        assert!(registry.is_in_category(world.entity(player), &EntityCategory::from("Named")));
+    }
+
+    #[test]
+    fn categories_are_listed_once_in_registration_order() {
+        let mut registry = CategoryRegistry::new();
+        registry.register_component_category::<Health>("Gameplay");
+        registry.register_component_category::<Node>(EntityCategory::UI);
+        registry.register_component_category::<Player>("Gameplay");
+
+        assert_eq!(
+            registry.categories(),
+            vec![EntityCategory::from("Gameplay"), EntityCategory::UI]
+        );
+
+        registry.unregister_category(&EntityCategory::from("Gameplay"));
+        assert_eq!(registry.categories(), vec![EntityCategory::UI]);
+    }
+}
@@ -8,6 +8,12 @@ use bevy::ecs::component::ComponentId;
 use bevy::ecs::world::World;
 use strsim::jaro_winkler;
 
+/// A reasonable default `threshold` for the fuzzy matching functions in this module.
+///
+/// This tolerates small typos and casing differences,
+/// while rejecting names that are only vaguely similar.
+pub const DEFAULT_FUZZY_MATCH_THRESHOLD: f64 = 0.9;
+
 /// Attempts to find a [`ComponentId`] for the given fuzzy component name.
 ///
 /// A vector of candidate matches will be returned, with the best-effort match first.
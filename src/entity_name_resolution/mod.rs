@@ -168,6 +168,37 @@ pub fn resolve_name(
     Some(EntityName::resolved(&resolved_name))
 }
 
+/// Resolves the name of the `entity`, which has the given `components`,
+/// looking up the priority of each component in the [`NameResolutionRegistry`].
+///
+/// This is a convenience wrapper around [`resolve_name`] for callers that only have [`ComponentId`]s.
+pub(crate) fn resolve_entity_name(
+    world: &World,
+    entity: Entity,
+    components: &[ComponentId],
+) -> Option<EntityName> {
+    let registry = world.get_resource::<NameResolutionRegistry>();
+    let components: Vec<(ComponentId, String, _)> = components
+        .iter()
+        .filter_map(|component_id| {
+            let info = world.components().get_info(*component_id)?;
+            let priority = registry
+                .zip(info.type_id())
+                .and_then(|(registry, type_id)| registry.get_priority_by_type_id(type_id));
+            Some((*component_id, info.name().shortname().to_string(), priority))
+        })
+        .collect();
+    let name_data: Vec<ComponentNameData> = components
+        .iter()
+        .map(|(component_id, short_name, priority)| ComponentNameData {
+            component_id: *component_id,
+            short_name: short_name.as_str(),
+            name_definition_priority: *priority,
+        })
+        .collect();
+    resolve_name(world, entity, &name_data)
+}
+
 /// Stores the registered name-defining component types and their priorities.
 ///
 /// When determining an entity's name via [`resolve_name`], components with higher priority values
@@ -1,26 +1,71 @@
 //! Methods that should exist on existing Bevy types.
 
-use bevy::ecs::{component::ComponentId, query::SpawnDetails, resource::IsResource};
+use bevy::asset::UntypedAssetId;
+use bevy::ecs::{
+    archetype::ArchetypeId,
+    component::ComponentId,
+    message::Message,
+    query::{QueryEntityError, SpawnDetails},
+    resource::IsResource,
+    system::{SystemId, SystemInput},
+};
 use bevy::prelude::*;
 use core::any::{TypeId, type_name};
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
 
 use crate::{
+    entity_categories::CategoryRegistry,
     entity_grouping::EntityGrouping,
     entity_name_resolution::{ComponentNameData, EntityName, resolve_name},
+    inspection::archetype_inspection::{
+        ArchetypeInspection, ArchetypeInspectionError, ArchetypeInspectionSettings,
+    },
+    inspection::asset_inspection::{
+        AssetCollectionInspection, AssetInspection, AssetInspectionError, AssetInspectionSettings,
+        HandleResolver, inspect_single_asset,
+    },
     inspection::component_inspection::{
-        ComponentDetailLevel, ComponentInspection, ComponentInspectionError,
-        ComponentInspectionSettings, ComponentMetadataMap, ComponentTypeInspection,
-        ComponentTypeMetadata,
+        ChangeDetails, ComponentAccessor, ComponentDetailLevel, ComponentInspection,
+        ComponentInspectionError, ComponentInspectionSettings, ComponentMetadataMap,
+        ComponentTypeInspection, ComponentTypeMetadata,
+    },
+    inspection::component_trace::{
+        ComponentTraceError, ComponentTraceRecord, ComponentTraces, stop_tracing_entity,
+        trace_entity,
     },
     inspection::entity_inspection::{
-        EntityInspection, EntityInspectionError, EntityInspectionSettings,
-        MultipleEntityInspectionSettings, filter_entity_list_for_inspection,
+        EntityInspection, EntityInspectionError, EntityInspectionPage, EntityInspectionSettings,
+        MultipleEntityInspectionSettings, entities_matching_inspection_filter,
+        filter_entity_list_for_inspection,
+    },
+    inspection::entity_lifecycle::{EntityLifecycleHistory, LifecycleRecord},
+    inspection::message_inspection::{
+        MessageInspection, MessageInspectionRegistry, MessageInspectionSettings,
     },
+    inspection::observer_inspection::{ObserverInspection, ObserverInspectionError},
+    inspection::relationship_inspection::{RelationshipInspection, related_entities},
     inspection::resource_inspection::{
-        ResourceInspection, ResourceInspectionError, ResourceInspectionSettings,
+        NonSendThread, ResourceInspection, ResourceInspectionError, ResourceInspectionSettings,
+    },
+    inspection::schedule_inspection::{ScheduleBuildRecords, ScheduleInspection},
+    inspection::state_inspection::{
+        StateInspection, StateInspectionSettings, StateTransitionError, queue_state_transition,
+    },
+    inspection::system_inspection::{
+        OneShotSystemMetadata, RunOneShotSystemError, SystemInspection, SystemInspectionError,
+    },
+    inspection::type_registry_inspection::{
+        MissingRegistration, RegisteredTypeInspection, find_missing_registrations,
+        inspect_registered_types,
+    },
+    memory_size::{MemoryDetailLevel, MemoryEstimate, MemorySize, estimate_heap_size},
+    reflection_tools::{
+        ValueFormat, clone_incomplete, component_value_to_string, format_reflected_value,
+        reflect_component_by_id, reflect_non_send_resource,
     },
-    memory_size::MemorySize,
-    reflection_tools::{clone_incomplete, component_value_to_string},
 };
 
 /// An extension trait for inspecting ECS objects, for methods that should belong on [`World`].
@@ -56,12 +101,30 @@ pub trait WorldInspectionExtensionTrait {
     ///
     /// If you need to update the metadata of component types between inspections,
     /// you should clear or modify the `metadata_map` before calling this method.
+    ///
+    /// Entities are filtered before they are grouped,
+    /// so grouping only needs to consider the matching entities.
+    ///
+    /// Only the entities selected by [`MultipleEntityInspectionSettings::offset`]
+    /// and [`MultipleEntityInspectionSettings::limit`] are inspected,
+    /// but the returned [`EntityInspectionPage`] reports how many entities matched in total.
     fn inspect_multiple(
         &self,
         entities: impl IntoIterator<Item = Entity>,
         settings: MultipleEntityInspectionSettings,
         metadata_map: &mut ComponentMetadataMap,
-    ) -> Vec<Result<EntityInspection, EntityInspectionError>>;
+    ) -> EntityInspectionPage;
+
+    /// Inspects every entity in the world that matches the provided settings.
+    ///
+    /// This behaves like [`inspect_multiple`](Self::inspect_multiple) called with every entity,
+    /// but only visits the entities in archetypes that pass the component filters,
+    /// rather than checking each entity in turn.
+    fn inspect_matching(
+        &self,
+        settings: MultipleEntityInspectionSettings,
+        metadata_map: &mut ComponentMetadataMap,
+    ) -> EntityInspectionPage;
 
     /// Inspects the component corresponding to the provided [`ComponentId`].
     ///
@@ -125,6 +188,27 @@ pub trait WorldInspectionExtensionTrait {
         settings: ResourceInspectionSettings,
     ) -> Vec<ResourceInspection>;
 
+    /// Inspects the non-`Send` resource with the provided [`ComponentId`].
+    ///
+    /// Non-`Send` resources can only be accessed from the thread they were inserted on,
+    /// so their value and heap memory are only read when this is called from the thread
+    /// recorded in the [`NonSendThread`] resource.
+    /// Otherwise, only their name and shallow size are reported.
+    fn inspect_non_send_resource_by_id(
+        &self,
+        component_id: ComponentId,
+        settings: ResourceInspectionSettings,
+    ) -> Result<ResourceInspection, ResourceInspectionError>;
+
+    /// Inspects all non-`Send` resources in the world, sorted by name.
+    ///
+    /// See [`inspect_non_send_resource_by_id`](Self::inspect_non_send_resource_by_id)
+    /// for which information is available.
+    fn inspect_all_non_send_resources(
+        &self,
+        settings: ResourceInspectionSettings,
+    ) -> Vec<ResourceInspection>;
+
     /// Inspects the provided component type `C`, providing information about the type itself.
     ///
     /// For a dynamically-typed variant, use [`inspect_component_by_id`](Self::inspect_component_by_id).
@@ -140,6 +224,105 @@ pub trait WorldInspectionExtensionTrait {
         &self,
         component_id: ComponentId,
     ) -> Result<ComponentTypeInspection, ComponentInspectionError>;
+
+    /// Inspects the [`Observer`] on the provided entity.
+    ///
+    /// The provided [`ObserverInspection`] lists the events, components and entities
+    /// that the observer watches, and can be logged using the [`Display`] trait.
+    fn inspect_observer(
+        &self,
+        entity: Entity,
+    ) -> Result<ObserverInspection, ObserverInspectionError>;
+
+    /// Inspects the one-shot system stored on the provided entity.
+    ///
+    /// The provided [`SystemInspection`] contains details about the system,
+    /// and can be logged using the [`Display`] trait.
+    fn inspect_system(&self, entity: Entity) -> Result<SystemInspection, SystemInspectionError>;
+
+    /// Inspects every schedule in the [`Schedules`] resource, sorted by label.
+    ///
+    /// Each [`ScheduleInspection`] lists the schedule's systems, system sets,
+    /// ordering constraints and run conditions, and can be logged using the [`Display`] trait.
+    ///
+    /// Schedules which are currently running are temporarily removed from [`Schedules`] by Bevy,
+    /// so they are not included.
+    fn inspect_schedules(&self) -> Vec<ScheduleInspection>;
+
+    /// Inspects the [`Messages`] resource of every message type, sorted by name.
+    ///
+    /// The buffered messages can only be read for message types registered in the
+    /// [`MessageInspectionRegistry`](crate::inspection::message_inspection::MessageInspectionRegistry).
+    fn inspect_messages(&self, settings: MessageInspectionSettings) -> Vec<MessageInspection>;
+
+    /// Inspects every [`Assets`] collection, sorted by the name of the asset type.
+    ///
+    /// Only asset types registered using [`AssetApp::register_asset_reflect`] can be inspected.
+    fn inspect_assets(&self, settings: AssetInspectionSettings) -> Vec<AssetCollectionInspection>;
+
+    /// Inspects the asset with the provided `id`.
+    ///
+    /// The provided [`AssetInspection`] contains the asset's path and load state,
+    /// as well as its reflected value if [`AssetInspectionSettings::include_values`] is `true`.
+    fn inspect_asset(
+        &self,
+        id: UntypedAssetId,
+        settings: AssetInspectionSettings,
+    ) -> Result<AssetInspection, AssetInspectionError>;
+
+    /// Inspects every state type, sorted by name.
+    ///
+    /// The provided [`StateInspection`]s contain the current and pending value of each state,
+    /// along with its recent transitions if the
+    /// [`StateInspectionPlugin`](crate::inspection::state_inspection::StateInspectionPlugin) is added.
+    fn inspect_states(&self, settings: StateInspectionSettings) -> Vec<StateInspection>;
+
+    /// Queues a transition of the state type named `state` to its unit variant named `variant`.
+    ///
+    /// The `state` can be either the full or the short type name of the state type,
+    /// which must be registered using [`AppExtStates::register_type_mutable_state`].
+    fn queue_state_transition(
+        &mut self,
+        state: &str,
+        variant: &str,
+    ) -> Result<(), StateTransitionError>;
+
+    /// Inspects the archetype identified by `archetype_id`,
+    /// describing how its components are stored and which archetypes it transitions to.
+    fn inspect_archetype(
+        &self,
+        archetype_id: ArchetypeId,
+        settings: ArchetypeInspectionSettings,
+    ) -> Result<ArchetypeInspection, ArchetypeInspectionError>;
+
+    /// Returns the recorded spawns and despawns of the `entity`, oldest first.
+    ///
+    /// This is empty unless the [`EntityLifecyclePlugin`] is added.
+    ///
+    /// [`EntityLifecyclePlugin`]: crate::inspection::entity_lifecycle::EntityLifecyclePlugin
+    fn entity_lifecycle(&self, entity: Entity) -> Vec<LifecycleRecord>;
+
+    /// Starts recording the component lifecycle events of the `entity`,
+    /// returning the observers that record them.
+    ///
+    /// See [`trace_entity`] for more details.
+    fn trace_entity(&mut self, entity: Entity) -> Result<Vec<Entity>, ComponentTraceError>;
+
+    /// Stops recording the component lifecycle events of the `entity`,
+    /// keeping the events recorded so far.
+    fn stop_tracing_entity(&mut self, entity: Entity);
+
+    /// Returns the recorded component lifecycle events of the `entity`, oldest first.
+    ///
+    /// This is empty unless the entity has been traced with [`Self::trace_entity`].
+    fn component_trace(&self, entity: Entity) -> Vec<ComponentTraceRecord>;
+
+    /// Inspects every type in the [`AppTypeRegistry`], listing the reflected type data each provides.
+    fn inspect_type_registry(&self) -> Vec<RegisteredTypeInspection>;
+
+    /// Lists the component and resource types present in the world
+    /// which are not registered in the [`AppTypeRegistry`], with a hint on how to register each.
+    fn audit_type_registrations(&self) -> Vec<MissingRegistration>;
 }
 
 impl WorldInspectionExtensionTrait for World {
@@ -164,7 +347,20 @@ impl WorldInspectionExtensionTrait for World {
         // This unwrap is safe because `SpawnDetails` is always registered.
         let mut spawn_details_query = self.try_query::<SpawnDetails>().unwrap();
 
-        let spawn_details = Some(spawn_details_query.get(self, entity)?);
+        let spawn_details = match spawn_details_query.get(self, entity) {
+            Ok(spawn_details) => Some(spawn_details),
+            Err(QueryEntityError::NotSpawned(error)) => {
+                // Describe how the entity was despawned, if it has been recorded
+                let despawn_record = self
+                    .get_resource::<EntityLifecycleHistory>()
+                    .and_then(|history| history.despawn_record(entity));
+                return Err(match despawn_record {
+                    Some(record) => EntityInspectionError::Despawned(Box::new(record.clone())),
+                    None => EntityInspectionError::EntityNotFound(error),
+                });
+            }
+            Err(error) => return Err(error.into()),
+        };
 
         // Temporary binding to avoid dropping borrow
         let entity_ref = self.entity(entity);
@@ -188,18 +384,15 @@ impl WorldInspectionExtensionTrait for World {
                 .filter_map(Result::ok)
                 .collect();
 
-            let total_bytes = components
+            let entity_size = MemoryEstimate::Shallow(MemorySize::new(size_of::<Entity>()));
+            let total_memory_size = components
                 .iter()
                 .map(|comp| comp.memory_size)
-                // Sum in u64 to avoid overflow for extremely large entities on 32-bit systems.
-                .fold(std::mem::size_of::<Entity>() as u64, |acc, size| {
-                    acc + size.0
-                });
-            let total_memory_size = MemorySize(total_bytes);
+                .fold(entity_size, |acc, size| acc + size);
 
-            (Some(components), Some(total_memory_size))
+            (Some(components), total_memory_size)
         } else {
-            (None, None)
+            (None, MemoryEstimate::Unknown)
         };
         let name = match &components {
             Some(comps) => {
@@ -239,11 +432,38 @@ impl WorldInspectionExtensionTrait for World {
             None => resolve_name(self, entity, &[]),
         };
 
+        let relationships = settings.include_relationships.then(|| {
+            entity_ref
+                .archetype()
+                .components()
+                .iter()
+                .filter_map(|component_id| {
+                    let metadata = metadata_map.get(component_id)?;
+                    let kind = metadata.relationship_kind?;
+                    let component_info = self.components().get_info(*component_id)?;
+
+                    Some(RelationshipInspection {
+                        component_id: *component_id,
+                        name: metadata.name.clone(),
+                        kind,
+                        entities: related_entities(entity_ref, component_info)?,
+                    })
+                })
+                .collect()
+        });
+
+        let categories = self
+            .get_resource::<CategoryRegistry>()
+            .map(|registry| registry.categorize(entity_ref))
+            .unwrap_or_default();
+
         Ok(EntityInspection {
             entity,
             name,
             total_memory_size,
             components,
+            relationships,
+            categories,
             spawn_details,
         })
     }
@@ -253,24 +473,19 @@ impl WorldInspectionExtensionTrait for World {
         entities: impl IntoIterator<Item = Entity>,
         settings: MultipleEntityInspectionSettings,
         metadata_map: &mut ComponentMetadataMap,
-    ) -> Vec<Result<EntityInspection, EntityInspectionError>> {
-        {
-            metadata_map.update(self);
-
-            let entity_grouping =
-                EntityGrouping::generate(self, entities, settings.grouping_strategy);
-            let mut entity_list = entity_grouping.flatten();
-
-            filter_entity_list_for_inspection(self, &mut entity_list, &settings);
+    ) -> EntityInspectionPage {
+        let mut matching_entities: Vec<Entity> = entities.into_iter().collect();
+        filter_entity_list_for_inspection(self, &mut matching_entities, &settings);
+        inspect_page(self, matching_entities, settings, metadata_map)
+    }
 
-            let mut inspections = Vec::with_capacity(entity_list.len());
-            for entity in entity_list {
-                let inspection =
-                    self.inspect_cached(entity, &settings.entity_settings, metadata_map);
-                inspections.push(inspection);
-            }
-            inspections
-        }
+    fn inspect_matching(
+        &self,
+        settings: MultipleEntityInspectionSettings,
+        metadata_map: &mut ComponentMetadataMap,
+    ) -> EntityInspectionPage {
+        let matching_entities = entities_matching_inspection_filter(self, &settings);
+        inspect_page(self, matching_entities, settings, metadata_map)
     }
 
     fn inspect_component_by_id(
@@ -283,20 +498,29 @@ impl WorldInspectionExtensionTrait for World {
         let component_info = self.components().get_info(component_id).ok_or(
             ComponentInspectionError::ComponentIdNotRegistered(component_id),
         )?;
-        let memory_size = MemorySize::new(component_info.layout().size());
 
         if !self.entity(entity).contains_id(component_id) {
             return Err(ComponentInspectionError::ComponentNotFound(component_id));
         }
 
+        let memory_size = measure_memory(
+            self,
+            entity,
+            metadata.type_id,
+            MemorySize::new(component_info.layout().size()),
+            settings.memory_detail_level,
+        );
+
         let name = component_info.name();
+        let change_details = ChangeDetails::new(self, entity, component_id);
 
         let component_string = if settings.detail_level == ComponentDetailLevel::Names {
             None
         } else {
-            Some(component_value_to_string(
+            Some(format_component_value(
                 self,
                 entity,
+                component_id,
                 metadata.type_id,
                 settings.full_type_names,
             ))
@@ -316,6 +540,7 @@ impl WorldInspectionExtensionTrait for World {
             component_id,
             name,
             memory_size,
+            change_details,
             value: component_string,
             reflected_value,
         })
@@ -356,21 +581,41 @@ impl WorldInspectionExtensionTrait for World {
             .components()
             .get_info(component_id)
             .ok_or(ResourceInspectionError::ResourceNotFound(component_id))?;
-        let memory_size = MemorySize::new(component_info.layout().size());
+        let shallow_size = MemorySize::new(component_info.layout().size());
 
         let name = component_info.name();
         let type_id = component_info.type_id();
 
+        let resource_entity = self.resource_entities().get(component_id);
+
+        let memory_size = match resource_entity {
+            Some(entity) => measure_memory(
+                self,
+                entity,
+                type_id,
+                shallow_size,
+                settings.memory_detail_level,
+            ),
+            None => MemoryEstimate::Shallow(shallow_size),
+        };
+
+        let change_details =
+            resource_entity.and_then(|entity| ChangeDetails::new(self, entity, component_id));
+
         let type_registry = self.resource::<AppTypeRegistry>();
         let type_registration = match type_id {
             Some(type_id) => type_registry.read().get(type_id).cloned(),
             None => None,
         };
 
-        let resource_string = match self.resource_entities().get(component_id) {
-            Some(entity) => {
-                component_value_to_string(self, entity, type_id, settings.full_type_names)
-            }
+        let resource_string = match resource_entity {
+            Some(entity) => format_component_value(
+                self,
+                entity,
+                component_id,
+                type_id,
+                settings.full_type_names,
+            ),
             None => match type_id {
                 Some(type_id) => format!("<Resource not present: TypeId({type_id:?})>"),
                 None => "Dynamic Type".to_string(),
@@ -380,9 +625,11 @@ impl WorldInspectionExtensionTrait for World {
         Ok(ResourceInspection {
             component_id,
             name,
+            is_send: true,
             value: resource_string,
             type_id,
             memory_size,
+            change_details,
             type_registration,
         })
     }
@@ -403,6 +650,89 @@ impl WorldInspectionExtensionTrait for World {
         inspections
     }
 
+    fn inspect_non_send_resource_by_id(
+        &self,
+        component_id: ComponentId,
+        settings: ResourceInspectionSettings,
+    ) -> Result<ResourceInspection, ResourceInspectionError> {
+        let component_info = self
+            .components()
+            .get_info(component_id)
+            .ok_or(ResourceInspectionError::ResourceNotFound(component_id))?;
+        let shallow_size = MemorySize::new(component_info.layout().size());
+
+        let name = component_info.name();
+        let type_id = component_info.type_id();
+
+        let type_registry = self.resource::<AppTypeRegistry>().read();
+        let type_registration = type_id.and_then(|type_id| type_registry.get(type_id).cloned());
+
+        let not_readable = if !self.contains_non_send_by_id(component_id) {
+            Some("<Resource not present>")
+        } else if !self
+            .get_resource::<NonSendThread>()
+            .is_some_and(NonSendThread::is_current)
+        {
+            Some("<Non-Send resource: can only be read from the thread that owns it>")
+        } else {
+            None
+        };
+        let reflected = match not_readable {
+            Some(_) => None,
+            None => reflect_non_send_resource(self, component_id, &type_registry),
+        };
+
+        let memory_size = match (reflected, settings.memory_detail_level) {
+            (Some(reflected), MemoryDetailLevel::Deep) => MemoryEstimate::Deep {
+                shallow: shallow_size,
+                heap: estimate_heap_size(reflected.as_partial_reflect(), &type_registry),
+            },
+            _ => MemoryEstimate::Shallow(shallow_size),
+        };
+
+        let value = match (not_readable, reflected) {
+            (Some(reason), _) => reason.to_string(),
+            (None, Some(reflected)) => format_reflected_value(
+                reflected.as_partial_reflect(),
+                ValueFormat {
+                    full_type_names: settings.full_type_names,
+                    formatter: Some(&HandleResolver::new(self, &type_registry)),
+                },
+            ),
+            (None, None) => "<Unreflectable: not registered with `ReflectFromPtr`>".to_string(),
+        };
+
+        Ok(ResourceInspection {
+            component_id,
+            name,
+            is_send: false,
+            value,
+            type_id,
+            memory_size,
+            change_details: None,
+            type_registration,
+        })
+    }
+
+    fn inspect_all_non_send_resources(
+        &self,
+        settings: ResourceInspectionSettings,
+    ) -> Vec<ResourceInspection> {
+        let mut inspections: Vec<ResourceInspection> = self
+            .storages()
+            .non_sends
+            .iter()
+            .map(|(component_id, _data)| component_id)
+            .filter(|component_id| self.contains_non_send_by_id(*component_id))
+            .filter_map(|component_id| {
+                self.inspect_non_send_resource_by_id(component_id, settings)
+                    .ok()
+            })
+            .collect();
+        inspections.sort_by_cached_key(|inspection| inspection.name.shortname().to_string());
+        inspections
+    }
+
     fn inspect_component_type<C: Component>(
         &self,
     ) -> Result<ComponentTypeInspection, ComponentInspectionError> {
@@ -427,11 +757,314 @@ impl WorldInspectionExtensionTrait for World {
             }
         }
 
+        let mut accessing_systems = Vec::new();
+        // Only the access recorded by the `ScheduleInspectionPlugin` is known,
+        // and only schedules which have been built have recorded access
+        if let (Some(records), Some(schedules)) = (
+            self.get_resource::<ScheduleBuildRecords>(),
+            self.get_resource::<Schedules>(),
+        ) {
+            for (_, schedule) in schedules.iter() {
+                let Ok(systems) = schedule.systems() else {
+                    continue;
+                };
+                for (key, system) in systems {
+                    if let Some(access_kind) =
+                        records.access_kind(schedule.label(), key, component_id)
+                    {
+                        accessing_systems.push(ComponentAccessor {
+                            system_name: system.name(),
+                            schedule: Some(format!("{:?}", schedule.label())),
+                            access_kind,
+                        });
+                    }
+                }
+            }
+            // Schedules are stored in no particular order, so list them by label like `inspect_schedules`
+            accessing_systems.sort_by(|a, b| a.schedule.cmp(&b.schedule));
+        }
+
+        // One-shot systems only record their access when registered using `register_inspected_system`
+        if let Some(mut one_shot_systems) = self.try_query::<&OneShotSystemMetadata>() {
+            for metadata in one_shot_systems.iter(self) {
+                let Some(access_kind) = metadata.access_kind(component_id) else {
+                    continue;
+                };
+                accessing_systems.push(ComponentAccessor {
+                    system_name: metadata.name.clone(),
+                    schedule: None,
+                    access_kind,
+                });
+            }
+        }
+
+        let mut observers = Vec::new();
+        if let Some(mut observer_query) = self.try_query::<(Entity, &Observer)>() {
+            for (entity, observer) in observer_query.iter(self) {
+                if observer.descriptor().components().contains(&component_id)
+                    && let Ok(inspection) = ObserverInspection::new(self, entity)
+                {
+                    observers.push(inspection);
+                }
+            }
+        }
+
         Ok(ComponentTypeInspection {
             entity_count,
             metadata,
+            accessing_systems,
+            observers,
         })
     }
+
+    fn inspect_observer(
+        &self,
+        entity: Entity,
+    ) -> Result<ObserverInspection, ObserverInspectionError> {
+        ObserverInspection::new(self, entity)
+    }
+
+    fn inspect_system(&self, entity: Entity) -> Result<SystemInspection, SystemInspectionError> {
+        SystemInspection::new(self, entity)
+    }
+
+    fn inspect_schedules(&self) -> Vec<ScheduleInspection> {
+        let Some(schedules) = self.get_resource::<Schedules>() else {
+            return Vec::new();
+        };
+
+        let mut inspections: Vec<ScheduleInspection> = schedules
+            .iter()
+            .map(|(_, schedule)| ScheduleInspection::new(self, schedule))
+            .collect();
+        inspections.sort_by(|a, b| a.label.cmp(&b.label));
+        inspections
+    }
+
+    fn inspect_messages(&self, settings: MessageInspectionSettings) -> Vec<MessageInspection> {
+        let mut inspections: Vec<MessageInspection> = self
+            .iter_resources()
+            .filter_map(|(component_info, _ptr)| {
+                MessageInspection::new(self, component_info.id(), &settings)
+            })
+            .collect();
+        inspections.sort_by_cached_key(|inspection| inspection.name.shortname().to_string());
+        inspections
+    }
+
+    fn inspect_assets(&self, settings: AssetInspectionSettings) -> Vec<AssetCollectionInspection> {
+        AssetCollectionInspection::inspect_all(self, settings)
+    }
+
+    fn inspect_asset(
+        &self,
+        id: UntypedAssetId,
+        settings: AssetInspectionSettings,
+    ) -> Result<AssetInspection, AssetInspectionError> {
+        inspect_single_asset(self, id, settings)
+    }
+
+    fn inspect_states(&self, settings: StateInspectionSettings) -> Vec<StateInspection> {
+        StateInspection::inspect_all(self, settings)
+    }
+
+    fn queue_state_transition(
+        &mut self,
+        state: &str,
+        variant: &str,
+    ) -> Result<(), StateTransitionError> {
+        queue_state_transition(self, state, variant)
+    }
+
+    fn inspect_archetype(
+        &self,
+        archetype_id: ArchetypeId,
+        settings: ArchetypeInspectionSettings,
+    ) -> Result<ArchetypeInspection, ArchetypeInspectionError> {
+        ArchetypeInspection::new(self, archetype_id, settings)
+    }
+
+    fn entity_lifecycle(&self, entity: Entity) -> Vec<LifecycleRecord> {
+        self.get_resource::<EntityLifecycleHistory>()
+            .map(|history| history.records_for(entity).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn trace_entity(&mut self, entity: Entity) -> Result<Vec<Entity>, ComponentTraceError> {
+        trace_entity(self, entity)
+    }
+
+    fn stop_tracing_entity(&mut self, entity: Entity) {
+        stop_tracing_entity(self, entity);
+    }
+
+    fn component_trace(&self, entity: Entity) -> Vec<ComponentTraceRecord> {
+        self.get_resource::<ComponentTraces>()
+            .map(|traces| traces.records(entity).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn inspect_type_registry(&self) -> Vec<RegisteredTypeInspection> {
+        inspect_registered_types(self)
+    }
+
+    fn audit_type_registrations(&self) -> Vec<MissingRegistration> {
+        find_missing_registrations(self)
+    }
+}
+
+/// An extension trait for registering and running one-shot systems in a way that supports inspection,
+/// for methods that should belong on [`World`].
+pub trait OneShotSystemExtensionTrait {
+    /// Registers a one-shot system like [`World::register_system`],
+    /// while recording its name, data access and run count in a [`OneShotSystemMetadata`] component.
+    ///
+    /// This information is then available in the system's [`SystemInspection`].
+    fn register_inspected_system<I, O, M>(
+        &mut self,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        I: SystemInput + 'static,
+        O: 'static;
+
+    /// Runs the one-shot system stored on the provided entity.
+    ///
+    /// Only systems without input or output can be run this way,
+    /// as their types are not known at compile time.
+    fn run_one_shot_system(&mut self, entity: Entity) -> Result<(), RunOneShotSystemError>;
+}
+
+impl OneShotSystemExtensionTrait for World {
+    fn register_inspected_system<I, O, M>(
+        &mut self,
+        system: impl IntoSystem<I, O, M> + 'static,
+    ) -> SystemId<I, O>
+    where
+        I: SystemInput + 'static,
+        O: 'static,
+    {
+        let run_count = Arc::new(AtomicU32::new(0));
+        let counter = run_count.clone();
+        let mut system = IntoSystem::into_system(system.map(move |output| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            output
+        }));
+
+        // Initializing the system again when it first runs is harmless
+        let access = system.initialize(self);
+        let metadata = OneShotSystemMetadata::new(system.name(), &access, run_count);
+
+        let system_id = self.register_system(system);
+        self.entity_mut(system_id.entity()).insert(metadata);
+        system_id
+    }
+
+    fn run_one_shot_system(&mut self, entity: Entity) -> Result<(), RunOneShotSystemError> {
+        let inspection = self.inspect_system(entity)?;
+        if !inspection.is_runnable() {
+            return Err(RunOneShotSystemError::UnsupportedSignature {
+                input_type: inspection.input_type,
+                output_type: inspection.output_type,
+            });
+        }
+
+        self.run_system(SystemId::<(), ()>::from_entity(entity))
+            .map_err(|error| RunOneShotSystemError::Failed(error.to_string()))
+    }
+}
+
+/// Measures the memory used by the component identified by `type_id` on `entity`.
+///
+/// Resources are stored as components on a dedicated backing entity, so this serves both
+/// component and resource inspection.
+///
+/// Deep measurement falls back to the shallow size when the value cannot be reflected.
+fn measure_memory(
+    world: &World,
+    entity: Entity,
+    type_id: Option<TypeId>,
+    shallow: MemorySize,
+    detail_level: MemoryDetailLevel,
+) -> MemoryEstimate {
+    if detail_level == MemoryDetailLevel::Shallow {
+        return MemoryEstimate::Shallow(shallow);
+    }
+
+    let Some(reflected) = type_id.and_then(|type_id| world.get_reflect(entity, type_id).ok())
+    else {
+        return MemoryEstimate::Shallow(shallow);
+    };
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let heap = estimate_heap_size(reflected.as_partial_reflect(), &type_registry);
+
+    MemoryEstimate::Deep { shallow, heap }
+}
+
+/// Groups the already filtered `matching_entities`,
+/// then inspects the page of them selected by the `settings`.
+fn inspect_page(
+    world: &World,
+    matching_entities: Vec<Entity>,
+    settings: MultipleEntityInspectionSettings,
+    metadata_map: &mut ComponentMetadataMap,
+) -> EntityInspectionPage {
+    metadata_map.update(world);
+
+    let entity_grouping =
+        EntityGrouping::generate(world, matching_entities, settings.grouping_strategy);
+    let entity_list = entity_grouping.flatten();
+    let total_matches = entity_list.len();
+
+    let page = entity_list
+        .into_iter()
+        .skip(settings.offset)
+        .take(settings.limit.unwrap_or(usize::MAX));
+    let mut inspections = Vec::new();
+    for entity in page {
+        let inspection = world.inspect_cached(entity, &settings.entity_settings, metadata_map);
+        inspections.push(inspection);
+    }
+
+    EntityInspectionPage {
+        inspections,
+        total_matches,
+        offset: settings.offset,
+    }
+}
+
+/// Formats the value of the component identified by `component_id` on `entity`,
+/// resolving any [`Handle`]s to the asset they point to.
+///
+/// Resources are stored as components on a dedicated backing entity, so this serves both
+/// component and resource inspection.
+///
+/// The [`AppTypeRegistry`] is only locked once, rather than for every nested value.
+fn format_component_value(
+    world: &World,
+    entity: Entity,
+    component_id: ComponentId,
+    type_id: Option<TypeId>,
+    full_type_names: bool,
+) -> String {
+    let Some(type_registry) = world.get_resource::<AppTypeRegistry>() else {
+        return component_value_to_string(world, entity, type_id, full_type_names);
+    };
+    if type_id.is_none() {
+        return "Dynamic Type".to_string();
+    }
+
+    let type_registry = type_registry.read();
+    match reflect_component_by_id(world, entity, component_id, &type_registry) {
+        Some(reflected) => format_reflected_value(
+            reflected.as_partial_reflect(),
+            ValueFormat {
+                full_type_names,
+                formatter: Some(&HandleResolver::new(world, &type_registry)),
+            },
+        ),
+        None => "<Unreflectable: not registered with `ReflectFromPtr`>".to_string(),
+    }
 }
 
 /// An extension trait for inspection methods that belong on [`EntityCommands`].
@@ -516,3 +1149,34 @@ impl CommandsExtensionTrait for Commands<'_, '_> {
         });
     }
 }
+
+/// An extension trait for inspection methods that belong on [`App`].
+pub trait AppInspectionExtensionTrait {
+    /// Adds the message type `M` like [`App::add_message`],
+    /// and registers it in the [`MessageInspectionRegistry`]
+    /// so that the number of buffered messages can be inspected.
+    fn add_inspected_message<M: Message>(&mut self) -> &mut Self;
+
+    /// Adds the message type `M` like [`App::add_message`],
+    /// and registers it in the [`MessageInspectionRegistry`]
+    /// so that both the number and the values of buffered messages can be inspected.
+    fn add_inspected_reflected_message<M: Message + Reflect>(&mut self) -> &mut Self;
+}
+
+impl AppInspectionExtensionTrait for App {
+    fn add_inspected_message<M: Message>(&mut self) -> &mut Self {
+        self.add_message::<M>();
+        self.world_mut()
+            .get_resource_or_init::<MessageInspectionRegistry>()
+            .register::<M>();
+        self
+    }
+
+    fn add_inspected_reflected_message<M: Message + Reflect>(&mut self) -> &mut Self {
+        self.add_message::<M>();
+        self.world_mut()
+            .get_resource_or_init::<MessageInspectionRegistry>()
+            .register_reflected::<M>();
+        self
+    }
+}
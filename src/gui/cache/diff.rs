@@ -0,0 +1,585 @@
+//! Comparisons between two [`WorldSnapshot`]s.
+//!
+//! This is useful for answering "what changed between frame N and frame N + 10?",
+//! by capturing a snapshot at each point and logging the resulting [`WorldSnapshotDiff`].
+
+use bevy::{
+    ecs::component::ComponentId,
+    platform::collections::HashMap,
+    prelude::*,
+    reflect::{PartialReflect, ReflectRef, enums::VariantType},
+};
+use core::fmt::Display;
+
+use crate::{
+    entity_name_resolution::EntityName,
+    gui::cache::WorldSnapshot,
+    inspection::{component_inspection::ComponentInspection, entity_inspection::EntityInspection},
+    reflection_tools::reflected_value_to_string,
+};
+
+/// The differences between two [`WorldSnapshot`]s.
+///
+/// Create this using [`WorldSnapshotDiff::new`] or [`WorldSnapshot::diff`].
+/// Log this using the [`Display`] trait to see a summary of the changes.
+///
+/// Only entities and components that were captured in both snapshots can be compared.
+/// Per-field changes require the snapshots to have been created with
+/// [`store_reflected_value`](crate::inspection::component_inspection::ComponentInspectionSettings::store_reflected_value)
+/// enabled; otherwise the formatted component values are compared as a whole.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorldSnapshotDiff {
+    /// Entities that are only present in the newer snapshot.
+    ///
+    /// Each of their components is reported as [`ComponentChange::Added`].
+    pub spawned: Vec<EntityDiff>,
+    /// Entities that are only present in the older snapshot.
+    ///
+    /// Each of their components is reported as [`ComponentChange::Removed`].
+    pub despawned: Vec<EntityDiff>,
+    /// Entities that are present in both snapshots, but whose components differ.
+    pub modified: Vec<EntityDiff>,
+}
+
+impl WorldSnapshotDiff {
+    /// Compares two snapshots, reporting the changes needed to go from `before` to `after`.
+    ///
+    /// Entities are reported in the order they were captured in their snapshot.
+    pub fn new(before: &WorldSnapshot, after: &WorldSnapshot) -> Self {
+        let spawned = after
+            .iter()
+            .filter(|inspection| before.get(inspection.entity).is_none())
+            .map(|inspection| EntityDiff::whole_entity(inspection, ComponentChange::Added))
+            .collect();
+
+        let despawned = before
+            .iter()
+            .filter(|inspection| after.get(inspection.entity).is_none())
+            .map(|inspection| EntityDiff::whole_entity(inspection, ComponentChange::Removed))
+            .collect();
+
+        let modified = after
+            .iter()
+            .filter_map(|new| {
+                let old = before.get(new.entity)?;
+                let diff = EntityDiff::new(old, new);
+                (!diff.components.is_empty()).then_some(diff)
+            })
+            .collect();
+
+        Self {
+            spawned,
+            despawned,
+            modified,
+        }
+    }
+
+    /// Returns true if no differences were found.
+    pub fn is_empty(&self) -> bool {
+        self.spawned.is_empty() && self.despawned.is_empty() && self.modified.is_empty()
+    }
+}
+
+impl Display for WorldSnapshotDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "No changes");
+        }
+
+        let sections = [
+            ("Spawned", &self.spawned),
+            ("Despawned", &self.despawned),
+            ("Modified", &self.modified),
+        ];
+
+        let mut first = true;
+        for (label, entity_diffs) in sections {
+            for entity_diff in entity_diffs {
+                if !first {
+                    writeln!(f)?;
+                }
+                first = false;
+                write!(f, "{label} {entity_diff}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl WorldSnapshot {
+    /// Compares this snapshot against a `newer` one.
+    ///
+    /// See [`WorldSnapshotDiff::new`] for more details.
+    pub fn diff(&self, newer: &WorldSnapshot) -> WorldSnapshotDiff {
+        WorldSnapshotDiff::new(self, newer)
+    }
+}
+
+/// The changes to the components of a single entity.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityDiff {
+    /// The entity that changed.
+    pub entity: Entity,
+    /// The name of the entity, taken from the newest snapshot it appears in.
+    pub name: Option<EntityName>,
+    /// The components that were added, removed or changed.
+    pub components: Vec<ComponentDiff>,
+}
+
+impl EntityDiff {
+    /// Compares two inspections of the same entity.
+    ///
+    /// Components which were not captured in one of the inspections are skipped.
+    pub fn new(before: &EntityInspection, after: &EntityInspection) -> Self {
+        let mut components = Vec::new();
+
+        if let (Some(old_components), Some(new_components)) =
+            (&before.components, &after.components)
+        {
+            let old_by_id: HashMap<ComponentId, &ComponentInspection> = old_components
+                .iter()
+                .map(|component| (component.component_id, component))
+                .collect();
+            let new_by_id: HashMap<ComponentId, &ComponentInspection> = new_components
+                .iter()
+                .map(|component| (component.component_id, component))
+                .collect();
+
+            for new in new_components {
+                let change = match old_by_id.get(&new.component_id) {
+                    None => ComponentChange::Added,
+                    Some(old) => {
+                        let field_changes = diff_component_values(old, new);
+                        if field_changes.is_empty() {
+                            continue;
+                        }
+                        ComponentChange::Changed(field_changes)
+                    }
+                };
+                components.push(ComponentDiff::new(new, change));
+            }
+
+            for old in old_components {
+                if !new_by_id.contains_key(&old.component_id) {
+                    components.push(ComponentDiff::new(old, ComponentChange::Removed));
+                }
+            }
+        }
+
+        Self {
+            entity: after.entity,
+            name: after.name.clone(),
+            components,
+        }
+    }
+
+    /// Reports every captured component of the entity with the same `change`.
+    fn whole_entity(inspection: &EntityInspection, change: ComponentChange) -> Self {
+        let components = inspection
+            .components
+            .iter()
+            .flatten()
+            .map(|component| ComponentDiff::new(component, change.clone()))
+            .collect();
+
+        Self {
+            entity: inspection.entity,
+            name: inspection.name.clone(),
+            components,
+        }
+    }
+}
+
+impl Display for EntityDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name_str = match &self.name {
+            Some(name) => name.as_str(),
+            None => "Entity",
+        };
+        write!(f, "{name_str} ({})", self.entity)?;
+
+        for component in &self.components {
+            write!(f, "\n{component}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A change to a single component on an entity.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComponentDiff {
+    /// The [`ComponentId`] of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// How the component changed.
+    pub change: ComponentChange,
+}
+
+impl ComponentDiff {
+    fn new(inspection: &ComponentInspection, change: ComponentChange) -> Self {
+        Self {
+            component_id: inspection.component_id,
+            name: inspection.name.clone(),
+            change,
+        }
+    }
+}
+
+impl Display for ComponentDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shortname = self.name.shortname();
+
+        match &self.change {
+            ComponentChange::Added => write!(f, "  + {shortname}"),
+            ComponentChange::Removed => write!(f, "  - {shortname}"),
+            ComponentChange::Changed(field_changes) => {
+                for (i, field_change) in field_changes.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "  ~ {shortname}{field_change}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The kind of change made to a component.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComponentChange {
+    /// The component was inserted.
+    Added,
+    /// The component was removed.
+    Removed,
+    /// The value of the component changed.
+    ///
+    /// This is never empty.
+    Changed(Vec<FieldChange>),
+}
+
+/// A change to the value of a single field in a component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldChange {
+    /// The path to the field within the component, e.g. `.translation.y` or `.items[2]`.
+    ///
+    /// This is empty when the component value as a whole changed.
+    pub path: String,
+    /// The formatted value before the change.
+    pub before: String,
+    /// The formatted value after the change.
+    pub after: String,
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {} -> {}", self.path, self.before, self.after)
+    }
+}
+
+/// Compares two inspections of the same component.
+///
+/// Falls back to comparing the formatted values when either reflected value is missing.
+fn diff_component_values(
+    before: &ComponentInspection,
+    after: &ComponentInspection,
+) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+
+    match (&before.reflected_value, &after.reflected_value) {
+        (Some(old), Some(new)) => {
+            diff_reflected(old.as_ref(), new.as_ref(), &mut String::new(), &mut changes);
+        }
+        _ => {
+            // Without a value on both sides, we simply cannot tell whether anything changed
+            if let (Some(old), Some(new)) = (&before.value, &after.value)
+                && old != new
+            {
+                changes.push(FieldChange {
+                    path: String::new(),
+                    before: old.clone(),
+                    after: new.clone(),
+                });
+            }
+        }
+    }
+
+    changes
+}
+
+/// Recursively compares two reflected values, recording the leaf fields that differ.
+///
+/// When the shape of the value changed (e.g. a different enum variant or list length),
+/// the whole value at `path` is reported as changed instead.
+fn diff_reflected(
+    before: &dyn PartialReflect,
+    after: &dyn PartialReflect,
+    path: &mut String,
+    changes: &mut Vec<FieldChange>,
+) {
+    match (before.reflect_ref(), after.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new))
+            if old.field_len() == new.field_len()
+                && (0..old.field_len()).all(|i| old.name_at(i) == new.name_at(i)) =>
+        {
+            for i in 0..old.field_len() {
+                let name = old.name_at(i).unwrap_or_default();
+                diff_field(
+                    format!(".{name}"),
+                    old.field_at(i),
+                    new.field_at(i),
+                    path,
+                    changes,
+                );
+            }
+        }
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new))
+            if old.field_len() == new.field_len() =>
+        {
+            for i in 0..old.field_len() {
+                diff_field(format!(".{i}"), old.field(i), new.field(i), path, changes);
+            }
+        }
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) if old.field_len() == new.field_len() => {
+            for i in 0..old.field_len() {
+                diff_field(format!(".{i}"), old.field(i), new.field(i), path, changes);
+            }
+        }
+        (ReflectRef::Array(old), ReflectRef::Array(new)) if old.len() == new.len() => {
+            for i in 0..old.len() {
+                diff_field(format!("[{i}]"), old.get(i), new.get(i), path, changes);
+            }
+        }
+        (ReflectRef::List(old), ReflectRef::List(new)) if old.len() == new.len() => {
+            for i in 0..old.len() {
+                diff_field(format!("[{i}]"), old.get(i), new.get(i), path, changes);
+            }
+        }
+        (ReflectRef::Enum(old), ReflectRef::Enum(new))
+            if old.variant_name() == new.variant_name() && old.field_len() == new.field_len() =>
+        {
+            for i in 0..old.field_len() {
+                let segment = match old.variant_type() {
+                    VariantType::Struct => format!(".{}", old.name_at(i).unwrap_or_default()),
+                    VariantType::Tuple | VariantType::Unit => format!(".{i}"),
+                };
+                diff_field(segment, old.field_at(i), new.field_at(i), path, changes);
+            }
+        }
+        // Opaque values, maps, sets and anything whose shape changed are compared as a whole
+        _ => {
+            let old_string = reflected_value_to_string(before, false);
+            let new_string = reflected_value_to_string(after, false);
+
+            let changed = match before.reflect_partial_eq(after) {
+                Some(equal) => !equal,
+                // Not all types support comparison, so fall back to their formatted values
+                None => old_string != new_string,
+            };
+
+            if changed {
+                changes.push(FieldChange {
+                    path: path.clone(),
+                    before: old_string,
+                    after: new_string,
+                });
+            }
+        }
+    }
+}
+
+/// Compares a single field of two reflected values, extending `path` with `segment`.
+fn diff_field(
+    segment: String,
+    before: Option<&dyn PartialReflect>,
+    after: Option<&dyn PartialReflect>,
+    path: &mut String,
+    changes: &mut Vec<FieldChange>,
+) {
+    if let (Some(before), Some(after)) = (before, after) {
+        let path_len = path.len();
+        path.push_str(&segment);
+        diff_reflected(before, after, path, changes);
+        path.truncate(path_len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_size::MemoryEstimate;
+
+    fn create_test_component(
+        entity: Entity,
+        id: usize,
+        value: impl Reflect,
+    ) -> ComponentInspection {
+        let value: Box<dyn PartialReflect> = Box::new(value);
+        ComponentInspection {
+            entity,
+            component_id: ComponentId::new(id),
+            name: DebugName::owned(value.reflect_type_path().to_string()),
+            memory_size: MemoryEstimate::Unknown,
+            change_details: None,
+            value: Some(reflected_value_to_string(value.as_ref(), false)),
+            reflected_value: Some(value),
+        }
+    }
+
+    fn create_test_inspection(
+        entity: Entity,
+        components: Vec<ComponentInspection>,
+    ) -> EntityInspection {
+        EntityInspection {
+            entity,
+            name: None,
+            total_memory_size: MemoryEstimate::Unknown,
+            components: Some(components),
+            relationships: None,
+            categories: Vec::new(),
+            spawn_details: None,
+        }
+    }
+
+    fn snapshot(inspections: Vec<EntityInspection>) -> WorldSnapshot {
+        let ordering = inspections.iter().map(|i| i.entity).collect();
+        WorldSnapshot::full(inspections, ordering)
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_diff() {
+        let e1 = Entity::from_bits(1);
+        let before = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(e1, 0, Transform::default())],
+        )]);
+        let after = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(e1, 0, Transform::default())],
+        )]);
+
+        let diff = before.diff(&after);
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn spawned_and_despawned_entities_are_reported() {
+        let e1 = Entity::from_bits(1);
+        let e2 = Entity::from_bits(2);
+        let before = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(e1, 0, Visibility::Hidden)],
+        )]);
+        let after = snapshot(vec![create_test_inspection(
+            e2,
+            vec![create_test_component(e2, 0, Visibility::Hidden)],
+        )]);
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.spawned.len(), 1);
+        assert_eq!(diff.spawned[0].entity, e2);
+        assert_eq!(diff.spawned[0].components[0].change, ComponentChange::Added);
+        assert_eq!(diff.despawned.len(), 1);
+        assert_eq!(diff.despawned[0].entity, e1);
+        assert_eq!(
+            diff.despawned[0].components[0].change,
+            ComponentChange::Removed
+        );
+        assert!(diff.modified.is_empty());
+    }
+
+    #[test]
+    fn added_and_removed_components_are_reported() {
+        let e1 = Entity::from_bits(1);
+        let before = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(e1, 0, Visibility::Hidden)],
+        )]);
+        let after = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(e1, 1, Transform::default())],
+        )]);
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.modified.len(), 1);
+        let changes: Vec<_> = diff.modified[0]
+            .components
+            .iter()
+            .map(|component| (component.component_id, component.change.clone()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (ComponentId::new(1), ComponentChange::Added),
+                (ComponentId::new(0), ComponentChange::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_field_changes_are_reported_by_path() {
+        let e1 = Entity::from_bits(1);
+        let before = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(e1, 0, Transform::default())],
+        )]);
+        let after = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(
+                e1,
+                0,
+                Transform::from_xyz(0.0, -1.0, 0.0),
+            )],
+        )]);
+
+        let diff = before.diff(&after);
+
+        let ComponentChange::Changed(field_changes) = &diff.modified[0].components[0].change else {
+            panic!("expected the component value to have changed");
+        };
+        assert_eq!(field_changes.len(), 1);
+        assert_eq!(field_changes[0].path, ".translation.y");
+        assert_eq!(field_changes[0].before, "0.0");
+        assert_eq!(field_changes[0].after, "-1.0");
+    }
+
+    #[test]
+    fn changed_enum_variants_are_reported_as_a_whole() {
+        let e1 = Entity::from_bits(1);
+        let before = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(e1, 0, Visibility::Hidden)],
+        )]);
+        let after = snapshot(vec![create_test_inspection(
+            e1,
+            vec![create_test_component(e1, 0, Visibility::Visible)],
+        )]);
+
+        let diff = before.diff(&after);
+
+        let ComponentChange::Changed(field_changes) = &diff.modified[0].components[0].change else {
+            panic!("expected the component value to have changed");
+        };
+        assert_eq!(field_changes.len(), 1);
+        assert!(field_changes[0].path.is_empty());
+    }
+}
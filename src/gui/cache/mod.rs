@@ -2,15 +2,33 @@
 
 use crate::{
     gui::{config::InspectorConfig, plugin::RefreshCache, state::InspectorState},
-    inspection::component_inspection::ComponentMetadataMap,
+    inspection::{
+        archetype_inspection::ArchetypeInspection,
+        asset_inspection::AssetCollectionInspection,
+        change_profiler::ChangeProfileReport,
+        component_inspection::ComponentMetadataMap,
+        message_inspection::MessageInspection,
+        resource_inspection::ResourceInspection,
+        schedule_inspection::ScheduleInspection,
+        state_inspection::StateInspection,
+        summary_history::SummaryTrend,
+        type_registry_inspection::{MissingRegistration, RegisteredTypeInspection},
+        world_summary::ArchetypeSummary,
+    },
 };
 use bevy::prelude::*;
 
+pub mod diff;
 pub mod snapshot;
 pub(crate) mod systems;
 
+pub use diff::WorldSnapshotDiff;
 pub use snapshot::WorldSnapshot;
-pub use systems::update_inspector_cache;
+pub use systems::{
+    update_archetype_cache, update_asset_cache, update_change_profile_cache,
+    update_inspector_cache, update_message_cache, update_non_send_resource_cache,
+    update_schedule_cache, update_state_cache, update_trend_cache, update_type_registry_cache,
+};
 
 /// Cached data for the inspector.
 ///
@@ -18,11 +36,65 @@ pub use systems::update_inspector_cache;
 #[derive(Resource)]
 pub struct InspectorCache {
     /// Cached object list after filtering.
+    ///
+    /// Only contains the objects on the current [`InspectorState::object_list_page`].
     pub filtered_objects: Vec<crate::gui::state::ObjectListEntry>,
+    /// The number of objects that matched the filter, across all pages.
+    pub total_objects: usize,
     /// Cached metadata map (reused across inspections).
     pub metadata_map: Option<ComponentMetadataMap>,
     /// Snapshot of the world state.
     pub snapshot: WorldSnapshot,
+    /// Inspections of every schedule, shown in the [`ObjectListTab::Systems`](crate::gui::state::ObjectListTab::Systems) tab.
+    ///
+    /// See [`update_schedule_cache`] for how this is kept up to date.
+    pub schedules: Vec<ScheduleInspection>,
+    /// Inspections of every message type, shown in the [`ObjectListTab::Messages`](crate::gui::state::ObjectListTab::Messages) tab.
+    ///
+    /// See [`update_message_cache`] for how this is kept up to date.
+    pub messages: Vec<MessageInspection>,
+    /// Inspections of every asset collection, shown in the [`ObjectListTab::Assets`](crate::gui::state::ObjectListTab::Assets) tab.
+    ///
+    /// See [`update_asset_cache`] for how this is kept up to date.
+    pub assets: Vec<AssetCollectionInspection>,
+    /// Inspections of every state type, shown in the [`ObjectListTab::States`](crate::gui::state::ObjectListTab::States) tab.
+    ///
+    /// See [`update_state_cache`] for how this is kept up to date.
+    pub states: Vec<StateInspection>,
+    /// Inspections of every non-`Send` resource, shown after the other resources
+    /// in the [`ObjectListTab::Resources`](crate::gui::state::ObjectListTab::Resources) tab.
+    ///
+    /// See [`update_non_send_resource_cache`] for how this is kept up to date.
+    pub non_send_resources: Vec<ResourceInspection>,
+    /// Summaries of every non-empty archetype, shown in the [`ObjectListTab::Archetypes`](crate::gui::state::ObjectListTab::Archetypes) tab.
+    ///
+    /// See [`update_archetype_cache`] for how this is kept up to date.
+    pub archetypes: Vec<ArchetypeSummary>,
+    /// A detailed inspection of the [`InspectorState::selected_archetype`], if any.
+    pub archetype: Option<ArchetypeInspection>,
+    /// The entity count of each sample in the [`SummaryHistory`](crate::inspection::summary_history::SummaryHistory),
+    /// oldest first, shown as a chart in the [`ObjectListTab::Trends`](crate::gui::state::ObjectListTab::Trends) tab.
+    ///
+    /// This is `None` if the [`SummaryHistoryPlugin`](crate::inspection::summary_history::SummaryHistoryPlugin) has not been added.
+    /// See [`update_trend_cache`] for how this is kept up to date.
+    pub entity_count_history: Option<Vec<u32>>,
+    /// The trend across the samples in the [`SummaryHistory`](crate::inspection::summary_history::SummaryHistory), if any.
+    pub summary_trend: Option<SummaryTrend>,
+    /// The number of frames sampled so far and the window size of the [`ChangeProfiler`](crate::inspection::change_profiler::ChangeProfiler),
+    /// shown in the [`ObjectListTab::Changes`](crate::gui::state::ObjectListTab::Changes) tab.
+    ///
+    /// This is `None` if the [`ChangeProfilerPlugin`](crate::inspection::change_profiler::ChangeProfilerPlugin) has not been added.
+    /// See [`update_change_profile_cache`] for how this is kept up to date.
+    pub change_profile_progress: Option<(u32, u32)>,
+    /// The report of the most recent sampling window of the [`ChangeProfiler`](crate::inspection::change_profiler::ChangeProfiler), if any.
+    pub change_profile: Option<ChangeProfileReport>,
+    /// Every type in the [`AppTypeRegistry`], shown in the [`ObjectListTab::TypeRegistry`](crate::gui::state::ObjectListTab::TypeRegistry) tab.
+    ///
+    /// See [`update_type_registry_cache`] for how this is kept up to date.
+    pub registered_types: Vec<RegisteredTypeInspection>,
+    /// The component and resource types in the world which are not registered in the [`AppTypeRegistry`],
+    /// shown before the registered types.
+    pub missing_registrations: Vec<MissingRegistration>,
     /// Tracks whether the cache should be refreshed.
     pub timer: Option<Timer>,
 }
@@ -36,8 +108,22 @@ impl FromWorld for InspectorCache {
 
         Self {
             filtered_objects: Vec::default(),
+            total_objects: 0,
             metadata_map: None,
             snapshot: WorldSnapshot::default(),
+            schedules: Vec::new(),
+            messages: Vec::new(),
+            assets: Vec::new(),
+            states: Vec::new(),
+            non_send_resources: Vec::new(),
+            archetypes: Vec::new(),
+            archetype: None,
+            entity_count_history: None,
+            summary_trend: None,
+            change_profile_progress: None,
+            change_profile: None,
+            registered_types: Vec::new(),
+            missing_registrations: Vec::new(),
             timer,
         }
     }
@@ -1,4 +1,4 @@
-use bevy::{platform::collections::HashMap, prelude::*};
+use bevy::{ecs::change_detection::Tick, platform::collections::HashMap, prelude::*};
 
 use crate::{
     gui::cache::InspectorCache,
@@ -17,6 +17,10 @@ pub struct WorldSnapshot {
     entity_order: Vec<Entity>,
     /// Whether the cache contains a full snapshot of the filtered entities (used for paused state).
     pub is_full: bool,
+    /// The world's last and current change ticks when a full snapshot was taken.
+    ///
+    /// Used to tell which snapshotted components changed in the frame before pausing.
+    pub change_ticks: Option<(Tick, Tick)>,
 }
 
 impl WorldSnapshot {
@@ -24,6 +28,7 @@ impl WorldSnapshot {
         self.inspections.clear();
         self.entity_order.clear();
         self.is_full = false;
+        self.change_ticks = None;
     }
 
     pub fn empty() -> Self {
@@ -40,6 +45,7 @@ impl WorldSnapshot {
             inspections,
             entity_order: ordering,
             is_full: true,
+            change_ticks: None,
         }
     }
 
@@ -55,9 +61,12 @@ impl WorldSnapshot {
             entity,
             &EntityInspectionSettings {
                 include_components: true,
+                include_relationships: true,
                 component_settings:
                     crate::inspection::component_inspection::ComponentInspectionSettings {
                         store_reflected_value: true,
+                        // Only a single entity is inspected, so the deeper walk is affordable
+                        memory_detail_level: crate::memory_size::MemoryDetailLevel::Deep,
                         ..default()
                     },
             },
@@ -73,6 +82,7 @@ impl WorldSnapshot {
                 inspections: HashMap::from([(inspection.entity, inspection)]),
                 entity_order,
                 is_full: false,
+                change_ticks: None,
             }
         } else {
             Self::empty()
@@ -97,13 +107,16 @@ impl WorldSnapshot {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::memory_size::MemoryEstimate;
 
     fn create_test_inspection(entity: Entity) -> EntityInspection {
         EntityInspection {
             entity,
             name: None,
-            total_memory_size: None,
+            total_memory_size: MemoryEstimate::Unknown,
             components: None,
+            relationships: None,
+            categories: Vec::new(),
             spawn_details: None,
         }
     }
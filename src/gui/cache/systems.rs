@@ -2,6 +2,7 @@ use std::any::TypeId;
 
 use bevy::{
     ecs::{
+        change_detection::Tick,
         component::ComponentId,
         resource::IsResource,
         system::{SystemIdMarker, SystemState},
@@ -14,24 +15,74 @@ use crate::{
     extension_methods::WorldInspectionExtensionTrait,
     gui::{
         cache::{InspectorCache, snapshot::WorldSnapshot},
+        config::InspectorConfig,
         plugin::RefreshCache,
         state::{InspectorInternal, InspectorState, ObjectListEntry, ObjectListTab},
     },
     inspection::{
+        archetype_inspection::ArchetypeInspectionSettings,
+        asset_inspection::AssetInspectionSettings,
+        change_profiler::ChangeProfiler,
         component_inspection::{ComponentInspectionSettings, ComponentMetadataMap},
         entity_inspection::{
-            EntityInspection, EntityInspectionError, EntityInspectionSettings,
+            EntityInspection, EntityInspectionPage, EntityInspectionSettings,
             MultipleEntityInspectionSettings,
         },
+        entity_query::{EntityQuery, EntityQueryError},
+        message_inspection::MessageInspectionSettings,
+        resource_inspection::ResourceInspectionSettings,
+        state_inspection::StateInspectionSettings,
+        summary_history::SummaryHistory,
+        world_summary::{SummarySettings, WorldSummaryExt},
     },
-    memory_size::MemorySize,
 };
 
 /// Criteria used to filter objects in both paused and running modes.
 pub(crate) struct ObjectListFilter {
     pub tab: ObjectListTab,
-    pub text: String,
-    pub mandatory_components: Vec<ComponentId>,
+    /// The filters parsed from the search text, combined with the mandatory components.
+    ///
+    /// This is `None` if the search text is not a valid [`EntityQuery`],
+    /// in which case no objects are listed.
+    pub settings: Option<MultipleEntityInspectionSettings>,
+    /// The number of matching objects before the current page.
+    pub offset: usize,
+    /// The maximum number of objects on the current page.
+    pub limit: usize,
+}
+
+impl ObjectListFilter {
+    /// Parses the [`InspectorState::filter_text`] as an [`EntityQuery`]
+    /// and adds the [`InspectorState::mandatory_components`] to it.
+    fn new(
+        world: &World,
+        state: &InspectorState,
+        page_size: usize,
+    ) -> Result<Self, EntityQueryError> {
+        let mut settings = MultipleEntityInspectionSettings {
+            with_component_filter: state.mandatory_components.clone(),
+            ..default()
+        };
+        if let ObjectListTab::Category(category) = &state.active_objects_tab {
+            settings.category_filter.push(category.clone());
+        }
+        EntityQuery::parse(&state.filter_text)?.apply(world, &mut settings)?;
+
+        Ok(Self {
+            settings: Some(settings),
+            ..Self::empty(state, page_size)
+        })
+    }
+
+    /// A filter that does not match any objects.
+    fn empty(state: &InspectorState, page_size: usize) -> Self {
+        Self {
+            tab: state.active_objects_tab.clone(),
+            settings: None,
+            offset: state.object_list_page * page_size,
+            limit: page_size,
+        }
+    }
 }
 
 /// Allows abstracting over paused and running mode
@@ -70,24 +121,215 @@ pub fn update_inspector_cache(
     state: &mut SystemState<MessageReader<RefreshCache>>,
 ) {
     update_component_metadata_map(world);
-    let (is_paused, selected_object, filter) = {
+    let (is_paused, selected_object, filter, filter_error) = {
         let state = world.resource::<InspectorState>();
-        (
-            state.is_paused,
-            state.selected_object,
-            ObjectListFilter {
-                tab: state.active_objects_tab,
-                text: state.filter_text.clone(),
-                mandatory_components: state.mandatory_components.clone(),
-            },
-        )
+        let page_size = world.resource::<InspectorConfig>().object_list_page_size;
+        // Invalid queries are reported in the search bar, rather than silently ignored
+        let (filter, filter_error) = match ObjectListFilter::new(world, state, page_size) {
+            Ok(filter) => (filter, None),
+            Err(error) => (ObjectListFilter::empty(state, page_size), Some(error)),
+        };
+        (state.is_paused, state.selected_object, filter, filter_error)
     };
 
+    // The state is only touched when the error changes, to avoid needlessly re-rendering the search bar.
+    if world.resource::<InspectorState>().filter_error != filter_error {
+        world.resource_mut::<InspectorState>().filter_error = filter_error;
+    }
+
     if is_paused {
         update_cache_paused(world, state, &filter);
     } else {
         update_cache_running(world, selected_object, &filter);
     }
+
+    // Jump back to the last page if the list shrank,
+    // which will be shown after the next refresh
+    let total_objects = world.resource::<InspectorCache>().total_objects;
+    let last_page = total_objects.saturating_sub(1) / filter.limit.max(1);
+    if world.resource::<InspectorState>().object_list_page > last_page {
+        world.resource_mut::<InspectorState>().object_list_page = last_page;
+    }
+}
+
+/// Exclusive system that refreshes [`InspectorCache::schedules`] while the Systems tab is active.
+///
+/// Bevy removes schedules from [`Schedules`] while they run,
+/// so this runs in [`Last`] to see the [`Update`] schedule,
+/// and schedules which are currently running keep their previous inspection.
+pub fn update_schedule_cache(world: &mut World) {
+    if world.resource::<InspectorState>().active_objects_tab != ObjectListTab::Systems {
+        return;
+    }
+
+    let inspections = world.inspect_schedules();
+    let mut cache = world.resource_mut::<InspectorCache>();
+    for inspection in inspections {
+        match cache
+            .schedules
+            .iter_mut()
+            .find(|cached| cached.label == inspection.label)
+        {
+            Some(cached) => *cached = inspection,
+            None => cache.schedules.push(inspection),
+        }
+    }
+    cache.schedules.sort_by(|a, b| a.label.cmp(&b.label));
+}
+
+/// Exclusive system that refreshes [`InspectorCache::messages`] while the Messages tab is active.
+///
+/// This runs in [`Last`], so that the messages written during [`Update`] are included.
+pub fn update_message_cache(world: &mut World) {
+    if world.resource::<InspectorState>().active_objects_tab != ObjectListTab::Messages {
+        return;
+    }
+
+    let settings = MessageInspectionSettings {
+        pending_message_limit: Some(world.resource::<InspectorConfig>().message_preview_limit),
+        ..default()
+    };
+    let inspections = world.inspect_messages(settings);
+    world.resource_mut::<InspectorCache>().messages = inspections;
+}
+
+/// Exclusive system that refreshes [`InspectorCache::assets`] while the Assets tab is active.
+///
+/// Asset values can be very large, so only the value of the [`InspectorState::selected_asset`] is included.
+pub fn update_asset_cache(world: &mut World) {
+    let (active_tab, selected_asset) = {
+        let state = world.resource::<InspectorState>();
+        (state.active_objects_tab.clone(), state.selected_asset)
+    };
+    if active_tab != ObjectListTab::Assets {
+        return;
+    }
+
+    let mut collections = world.inspect_assets(AssetInspectionSettings::default());
+    if let Some(id) = selected_asset
+        && let Ok(inspection) = world.inspect_asset(
+            id,
+            AssetInspectionSettings {
+                include_values: true,
+                ..default()
+            },
+        )
+    {
+        for asset in collections.iter_mut().flat_map(|c| c.assets.iter_mut()) {
+            if asset.id == Some(id) {
+                *asset = inspection.clone();
+            }
+        }
+    }
+    world.resource_mut::<InspectorCache>().assets = collections;
+}
+
+/// Exclusive system that refreshes [`InspectorCache::states`] while the States tab is active.
+pub fn update_state_cache(world: &mut World) {
+    if world.resource::<InspectorState>().active_objects_tab != ObjectListTab::States {
+        return;
+    }
+
+    let inspections = world.inspect_states(StateInspectionSettings::default());
+    world.resource_mut::<InspectorCache>().states = inspections;
+}
+
+/// Exclusive system that refreshes [`InspectorCache::non_send_resources`] while the Resources tab is active.
+///
+/// Exclusive systems run on the main thread, which is required to read the values of non-`Send` resources.
+/// The [`InspectorWindowPlugin`](crate::gui::plugin::InspectorWindowPlugin) records this thread
+/// in the [`NonSendThread`](crate::inspection::resource_inspection::NonSendThread) resource.
+pub fn update_non_send_resource_cache(world: &mut World) {
+    if world.resource::<InspectorState>().active_objects_tab != ObjectListTab::Resources {
+        return;
+    }
+
+    let inspections = world.inspect_all_non_send_resources(ResourceInspectionSettings::default());
+    world.resource_mut::<InspectorCache>().non_send_resources = inspections;
+}
+
+/// Exclusive system that refreshes [`InspectorCache::archetypes`] and [`InspectorCache::archetype`]
+/// while the Archetypes tab is active.
+pub fn update_archetype_cache(world: &mut World) {
+    let (active_tab, selected_archetype) = {
+        let state = world.resource::<InspectorState>();
+        (state.active_objects_tab.clone(), state.selected_archetype)
+    };
+    if active_tab != ObjectListTab::Archetypes {
+        return;
+    }
+
+    let summary = world.summarize(SummarySettings {
+        include_component_names: true,
+        include_empty_archetypes: false,
+        max_archetype_rows: None,
+        ..default()
+    });
+    let inspection = selected_archetype.and_then(|archetype_id| {
+        world
+            .inspect_archetype(archetype_id, ArchetypeInspectionSettings::default())
+            .ok()
+    });
+    let mut cache = world.resource_mut::<InspectorCache>();
+    cache.archetypes = summary.archetype_summaries;
+    cache.archetype = inspection;
+}
+
+/// Exclusive system that refreshes [`InspectorCache::entity_count_history`] and [`InspectorCache::summary_trend`]
+/// while the Trends tab is active.
+pub fn update_trend_cache(world: &mut World) {
+    if world.resource::<InspectorState>().active_objects_tab != ObjectListTab::Trends {
+        return;
+    }
+
+    let (entity_count_history, summary_trend) = match world.get_resource::<SummaryHistory>() {
+        Some(history) => (
+            Some(
+                history
+                    .samples()
+                    .map(|sample| sample.total_entities)
+                    .collect(),
+            ),
+            history.trend(),
+        ),
+        None => (None, None),
+    };
+    let mut cache = world.resource_mut::<InspectorCache>();
+    cache.entity_count_history = entity_count_history;
+    cache.summary_trend = summary_trend;
+}
+
+/// Exclusive system that refreshes [`InspectorCache::change_profile_progress`] and [`InspectorCache::change_profile`]
+/// while the Changes tab is active.
+pub fn update_change_profile_cache(world: &mut World) {
+    if world.resource::<InspectorState>().active_objects_tab != ObjectListTab::Changes {
+        return;
+    }
+
+    let (change_profile_progress, change_profile) = match world.get_resource::<ChangeProfiler>() {
+        Some(profiler) => (
+            Some((profiler.frames_sampled(), profiler.window_frames)),
+            profiler.report().cloned(),
+        ),
+        None => (None, None),
+    };
+    let mut cache = world.resource_mut::<InspectorCache>();
+    cache.change_profile_progress = change_profile_progress;
+    cache.change_profile = change_profile;
+}
+
+/// Exclusive system that refreshes [`InspectorCache::registered_types`] and [`InspectorCache::missing_registrations`]
+/// while the Types tab is active.
+pub fn update_type_registry_cache(world: &mut World) {
+    if world.resource::<InspectorState>().active_objects_tab != ObjectListTab::TypeRegistry {
+        return;
+    }
+
+    let registered_types = world.inspect_type_registry();
+    let missing_registrations = world.audit_type_registrations();
+    let mut cache = world.resource_mut::<InspectorCache>();
+    cache.registered_types = registered_types;
+    cache.missing_registrations = missing_registrations;
 }
 
 fn update_component_metadata_map(world: &mut World) {
@@ -108,7 +350,7 @@ fn update_cache_running(
     selected_object: Option<Entity>,
     filter: &ObjectListFilter,
 ) {
-    let object_list = generate_live_object_list(world, filter);
+    let (object_list, total_objects) = generate_live_object_list(world, filter);
     let updated_snapshot = if let Some(selected) = selected_object {
         WorldSnapshot::single(world, selected)
     } else {
@@ -118,6 +360,7 @@ fn update_cache_running(
     let mut cache = world.resource_mut::<InspectorCache>();
     cache.snapshot = updated_snapshot;
     cache.filtered_objects = object_list;
+    cache.total_objects = total_objects;
 
     // Prevents sudden writing of `RefreshCache`
     // after a forceful refresh.
@@ -126,46 +369,111 @@ fn update_cache_running(
     }
 }
 
-fn generate_live_object_list(world: &mut World, filter: &ObjectListFilter) -> Vec<ObjectListEntry> {
-    let entities = query_entities_for_tab(world, filter.tab);
-    let inspections = inspect_entities(world, &filter.mandatory_components, entities);
+/// Generates the entries on the current page of the object list,
+/// along with the total number of matching objects.
+fn generate_live_object_list(
+    world: &mut World,
+    filter: &ObjectListFilter,
+) -> (Vec<ObjectListEntry>, usize) {
+    let Some(mut settings) = filter.settings.clone() else {
+        return (Vec::new(), 0);
+    };
+    if !restrict_settings_to_tab(world, &filter.tab, &mut settings) {
+        return (Vec::new(), 0);
+    }
+
+    // Names are matched against the display name, which is only known after inspecting.
+    // Without a name filter, only the entities on the current page need to be inspected.
+    if settings.name_filter.is_none() {
+        let page_settings = MultipleEntityInspectionSettings {
+            offset: filter.offset,
+            limit: Some(filter.limit),
+            ..settings.clone()
+        };
+        let page = inspect_entities(world, page_settings);
+        let entries = page
+            .inspections
+            .iter()
+            .filter_map(|r| r.as_ref().ok())
+            .map(create_object_list_entry)
+            .collect();
+        return (entries, page.total_matches);
+    }
 
+    let all_settings = MultipleEntityInspectionSettings {
+        name_filter: None,
+        ..settings
+    };
+    let inspections = inspect_entities(world, all_settings);
     filter_inspections_and_create_entries(
-        inspections.iter().filter_map(|r| r.as_ref().ok()),
+        inspections
+            .inspections
+            .iter()
+            .filter_map(|r| r.as_ref().ok()),
         filter,
         None,
+        None,
     )
 }
 
-/// Queries all entities matching the active tab, excluding UI nodes, windows, and inspector internals.
-fn query_entities_for_tab(world: &mut World, tab: ObjectListTab) -> Vec<Entity> {
-    let mut query = world.query::<EntityRef>();
-    query
-        .iter(world)
-        .filter(|e| {
-            if e.contains::<InspectorInternal>() {
-                return false;
-            }
-            matches_tab(*e, tab)
-        })
-        .map(|e| e.id())
-        .collect()
+/// Adds the components that decide whether an entity is listed in the `tab` to the `settings`,
+/// so that the tab is matched once per archetype by [`World::inspect_matching`].
+/// This mirrors [`matches_tab`], with inspector internals excluded from every tab.
+///
+/// Returns `false` if the `tab` does not list entities,
+/// or if no entity can have the component it requires.
+fn restrict_settings_to_tab(
+    world: &World,
+    tab: &ObjectListTab,
+    settings: &mut MultipleEntityInspectionSettings,
+) -> bool {
+    settings
+        .without_component_filter
+        .extend(world.component_id::<InspectorInternal>());
+    let required = match tab {
+        ObjectListTab::Entities => {
+            settings.without_component_filter.extend(
+                [
+                    world.component_id::<Node>(),
+                    world.component_id::<IsResource>(),
+                    world.component_id::<Observer>(),
+                    world.component_id::<SystemIdMarker>(),
+                ]
+                .into_iter()
+                .flatten(),
+            );
+            return true;
+        }
+        ObjectListTab::Resources => world.component_id::<IsResource>(),
+        ObjectListTab::Observers => world.component_id::<Observer>(),
+        ObjectListTab::OneShotSystems => world.component_id::<SystemIdMarker>(),
+        // Category membership is checked using `MultipleEntityInspectionSettings::category_filter`
+        ObjectListTab::Category(_) => return true,
+        // The remaining tabs list objects other than entities
+        _ => return false,
+    };
+    match required {
+        Some(component_id) => {
+            settings.with_component_filter.push(component_id);
+            true
+        }
+        None => false,
+    }
 }
 
 fn inspect_entities(
     world: &mut World,
-    mandatory_components: &[ComponentId],
-    entities: Vec<Entity>,
-) -> Vec<Result<EntityInspection, EntityInspectionError>> {
+    settings: MultipleEntityInspectionSettings,
+) -> EntityInspectionPage {
     world.resource_scope(|world, mut cache: Mut<InspectorCache>| {
         let Some(ref mut metadata_map) = cache.metadata_map else {
-            return Vec::new();
-        };
-        let inspection_settings = MultipleEntityInspectionSettings {
-            with_component_filter: mandatory_components.to_vec(),
-            ..default()
+            return EntityInspectionPage {
+                inspections: Vec::new(),
+                total_matches: 0,
+                offset: settings.offset,
+            };
         };
-        world.inspect_multiple(entities.iter().copied(), inspection_settings, metadata_map)
+        world.inspect_matching(settings, metadata_map)
     })
 }
 
@@ -189,12 +497,14 @@ fn update_cache_paused(
     }
 
     world.resource_scope(|_world, mut cache: Mut<InspectorCache>| {
-        let object_list = filter_inspections_and_create_entries(
+        let (object_list, total_objects) = filter_inspections_and_create_entries(
             cache.snapshot.iter(),
             filter,
             cache.metadata_map.as_ref(),
+            cache.snapshot.change_ticks,
         );
         cache.filtered_objects = object_list;
+        cache.total_objects = total_objects;
     });
 }
 
@@ -211,6 +521,7 @@ fn create_full_snapshot(world: &mut World) {
         .unwrap();
     let settings = EntityInspectionSettings {
         include_components: true,
+        include_relationships: true,
         component_settings: ComponentInspectionSettings {
             store_reflected_value: true,
             ..default()
@@ -225,9 +536,11 @@ fn create_full_snapshot(world: &mut World) {
         }
     }
 
+    let change_ticks = (world.last_change_tick(), world.read_change_tick());
     let mut cache = world.resource_mut::<InspectorCache>();
     cache.metadata_map = Some(metadata_map);
     cache.snapshot = WorldSnapshot::full(inspections, sorted_entities);
+    cache.snapshot.change_ticks = Some(change_ticks);
 }
 
 /// Gets all the entities without the component [`InspectorInternal`].
@@ -240,55 +553,113 @@ fn get_all_inspectable_entities(world: &mut World) -> Vec<Entity> {
         .collect()
 }
 
+/// Filters the inspections and turns the ones on the current page into [`ObjectListEntry`]s.
+///
+/// When `metadata_map` is provided (in paused mode), the component filters are checked against the inspections,
+/// using `change_ticks` (the world's last and current change tick) for the changed component filter.
+/// Otherwise, the inspections are assumed to already match them.
+///
+/// Returns the entries along with the total number of matching inspections.
 pub(crate) fn filter_inspections_and_create_entries<'a>(
     inspections: impl Iterator<Item = &'a EntityInspection>,
     filter: &ObjectListFilter,
     metadata_map: Option<&ComponentMetadataMap>,
-) -> Vec<ObjectListEntry> {
-    inspections
+    change_ticks: Option<(Tick, Tick)>,
+) -> (Vec<ObjectListEntry>, usize) {
+    let Some(settings) = &filter.settings else {
+        return (Vec::new(), 0);
+    };
+
+    let matching: Vec<&EntityInspection> = inspections
         .filter(|inspection| {
             if let Some(metadata_map) = metadata_map {
-                if !matches_tab((*inspection, metadata_map), filter.tab) {
+                if !matches_tab((*inspection, metadata_map), &filter.tab) {
                     return false;
                 }
 
-                if !filter
-                    .mandatory_components
-                    .iter()
-                    .all(|&comp_id| has_component_by_id(inspection, comp_id))
-                {
+                if !matches_component_filters(inspection, settings, change_ticks) {
                     return false;
                 }
             }
-            true
+            settings
+                .name_filter
+                .as_ref()
+                .is_none_or(|name_filter| name_filter.matches(&display_name(inspection)))
         })
-        .filter_map(|inspection| try_create_object_list_entry(inspection, &filter.text))
-        .collect()
+        .collect();
+
+    // Entries are only built for the current page, as building them is relatively expensive
+    let entries = matching
+        .iter()
+        .skip(filter.offset)
+        .take(filter.limit)
+        .map(|inspection| create_object_list_entry(inspection))
+        .collect();
+    (entries, matching.len())
 }
 
-fn try_create_object_list_entry(
-    inspection: &EntityInspection,
-    filter_text: &str,
-) -> Option<ObjectListEntry> {
-    let name = inspection
+/// The name shown for an entity in the object list.
+fn display_name(inspection: &EntityInspection) -> String {
+    inspection
         .name
         .as_ref()
         .map(|n| n.to_string())
-        .unwrap_or_else(|| format!("Entity {:?}", inspection.entity));
-
-    if !filter_text.is_empty() && !name.to_lowercase().contains(&filter_text.to_lowercase()) {
-        return None;
-    }
+        .unwrap_or_else(|| format!("Entity {:?}", inspection.entity))
+}
 
-    Some(ObjectListEntry {
+fn create_object_list_entry(inspection: &EntityInspection) -> ObjectListEntry {
+    ObjectListEntry {
         entity: inspection.entity,
-        display_name: name,
+        display_name: display_name(inspection),
         component_count: inspection.components.as_ref().map(|c| c.len()).unwrap_or(0),
-        memory_size: inspection.total_memory_size.unwrap_or(MemorySize::new(0)),
-    })
+        memory_size: inspection.total_memory_size,
+    }
+}
+
+/// Checks the component, category and value filters against a snapshotted inspection.
+fn matches_component_filters(
+    inspection: &EntityInspection,
+    settings: &MultipleEntityInspectionSettings,
+    change_ticks: Option<(Tick, Tick)>,
+) -> bool {
+    settings
+        .with_component_filter
+        .iter()
+        .all(|&comp_id| has_component_by_id(inspection, comp_id))
+        && !settings
+            .without_component_filter
+            .iter()
+            .any(|&comp_id| has_component_by_id(inspection, comp_id))
+        && settings.changed_component_filter.iter().all(|&comp_id| {
+            let Some((last_run, this_run)) = change_ticks else {
+                return false;
+            };
+            inspection
+                .components
+                .iter()
+                .flatten()
+                .find(|ci| ci.component_id == comp_id)
+                .and_then(|ci| ci.change_details.as_ref())
+                .is_some_and(|details| {
+                    Tick::new(details.changed_tick).is_newer_than(last_run, this_run)
+                })
+        })
+        && settings
+            .category_filter
+            .iter()
+            .all(|category| inspection.categories.contains(category))
+        && settings.value_predicates.iter().all(|predicate| {
+            inspection
+                .components
+                .iter()
+                .flatten()
+                .find(|ci| ci.component_id == predicate.component_id)
+                .and_then(|ci| ci.reflected_value.as_deref())
+                .is_some_and(|reflected| predicate.evaluate(reflected))
+        })
 }
 
-fn matches_tab(checker: impl ComponentChecker, tab: ObjectListTab) -> bool {
+fn matches_tab(checker: impl ComponentChecker, tab: &ObjectListTab) -> bool {
     match tab {
         ObjectListTab::Entities => {
             !checker.has_component::<Node>()
@@ -299,6 +670,24 @@ fn matches_tab(checker: impl ComponentChecker, tab: ObjectListTab) -> bool {
         ObjectListTab::Resources => checker.has_component::<IsResource>(),
         ObjectListTab::Observers => checker.has_component::<Observer>(),
         ObjectListTab::OneShotSystems => checker.has_component::<SystemIdMarker>(),
+        // Systems in schedules are not entities, and are listed using `InspectorCache::schedules`
+        ObjectListTab::Systems => false,
+        // Messages are listed using `InspectorCache::messages`
+        ObjectListTab::Messages => false,
+        // Assets are listed using `InspectorCache::assets`
+        ObjectListTab::Assets => false,
+        // States are listed using `InspectorCache::states`
+        ObjectListTab::States => false,
+        // Archetypes are listed using `InspectorCache::archetypes`
+        ObjectListTab::Archetypes => false,
+        // Trends are shown using `InspectorCache::entity_count_history`
+        ObjectListTab::Trends => false,
+        // Component changes are shown using `InspectorCache::change_profile`
+        ObjectListTab::Changes => false,
+        // Types are listed using `InspectorCache::registered_types`
+        ObjectListTab::TypeRegistry => false,
+        // Category membership is checked using `MultipleEntityInspectionSettings::category_filter`
+        ObjectListTab::Category(_) => true,
     }
 }
 
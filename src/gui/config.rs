@@ -16,6 +16,12 @@ pub struct InspectorConfig {
     pub open_on_startup: bool,
     /// Optional key to toggle the inspector window.
     pub toggle_key: Option<KeyCode>,
+    /// Maximum number of objects shown at once in the object list.
+    ///
+    /// Larger lists are split into pages, to avoid inspecting and spawning rows for every object.
+    pub object_list_page_size: usize,
+    /// Maximum number of buffered messages shown for each message type in the Messages tab.
+    pub message_preview_limit: usize,
 
     // Layout
     /// Width of the left panel (entity list).
@@ -24,6 +30,8 @@ pub struct InspectorConfig {
     pub title_bar_height: Val,
     /// Height of the tab bar.
     pub tab_bar_height: Val,
+    /// Height of charts, such as the entity count chart in the Trends tab.
+    pub chart_height: Val,
 
     // Spacing
     /// Padding inside panels.
@@ -48,6 +56,10 @@ pub struct InspectorConfig {
     pub muted_text_color: Color,
     /// Error text color.
     pub error_text_color: Color,
+    /// Background color of badges, such as the one marking non-`Send` resources.
+    pub badge_color: Color,
+    /// Color of the bars in charts.
+    pub chart_color: Color,
 }
 
 impl Default for InspectorConfig {
@@ -57,11 +69,14 @@ impl Default for InspectorConfig {
             refresh_interval: Some(Duration::from_millis(500)),
             open_on_startup: false,
             toggle_key: None,
+            object_list_page_size: 200,
+            message_preview_limit: 20,
 
             // Layout
             left_panel_width: Val::Percent(30.0),
             title_bar_height: Val::Px(40.0),
             tab_bar_height: Val::Px(36.0),
+            chart_height: Val::Px(80.0),
 
             // Spacing
             panel_padding: UiRect::all(Val::Px(8.0)),
@@ -77,6 +92,8 @@ impl Default for InspectorConfig {
             border_color: Color::srgba(0.3, 0.3, 0.3, 1.0),
             muted_text_color: Color::srgba(0.6, 0.6, 0.6, 1.0),
             error_text_color: Color::srgba(0.8, 0.3, 0.3, 1.0),
+            badge_color: Color::srgba(0.45, 0.3, 0.1, 1.0),
+            chart_color: Color::srgba(0.3, 0.5, 0.8, 1.0),
         }
     }
 }
@@ -1,13 +1,13 @@
 //! Detail panel for the right side of the inspector.
 //!
 //! See [`DetailsTab`](crate::gui::state::DetailTab) for the different tabs available in this panel,
-//! which is used to switch between different detail views (e.g., components, relationships).
+//! which is used to switch between different detail views (e.g., components, relationships, history).
 
 #![expect(deprecated, reason = "need to upgrade to BSN still")]
 
+use bevy::asset::UntypedAssetId;
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::ecs::observer::On;
-use bevy::ecs::relationship::Relationship;
 use bevy::feathers::controls::{ButtonBundleProps, button_bundle};
 use bevy::feathers::theme::ThemeBackgroundColor;
 use bevy::feathers::tokens;
@@ -19,14 +19,17 @@ use bevy::ui_widgets::{Activate, ControlOrientation, Scrollbar, ScrollbarThumb,
 use core::any::TypeId;
 
 use crate::entity_name_resolution::EntityName;
-use crate::extension_methods::WorldInspectionExtensionTrait;
+use crate::extension_methods::{OneShotSystemExtensionTrait, WorldInspectionExtensionTrait};
 use crate::gui::cache::InspectorCache;
 use crate::gui::config::InspectorConfig;
+use crate::gui::panels::object_list::OpenAsset;
 use crate::gui::plugin::RefreshCache;
-use crate::gui::state::{DetailTab, InspectorState};
+use crate::gui::state::{DetailTab, InspectorInternal, InspectorState};
 use crate::gui::widgets::drag_value::{DragValue, DragValueDragState, FieldPath, FieldPathSegment};
+use crate::inspection::asset_inspection::HandleResolver;
 use crate::inspection::component_inspection::ComponentMetadataMap;
-use crate::inspection::entity_inspection::{EntityInspection, EntityInspectionSettings};
+use crate::inspection::component_trace::ComponentTraces;
+use crate::inspection::entity_inspection::EntityInspection;
 
 /// Marker component for the detail panel container.
 #[derive(Component)]
@@ -48,6 +51,18 @@ pub struct ComponentCard;
 #[derive(Component)]
 pub struct HierarchyNode(pub Entity);
 
+/// A button that runs the one-shot system stored on the entity.
+#[derive(Component)]
+pub struct RunSystemButton(pub Entity);
+
+/// A button that opens the asset that a [`Handle`] field points to in the Assets tab.
+#[derive(Component)]
+pub struct AssetLinkButton(pub UntypedAssetId);
+
+/// A button that starts or stops tracing the component lifecycle events of the entity.
+#[derive(Component)]
+pub struct TraceToggleButton(pub Entity);
+
 /// Observer for tab button clicks.
 fn on_tab_button_click(
     activate: On<Activate>,
@@ -74,6 +89,67 @@ fn on_hierarchy_node_click(
     }
 }
 
+/// Observer for the [`RunSystemButton`] of a one-shot system.
+fn on_run_system_button_click(
+    activate: On<Activate>,
+    mut commands: Commands,
+    buttons: Query<&RunSystemButton>,
+    mut refresh_cache: MessageWriter<RefreshCache>,
+) {
+    if let Ok(button) = buttons.get(activate.entity) {
+        let system = button.0;
+        commands.queue(move |world: &mut World| {
+            if let Err(error) = world.run_one_shot_system(system) {
+                warn!("Could not run one-shot system {system}: {error}");
+            }
+        });
+        // Show the updated run count
+        refresh_cache.write(RefreshCache { force: true });
+    }
+}
+
+/// Observer for the [`AssetLinkButton`] of a [`Handle`] field.
+fn on_asset_link_button_click(
+    activate: On<Activate>,
+    mut commands: Commands,
+    buttons: Query<&AssetLinkButton>,
+) {
+    if let Ok(button) = buttons.get(activate.entity) {
+        commands.trigger(OpenAsset(button.0));
+    }
+}
+
+/// Observer for the [`TraceToggleButton`] in the History tab.
+fn on_trace_toggle_button_click(
+    activate: On<Activate>,
+    mut commands: Commands,
+    buttons: Query<&TraceToggleButton>,
+    mut refresh_cache: MessageWriter<RefreshCache>,
+) {
+    if let Ok(button) = buttons.get(activate.entity) {
+        let entity = button.0;
+        commands.queue(move |world: &mut World| {
+            let traced = world
+                .get_resource::<ComponentTraces>()
+                .is_some_and(|traces| traces.is_traced(entity));
+            if traced {
+                world.stop_tracing_entity(entity);
+                return;
+            }
+            match world.trace_entity(entity) {
+                Ok(observers) => {
+                    // Keep the tracing observers out of the entity list
+                    for observer in observers {
+                        world.entity_mut(observer).insert(InspectorInternal);
+                    }
+                }
+                Err(error) => warn!("Could not trace entity {entity}: {error}"),
+            }
+        });
+        refresh_cache.write(RefreshCache { force: true });
+    }
+}
+
 /// Exclusive system that syncs the detail panel with the current selection.
 /// Uses exclusive world access to avoid resource conflicts.
 /// Periodically rebuilds UI to support live updating of values.
@@ -93,13 +169,7 @@ pub fn render_detail_panel(world: &mut World) {
         return;
     };
 
-    render_active_tab(
-        world,
-        content_entity,
-        selected_object,
-        active_tab,
-        &inspection,
-    );
+    render_active_tab(world, content_entity, active_tab, &inspection);
 }
 
 fn check_for_state_changes(world: &mut World) -> Option<(Option<Entity>, DetailTab)> {
@@ -207,7 +277,6 @@ fn spawn_error_message(world: &mut World, parent: Entity) {
 fn render_active_tab(
     world: &mut World,
     content_entity: Entity,
-    selected_object: Entity,
     active_tab: DetailTab,
     inspection: &EntityInspection,
 ) {
@@ -224,12 +293,10 @@ fn render_active_tab(
             spawn_components_tab_exclusive(world, content_entity, inspection, &mut metadata_map);
         }
         DetailTab::Relationships => {
-            spawn_relationships_tab_exclusive(
-                world,
-                content_entity,
-                selected_object,
-                &metadata_map,
-            );
+            spawn_relationships_tab_exclusive(world, content_entity, inspection);
+        }
+        DetailTab::History => {
+            spawn_history_tab_exclusive(world, content_entity, inspection);
         }
     }
 
@@ -243,6 +310,8 @@ struct ReflectedField {
     indent: u8,
     /// If this is an editable numeric field, contains the numeric value and path segments
     editable: Option<EditableFieldInfo>,
+    /// If this is a [`Handle`] field, the asset it points to
+    asset: Option<UntypedAssetId>,
 }
 
 /// Information needed to make a field editable
@@ -253,9 +322,31 @@ struct EditableFieldInfo {
     path: Vec<FieldPathSegment>,
 }
 
+/// Creates a field for a reflected [`Handle`], showing the path and load state of its asset.
+///
+/// Returns `None` if the value is not a handle.
+fn handle_field(
+    handles: &HandleResolver,
+    name: String,
+    reflected: &dyn PartialReflect,
+    indent: u8,
+) -> Option<ReflectedField> {
+    let handle = handles.resolve(reflected)?;
+    Some(ReflectedField {
+        name,
+        value: handle.to_string(),
+        indent,
+        editable: None,
+        asset: handle.asset.id,
+    })
+}
+
 /// Extracts fields from a reflected value into a flat list of label/value pairs.
 /// Tracks the path to each field for write-back support.
+///
+/// [`Handle`] fields are resolved to their asset using the `handles` resolver.
 fn extract_fields_from_reflect(
+    handles: &HandleResolver,
     reflected: &dyn PartialReflect,
     fields: &mut Vec<ReflectedField>,
     indent: u8,
@@ -266,6 +357,12 @@ fn extract_fields_from_reflect(
             for i in 0..s.field_len() {
                 let field_name = s.name_at(i).unwrap_or("?");
                 let field_value = s.field_at(i).unwrap();
+                if let Some(field) =
+                    handle_field(handles, field_name.to_string(), field_value, indent)
+                {
+                    fields.push(field);
+                    continue;
+                }
 
                 // Build path to this field
                 let mut field_path = current_path.to_vec();
@@ -284,6 +381,7 @@ fn extract_fields_from_reflect(
                         value: val,
                         indent,
                         editable,
+                        asset: None,
                     });
                 } else {
                     // Complex nested type - add header and recurse
@@ -296,14 +394,25 @@ fn extract_fields_from_reflect(
                         value: format!("[{}]", type_name),
                         indent,
                         editable: None,
+                        asset: None,
                     });
-                    extract_fields_from_reflect(field_value, fields, indent + 1, &field_path);
+                    extract_fields_from_reflect(
+                        handles,
+                        field_value,
+                        fields,
+                        indent + 1,
+                        &field_path,
+                    );
                 }
             }
         }
         ReflectRef::TupleStruct(ts) => {
             for i in 0..ts.field_len() {
                 let field_value = ts.field(i).unwrap();
+                if let Some(field) = handle_field(handles, format!(".{}", i), field_value, indent) {
+                    fields.push(field);
+                    continue;
+                }
 
                 // Build path to this field (use Index for tuple structs)
                 let mut field_path = current_path.to_vec();
@@ -324,6 +433,7 @@ fn extract_fields_from_reflect(
                         value: val,
                         indent,
                         editable,
+                        asset: None,
                     });
                 } else {
                     let type_name = field_value
@@ -335,8 +445,15 @@ fn extract_fields_from_reflect(
                         value: format!("[{}]", type_name),
                         indent,
                         editable: None,
+                        asset: None,
                     });
-                    extract_fields_from_reflect(field_value, fields, indent + 1, &field_path);
+                    extract_fields_from_reflect(
+                        handles,
+                        field_value,
+                        fields,
+                        indent + 1,
+                        &field_path,
+                    );
                 }
             }
         }
@@ -349,6 +466,7 @@ fn extract_fields_from_reflect(
                         value: variant_name.to_string(),
                         indent,
                         editable: None,
+                        asset: None,
                     });
                 }
                 VariantType::Tuple => {
@@ -357,16 +475,22 @@ fn extract_fields_from_reflect(
                         value: variant_name.to_string(),
                         indent,
                         editable: None,
+                        asset: None,
                     });
                     for i in 0..e.field_len() {
                         let field_value = e.field_at(i).unwrap();
                         let value_str = format_simple_value(field_value);
-                        if let Some(val) = value_str {
+                        if let Some(field) =
+                            handle_field(handles, format!(".{}", i), field_value, indent + 1)
+                        {
+                            fields.push(field);
+                        } else if let Some(val) = value_str {
                             fields.push(ReflectedField {
                                 name: format!(".{}", i),
                                 value: val,
                                 indent: indent + 1,
                                 editable: None, // TODO: enum field editing
+                                asset: None,
                             });
                         }
                     }
@@ -377,17 +501,23 @@ fn extract_fields_from_reflect(
                         value: variant_name.to_string(),
                         indent,
                         editable: None,
+                        asset: None,
                     });
                     for i in 0..e.field_len() {
                         let field_name = e.name_at(i).unwrap_or("?");
                         let field_value = e.field_at(i).unwrap();
                         let value_str = format_simple_value(field_value);
-                        if let Some(val) = value_str {
+                        if let Some(field) =
+                            handle_field(handles, field_name.to_string(), field_value, indent + 1)
+                        {
+                            fields.push(field);
+                        } else if let Some(val) = value_str {
                             fields.push(ReflectedField {
                                 name: field_name.to_string(),
                                 value: val,
                                 indent: indent + 1,
                                 editable: None, // TODO: enum field editing
+                                asset: None,
                             });
                         }
                     }
@@ -402,6 +532,7 @@ fn extract_fields_from_reflect(
                     value: val,
                     indent,
                     editable: None,
+                    asset: None,
                 });
             }
         }
@@ -466,6 +597,8 @@ fn format_simple_value(reflected: &dyn PartialReflect) -> Option<String> {
 struct ComponentCardData {
     name: String,
     size: String,
+    /// When the component was added and last changed, if known
+    change_details: Option<String>,
     fields: Vec<ReflectedField>,
     /// The entity this component belongs to (for write-back)
     entity: Entity,
@@ -473,6 +606,103 @@ struct ComponentCardData {
     component_type_id: Option<TypeId>,
 }
 
+/// An extra card shown above the component cards,
+/// for entities with special meaning like observers.
+struct InfoCard {
+    title: String,
+    /// Label/value pairs shown in the card.
+    fields: Vec<(&'static str, String)>,
+    /// A one-shot system that can be run using a button in the card.
+    runnable_system: Option<Entity>,
+}
+
+/// Formats a list of names for display in an [`InfoCard`].
+fn format_list(names: impl IntoIterator<Item = String>) -> String {
+    let names: Vec<String> = names.into_iter().collect();
+    format!("[{}]", names.join(", "))
+}
+
+/// Describes the events, components and entities watched by an observer.
+fn observer_info_card(world: &World, entity: Entity) -> Option<InfoCard> {
+    let observer = world.inspect_observer(entity).ok()?;
+
+    let events = format_list(
+        observer
+            .events
+            .iter()
+            .map(|event| event.shortname().to_string()),
+    );
+    let components = if observer.components.is_empty() {
+        "any".to_string()
+    } else {
+        format_list(
+            observer
+                .components
+                .iter()
+                .map(|component| component.name.shortname().to_string()),
+        )
+    };
+    let entities = if observer.watched_entities.is_empty() {
+        "all".to_string()
+    } else {
+        format_list(observer.watched_entities.iter().map(ToString::to_string))
+    };
+    let spawned_by = match &observer.spawned_by {
+        Some(location) => format!("{location} (tick {})", observer.spawn_tick),
+        None => format!("tick {}", observer.spawn_tick),
+    };
+
+    Some(InfoCard {
+        title: format!("Observer | {}", observer.system_name.shortname()),
+        fields: vec![
+            ("Events", events),
+            ("Components", components),
+            ("Entities", entities),
+            ("Spawned by", spawned_by),
+        ],
+        runnable_system: None,
+    })
+}
+
+/// Describes the signature, access and run count of a one-shot system.
+fn system_info_card(world: &World, entity: Entity) -> Option<InfoCard> {
+    let system = world.inspect_system(entity).ok()?;
+
+    let title = match &system.name {
+        Some(name) => format!("One-shot system | {}", name.shortname()),
+        None => "One-shot system".to_string(),
+    };
+    let mut fields = vec![(
+        "Signature",
+        format!("{} -> {}", system.input_type, system.output_type),
+    )];
+    if let Some(access) = &system.access {
+        if access.is_exclusive {
+            fields.push(("Access", "exclusive world access".to_string()));
+        } else {
+            let reads = if access.reads_all {
+                "everything".to_string()
+            } else {
+                format_list(access.reads.iter().map(ToString::to_string))
+            };
+            fields.push(("Reads", reads));
+            fields.push((
+                "Writes",
+                format_list(access.writes.iter().map(ToString::to_string)),
+            ));
+        }
+    }
+    if let Some(run_count) = system.run_count {
+        fields.push(("Runs", run_count.to_string()));
+    }
+
+    Some(InfoCard {
+        title,
+        fields,
+        runnable_system: system.is_runnable().then_some(entity),
+    })
+}
+
 fn spawn_components_tab_exclusive(
     world: &mut World,
     parent: Entity,
@@ -484,9 +714,7 @@ fn spawn_components_tab_exclusive(
     ));
 
     let component_count = inspection.components.as_ref().map_or(0, |c| c.len());
-    let memory_display = inspection
-        .total_memory_size
-        .map_or_else(|| "?".to_string(), |m| m.to_string());
+    let memory_display = inspection.total_memory_size.to_string();
 
     let &InspectorConfig {
         title_font_size,
@@ -501,24 +729,36 @@ fn spawn_components_tab_exclusive(
 
     let field_name_color = Color::srgba(0.6, 0.8, 1.0, 1.0); // Light blue for field names
 
+    // Observers and one-shot systems get an extra card describing how they work.
+    // This is read from the live world, as this information is not part of the snapshot.
+    let info_card = observer_info_card(world, inspection.entity)
+        .or_else(|| system_info_card(world, inspection.entity));
+
     // Extract fields for each component using cached inspection data
     let mut component_cards: Vec<ComponentCardData> = Vec::new();
 
     if let Some(components) = &inspection.components {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let handles = HandleResolver::new(world, &type_registry);
+
         for component_inspection in components {
             let comp_id = component_inspection.component_id;
 
             // Get metadata for this component
             let meta = metadata_map.map.get(&comp_id);
             let name = meta.map_or_else(|| "?".to_string(), |m| m.name.shortname().to_string());
-            let size = meta.map_or_else(|| "?".to_string(), |m| m.memory_size.to_string());
+            let size = component_inspection.memory_size.to_string();
+            let change_details = component_inspection
+                .change_details
+                .as_ref()
+                .map(ToString::to_string);
             let component_type_id = meta.and_then(|m| m.type_id);
 
             // Try to get reflected component data from the inspection snapshot
             let mut fields = Vec::new();
 
             if let Some(reflected_box) = &component_inspection.reflected_value {
-                extract_fields_from_reflect(reflected_box.as_ref(), &mut fields, 0, &[]);
+                extract_fields_from_reflect(&handles, reflected_box.as_ref(), &mut fields, 0, &[]);
             } else if let Some(value_str) = &component_inspection.value {
                 // Fallback to string value if reflected value not available
                 fields.push(ReflectedField {
@@ -526,12 +766,14 @@ fn spawn_components_tab_exclusive(
                     value: value_str.clone(),
                     indent: 0,
                     editable: None,
+                    asset: None,
                 });
             }
 
             component_cards.push(ComponentCardData {
                 name,
                 size,
+                change_details,
                 fields,
                 entity: inspection.entity,
                 component_type_id,
@@ -557,6 +799,87 @@ fn spawn_components_tab_exclusive(
             },
         ));
 
+        if let Some(info_card) = info_card {
+            p.spawn((
+                Node {
+                    width: Percent(100.0),
+                    padding: panel_padding,
+                    margin: UiRect::bottom(item_gap),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    border: UiRect::all(Px(1.0)),
+                    ..default()
+                },
+                ThemeBackgroundColor(tokens::WINDOW_BG),
+                BorderColor::all(border_color),
+            ))
+            .with_children(|card| {
+                card.spawn((
+                    Text::new(info_card.title),
+                    TextFont {
+                        font_size: FontSize::Px(body_font_size),
+                        ..default()
+                    },
+                    TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                    Node {
+                        margin: UiRect::bottom(Px(4.0)),
+                        ..default()
+                    },
+                ));
+
+                for (name, value) in info_card.fields {
+                    card.spawn(Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Px(8.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(format!("{name}:")),
+                            TextFont {
+                                font_size: FontSize::Px(small_font_size),
+                                ..default()
+                            },
+                            TextColor(field_name_color),
+                        ));
+                        row.spawn((
+                            Text::new(value),
+                            TextFont {
+                                font_size: FontSize::Px(small_font_size),
+                                ..default()
+                            },
+                            TextColor(muted_text_color),
+                        ));
+                    });
+                }
+
+                if let Some(system) = info_card.runnable_system {
+                    card.spawn(Node {
+                        margin: UiRect::top(Px(4.0)),
+                        ..default()
+                    })
+                    .with_children(|wrapper| {
+                        wrapper.spawn((
+                            button_bundle(
+                                ButtonBundleProps::default(),
+                                RunSystemButton(system),
+                                bevy::prelude::Spawn((
+                                    Text::new("Run"),
+                                    TextFont {
+                                        font_size: FontSize::Px(body_font_size),
+                                        ..default()
+                                    },
+                                )),
+                            ),
+                            observe(on_run_system_button_click),
+                        ));
+                    });
+                }
+            });
+        }
+
         // Component cards
         for card_data in component_cards {
             p.spawn((
@@ -588,6 +911,22 @@ fn spawn_components_tab_exclusive(
                     },
                 ));
 
+                // Change detection information (e.g. "who last mutated this?")
+                if let Some(change_details) = &card_data.change_details {
+                    card.spawn((
+                        Text::new(change_details.clone()),
+                        TextFont {
+                            font_size: FontSize::Px(small_font_size),
+                            ..default()
+                        },
+                        TextColor(muted_text_color),
+                        Node {
+                            margin: UiRect::bottom(Px(4.0)),
+                            ..default()
+                        },
+                    ));
+                }
+
                 // Field rows (dear imgui style)
                 for field in &card_data.fields {
                     let indent_px = field.indent as f32 * 12.0;
@@ -650,6 +989,22 @@ fn spawn_components_tab_exclusive(
                                 },
                                 TextColor(Color::srgba(0.9, 0.9, 0.6, 1.0)), // Yellow for editable
                             ));
+                        } else if let Some(asset) = field.asset {
+                            // Handles link to their asset in the Assets tab
+                            row.spawn((
+                                button_bundle(
+                                    ButtonBundleProps::default(),
+                                    AssetLinkButton(asset),
+                                    bevy::prelude::Spawn((
+                                        Text::new(field.value.clone()),
+                                        TextFont {
+                                            font_size: FontSize::Px(small_font_size),
+                                            ..default()
+                                        },
+                                    )),
+                                ),
+                                observe(on_asset_link_button_click),
+                            ));
                         } else {
                             // Field value (muted) - non-editable
                             row.spawn((
@@ -683,52 +1038,28 @@ fn spawn_components_tab_exclusive(
 fn spawn_relationships_tab_exclusive(
     world: &mut World,
     parent: Entity,
-    entity: Entity,
-    _metadata_map: &ComponentMetadataMap,
+    inspection: &EntityInspection,
 ) {
-    // FIXME: This tab directly queries live world state, bypassing the paused snapshot system.
-    // Long-term, relationship data should be included in `EntityInspection` to support snapshots.
-
-    // Get parent (ChildOf component)
-    let parent_entity = world.get::<ChildOf>(entity).map(|c| c.get());
-
-    // Get children
-    let children: Vec<Entity> = world
-        .get::<Children>(entity)
-        .map(|c| c.iter().collect())
-        .unwrap_or_default();
-
-    // Collect hierarchy node data before mutable world access
-    let parent_node_data = parent_entity.map(|e| {
-        let name = world
-            .get::<Name>(e)
-            .map_or_else(|| format!("Entity {:?}", e), |n| n.as_str().to_string());
-        let component_count = world
-            .inspect(e, EntityInspectionSettings::default())
-            .ok()
-            .and_then(|i| i.components.as_ref().map(|c| c.len()))
-            .unwrap_or(0);
-        (e, name, component_count)
-    });
-
-    let children_node_data: Vec<(Entity, String, usize)> = children
+    // Collect related entity data before mutable world access
+    let sections: Vec<(String, Vec<(Entity, String)>)> = inspection
+        .relationships
         .iter()
-        .map(|&e| {
-            let name = world
-                .get::<Name>(e)
-                .map_or_else(|| format!("Entity {:?}", e), |n| n.as_str().to_string());
-
-            let component_count = world
-                .inspect(e, EntityInspectionSettings::default())
-                .ok()
-                .and_then(|i| i.components.as_ref().map(|c| c.len()))
-                .unwrap_or(0);
-            (e, name, component_count)
+        .flatten()
+        .map(|relationship| {
+            let title = format!(
+                "{} ({})",
+                relationship.name.shortname(),
+                relationship.entities.len()
+            );
+            let nodes = relationship
+                .entities
+                .iter()
+                .map(|&e| (e, related_entity_label(world, e)))
+                .collect();
+            (title, nodes)
         })
         .collect();
 
-    let children_len = children.len();
-
     let &InspectorConfig {
         title_font_size,
         body_font_size,
@@ -738,47 +1069,9 @@ fn spawn_relationships_tab_exclusive(
     } = world.resource::<InspectorConfig>();
 
     world.entity_mut(parent).with_children(|p| {
-        // Parent section
-        p.spawn((
-            Text::new("Parent"),
-            TextFont {
-                font_size: FontSize::Px(title_font_size),
-                ..default()
-            },
-            TextColor(Color::WHITE),
-            Node {
-                margin: UiRect::bottom(Px(8.0)),
-                ..default()
-            },
-        ));
-
-        if let Some((ent, name, comp_count)) = parent_node_data {
-            let label = format!("{} ({} components)", name, comp_count);
-            // Wrap button in container to handle margin (button_bundle() already includes Node)
-            p.spawn(Node {
-                margin: UiRect::bottom(item_gap),
-                ..default()
-            })
-            .with_children(|wrapper| {
-                wrapper.spawn((
-                    button_bundle(
-                        ButtonBundleProps::default(),
-                        HierarchyNode(ent),
-                        bevy::prelude::Spawn((
-                            Text::new(label),
-                            TextFont {
-                                font_size: FontSize::Px(body_font_size),
-                                ..default()
-                            },
-                            TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
-                        )),
-                    ),
-                    observe(on_hierarchy_node_click),
-                ));
-            });
-        } else {
+        if sections.is_empty() {
             p.spawn((
-                Text::new("No parent (root entity)"),
+                Text::new("No relationships"),
                 TextFont {
                     font_size: FontSize::Px(body_font_size),
                     ..default()
@@ -791,36 +1084,23 @@ fn spawn_relationships_tab_exclusive(
             ));
         }
 
-        // Children section
-        p.spawn((
-            Text::new(format!("Children ({})", children_len)),
-            TextFont {
-                font_size: FontSize::Px(title_font_size),
-                ..default()
-            },
-            TextColor(Color::WHITE),
-            Node {
-                margin: UiRect::new(Px(0.0), Px(0.0), Px(16.0), Px(8.0)),
-                ..default()
-            },
-        ));
-
-        if children_node_data.is_empty() {
+        for (i, (title, nodes)) in sections.into_iter().enumerate() {
+            // One section per relationship component (e.g. `ChildOf`, `Children`)
+            let top_margin = if i == 0 { Px(0.0) } else { Px(16.0) };
             p.spawn((
-                Text::new("No children"),
+                Text::new(title),
                 TextFont {
-                    font_size: FontSize::Px(body_font_size),
+                    font_size: FontSize::Px(title_font_size),
                     ..default()
                 },
-                TextColor(muted_text_color),
+                TextColor(Color::WHITE),
                 Node {
-                    margin: UiRect::bottom(Px(16.0)),
+                    margin: UiRect::new(Px(0.0), Px(0.0), top_margin, Px(8.0)),
                     ..default()
                 },
             ));
-        } else {
-            for (ent, name, comp_count) in children_node_data {
-                let label = format!("{} ({} components)", name, comp_count);
+
+            for (ent, label) in nodes {
                 // Wrap button in container to handle margin (button_bundle() already includes Node)
                 p.spawn(Node {
                     margin: UiRect::bottom(item_gap),
@@ -848,6 +1128,118 @@ fn spawn_relationships_tab_exclusive(
     });
 }
 
+/// Spawns the History tab, listing the traced component lifecycle events of the entity, newest first.
+///
+/// These are read from the live world, as tracing happens independently of snapshots.
+fn spawn_history_tab_exclusive(world: &mut World, parent: Entity, inspection: &EntityInspection) {
+    let entity = inspection.entity;
+    let (traced, records) = world
+        .get_resource::<ComponentTraces>()
+        .map(|traces| {
+            let records: Vec<String> = traces
+                .records(entity)
+                .rev()
+                .map(|record| record.to_string())
+                .collect();
+            (traces.is_traced(entity), records)
+        })
+        .unwrap_or_default();
+
+    let &InspectorConfig {
+        body_font_size,
+        small_font_size,
+        muted_text_color,
+        item_gap,
+        ..
+    } = world.resource::<InspectorConfig>();
+
+    let button_label = if traced {
+        "Stop tracing"
+    } else {
+        "Trace this entity"
+    };
+
+    world.entity_mut(parent).with_children(|p| {
+        p.spawn(Node {
+            margin: UiRect::bottom(Px(8.0)),
+            ..default()
+        })
+        .with_children(|wrapper| {
+            wrapper.spawn((
+                button_bundle(
+                    ButtonBundleProps::default(),
+                    TraceToggleButton(entity),
+                    bevy::prelude::Spawn((
+                        Text::new(button_label),
+                        TextFont {
+                            font_size: FontSize::Px(body_font_size),
+                            ..default()
+                        },
+                    )),
+                ),
+                observe(on_trace_toggle_button_click),
+            ));
+        });
+
+        if records.is_empty() {
+            let message = if traced {
+                "No events recorded yet"
+            } else {
+                "Trace this entity to record its component events"
+            };
+            p.spawn((
+                Text::new(message),
+                TextFont {
+                    font_size: FontSize::Px(body_font_size),
+                    ..default()
+                },
+                TextColor(muted_text_color),
+            ));
+        }
+
+        for record in records {
+            p.spawn((
+                Text::new(record),
+                TextFont {
+                    font_size: FontSize::Px(small_font_size),
+                    ..default()
+                },
+                TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                Node {
+                    margin: UiRect::bottom(item_gap),
+                    ..default()
+                },
+            ));
+        }
+    });
+}
+
+/// Builds a button label for an entity shown in the Relationships tab.
+///
+/// Prefers the cached snapshot, so that paused inspection shows the state at the time of the snapshot.
+/// Falls back to the live world for entities that were not snapshotted.
+fn related_entity_label(world: &World, entity: Entity) -> String {
+    if let Some(related) = world.resource::<InspectorCache>().snapshot.get(entity) {
+        let name = related
+            .name
+            .as_ref()
+            .map_or_else(|| format!("Entity {entity:?}"), |n| n.as_str().to_string());
+        let component_count = related.components.as_ref().map_or(0, |c| c.len());
+        return format!("{name} ({component_count} components)");
+    }
+
+    match world.get_entity(entity) {
+        Ok(entity_ref) => {
+            let name = entity_ref
+                .get::<Name>()
+                .map_or_else(|| format!("Entity {entity:?}"), |n| n.as_str().to_string());
+            let component_count = entity_ref.archetype().component_count();
+            format!("{name} ({component_count} components)")
+        }
+        Err(_) => format!("Entity {entity:?} (despawned)"),
+    }
+}
+
 /// Spawns the detail panel structure.
 pub fn spawn_detail_panel(parent: &mut ChildSpawnerCommands<'_>, config: &InspectorConfig) {
     parent
@@ -911,6 +1303,22 @@ pub fn spawn_detail_panel(parent: &mut ChildSpawnerCommands<'_>, config: &Inspec
                         ),
                         observe(on_tab_button_click),
                     ));
+
+                    // History tab
+                    tabs.spawn((
+                        button_bundle(
+                            ButtonBundleProps::default(),
+                            TabButton(DetailTab::History),
+                            bevy::prelude::Spawn((
+                                Text::new("History"),
+                                TextFont {
+                                    font_size: FontSize::Px(config.body_font_size),
+                                    ..default()
+                                },
+                            )),
+                        ),
+                        observe(on_tab_button_click),
+                    ));
                 });
 
             // Scrollable area with scrollbar - use Grid layout
@@ -976,6 +1384,9 @@ mod tests {
     use crate::gui::cache::{InspectorCache, WorldSnapshot};
     use crate::gui::config::InspectorConfig;
     use crate::gui::state::{DetailTab, InspectorState};
+    use crate::inspection::relationship_inspection::{RelationshipInspection, RelationshipKind};
+    use crate::memory_size::MemoryEstimate;
+    use bevy::ecs::component::ComponentId;
     use bevy::ecs::system::RunSystemOnce;
 
     fn setup_test_app() -> App {
@@ -992,8 +1403,10 @@ mod tests {
         EntityInspection {
             entity,
             name: None,
-            total_memory_size: None,
+            total_memory_size: MemoryEstimate::Unknown,
             components: None,
+            relationships: None,
+            categories: Vec::new(),
             spawn_details: None,
         }
     }
@@ -1021,4 +1434,64 @@ mod tests {
             .expect("Content entity should have children");
         assert!(!children.is_empty());
     }
+
+    #[test]
+    fn relationships_tab_uses_snapshot_data() {
+        let mut app = setup_test_app();
+        app.add_plugins(NameResolutionPlugin);
+        let entity = Entity::from_bits(1);
+        // Neither entity exists in the world, so all data must come from the snapshot
+        let target = Entity::from_bits(2);
+
+        let mut inspection = create_test_inspection(entity);
+        inspection.relationships = Some(vec![RelationshipInspection {
+            component_id: ComponentId::new(0),
+            name: DebugName::type_name::<ChildOf>(),
+            kind: RelationshipKind::Relationship,
+            entities: vec![target],
+        }]);
+        let mut cache = app.world_mut().resource_mut::<InspectorCache>();
+        cache.snapshot = WorldSnapshot::full(vec![inspection], vec![entity]);
+
+        let mut state = app.world_mut().resource_mut::<InspectorState>();
+        state.selected_object = Some(entity);
+        state.active_detail_tab = DetailTab::Relationships;
+
+        let content_entity = app.world_mut().spawn((DetailContent, Node::default())).id();
+        let _ = app.world_mut().run_system_once(render_detail_panel);
+
+        let hierarchy_nodes: Vec<Entity> = app
+            .world_mut()
+            .query::<&HierarchyNode>()
+            .iter(app.world())
+            .map(|node| node.0)
+            .collect();
+        assert_eq!(hierarchy_nodes, vec![target]);
+        assert!(app.world().get::<Children>(content_entity).is_some());
+    }
+
+    #[test]
+    fn history_tab_shows_trace_toggle() {
+        let mut app = setup_test_app();
+        app.add_plugins(NameResolutionPlugin);
+        let entity = app.world_mut().spawn_empty().id();
+        let inspection = create_test_inspection(entity);
+        let mut cache = app.world_mut().resource_mut::<InspectorCache>();
+        cache.snapshot = WorldSnapshot::full(vec![inspection], vec![entity]);
+
+        let mut state = app.world_mut().resource_mut::<InspectorState>();
+        state.selected_object = Some(entity);
+        state.active_detail_tab = DetailTab::History;
+
+        app.world_mut().spawn((DetailContent, Node::default()));
+        let _ = app.world_mut().run_system_once(render_detail_panel);
+
+        let toggles: Vec<Entity> = app
+            .world_mut()
+            .query::<&TraceToggleButton>()
+            .iter(app.world())
+            .map(|button| button.0)
+            .collect();
+        assert_eq!(toggles, vec![entity]);
+    }
 }
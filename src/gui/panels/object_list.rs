@@ -10,6 +10,9 @@
 //! This entity is a [`Node`] which is a parent for all of the UI nodes related
 //! to the object list panel, including tabs, search bar, and list content.
 //!
+//! Alongside the built-in tabs, a tab is spawned for each category in the [`CategoryRegistry`](crate::entity_categories::CategoryRegistry)
+//! when the panel is created.
+//!
 //! The content for each tab is stored in a child node with the [`ObjectListContent`] component,
 //! which has a field indicating which tab it belongs to. These content nodes are typically scrollable
 //! areas which contain the list of entities or resources, and are switched between using the tab buttons.
@@ -17,8 +20,35 @@
 //! Each row in the object list is an entity with the [`ObjectRow`] component, which
 //! stores the object this row represents (e.g., an entity or resource ID).
 //! These rows are children of the content node for their respective tab.
+//! Non-`Send` resources are not stored on entities, so they are listed after the other resources
+//! by the [`render_non_send_resource_list`] system, in a [`NonSendResourceEntry`] marked with a badge.
+//! The exceptions are the [`ObjectListTab::Systems`] tab, whose content is a read-only list of schedules and their systems,
+//! spawned by the [`render_schedule_list`] system,
+//! the [`ObjectListTab::Messages`] tab, whose content is a read-only list of message types and their buffered messages,
+//! spawned by the [`render_message_list`] system,
+//! the [`ObjectListTab::Assets`] tab, whose content is a list of asset collections and their assets,
+//! spawned by the [`render_asset_list`] system,
+//! the [`ObjectListTab::States`] tab, whose content is a list of state types and their values,
+//! spawned by the [`render_state_list`] system,
+//! the [`ObjectListTab::Archetypes`] tab, whose content is a list of non-empty archetypes,
+//! spawned by the [`render_archetype_list`] system,
+//! the [`ObjectListTab::Trends`] tab, whose content is a chart of the entity count over time,
+//! spawned by the [`render_trend_chart`] system,
+//! the [`ObjectListTab::Changes`] tab, whose content is a table of how often each component type is changed,
+//! spawned by the [`render_change_profile_table`] system,
+//! and the [`ObjectListTab::TypeRegistry`] tab, whose content is a list of the missing and registered reflected types,
+//! spawned by the [`render_type_registry_list`] system.
+//! Each asset is shown by an [`AssetRow`], which shows the asset's value when clicked,
+//! each variant of a state is shown by a [`StateVariantButton`], which queues a transition to it when clicked,
+//! and each archetype is shown by an [`ArchetypeRow`], which shows its storage, edges and entities when clicked.
+//!
+//! Below the content, the [`ObjectListPageButton`]s and the [`ObjectListPageLabel`] allow moving between pages,
+//! as only [`InspectorConfig::object_list_page_size`] objects are listed at once.
 //!
 //! The search bar is an entity with the [`SearchInput`] component, which is a child of the main [`ObjectListPanel`] node.
+//! The [`render_search_input`] system displays the current [`InspectorState::filter_text`] in it,
+//! which is parsed as an [`EntityQuery`](crate::inspection::entity_query::EntityQuery),
+//! along with any error produced while parsing it.
 //!
 //! # Data flow
 //!
@@ -34,10 +64,16 @@
 //! which is observed by the [`on_object_row_click`] system to update the selected object in the [`InspectorState`].
 //! This allows the [`detail_panel`](super::detail_panel) to show the appropriate information for the selected object.
 //!
+//! Triggering the [`OpenAsset`] event selects an asset and switches to the [`ObjectListTab::Assets`] tab,
+//! which the detail panel does when a [`Handle`] is clicked.
+//!
 //! [`update_inspector_cache`]: crate::gui::cache::update_inspector_cache
 
 #![expect(deprecated, reason = "need to upgrade to BSN still")]
 
+use bevy::asset::UntypedAssetId;
+use bevy::ecs::archetype::ArchetypeId;
+use bevy::ecs::component::{ComponentId, StorageType};
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::ecs::observer::On;
 
@@ -46,11 +82,17 @@ use bevy::prelude::*;
 use bevy::ui::Val::*;
 use bevy::ui_widgets::{Activate, ControlOrientation, Scrollbar, ScrollbarThumb};
 
+use crate::entity_categories::EntityCategory;
+use crate::extension_methods::WorldInspectionExtensionTrait;
 use crate::gui::cache::InspectorCache;
 use crate::gui::config::InspectorConfig;
 use crate::gui::plugin::RefreshCache;
 use crate::gui::state::{InspectorState, ObjectListEntry, ObjectListTab};
-use crate::gui::widgets::tabs::{HasContent, Tab, TabActivated, TabContentDisplayMode, TabGroup};
+use crate::gui::widgets::tabs::{
+    ActivateTab, HasContent, Tab, TabActivated, TabContentDisplayMode, TabGroup,
+};
+use crate::inspection::archetype_inspection::ArchetypeInspection;
+use crate::inspection::schedule_inspection::format_conditions;
 
 /// Marker component for the object list panel container.
 ///
@@ -73,10 +115,55 @@ pub struct ObjectRow {
     pub selected_object: Entity,
 }
 
+/// Marker component for the node listing a non-`Send` resource in the [`ObjectListTab::Resources`] tab,
+/// which holds its [`NonSendResourceRow`] and its value when selected.
+///
+/// These nodes are skipped by [`render_object_list`], which only manages [`ObjectRow`]s.
+#[derive(Component)]
+pub struct NonSendResourceEntry;
+
+/// Component for non-`Send` resource rows, which stores the resource this row represents.
+#[derive(Component)]
+pub struct NonSendResourceRow(pub ComponentId);
+
+/// Component for asset rows in the [`ObjectListTab::Assets`] tab, which stores the asset this row represents.
+#[derive(Component)]
+pub struct AssetRow(pub UntypedAssetId);
+
+/// A button in the [`ObjectListTab::States`] tab that queues a transition of a state to one of its variants.
+#[derive(Component)]
+pub struct StateVariantButton {
+    /// The full type name of the state type.
+    pub state: String,
+    /// The name of the unit variant to transition to.
+    pub variant: String,
+}
+
+/// Component for archetype rows in the [`ObjectListTab::Archetypes`] tab, which stores the archetype this row represents.
+#[derive(Component)]
+pub struct ArchetypeRow(pub ArchetypeId);
+
+/// Event that selects an asset and switches the object list to the [`ObjectListTab::Assets`] tab.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct OpenAsset(pub UntypedAssetId);
+
 /// Marker component for the search bar input.
 #[derive(Component)]
 pub struct SearchInput;
 
+/// A button that moves between pages of the object list.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectListPageButton {
+    /// Moves to the previous page.
+    Previous,
+    /// Moves to the next page.
+    Next,
+}
+
+/// Marker component for the text showing which objects are on the current page.
+#[derive(Component)]
+pub struct ObjectListPageLabel;
+
 /// System that syncs the object list display with the cache.
 pub fn render_object_list(
     mut commands: Commands,
@@ -85,13 +172,24 @@ pub fn render_object_list(
     config: Res<InspectorConfig>,
     list_content: Query<(Entity, &ObjectListContent)>,
     children: Query<&Children>,
+    non_send_resource_entries: Query<(), With<NonSendResourceEntry>>,
     mut update_params: (
         Query<(&Children, &mut ObjectRow)>,
         Query<(&mut Text, &mut TextFont, &mut TextColor)>,
     ),
 ) {
     for (content_entity, object_list_content) in &list_content {
-        if state.active_objects_tab != object_list_content.tab {
+        // The systems, messages, assets, states, archetypes, trends, changes and types tabs are rendered by their own systems
+        if state.active_objects_tab != object_list_content.tab
+            || object_list_content.tab == ObjectListTab::Systems
+            || object_list_content.tab == ObjectListTab::Messages
+            || object_list_content.tab == ObjectListTab::Assets
+            || object_list_content.tab == ObjectListTab::States
+            || object_list_content.tab == ObjectListTab::Archetypes
+            || object_list_content.tab == ObjectListTab::Trends
+            || object_list_content.tab == ObjectListTab::Changes
+            || object_list_content.tab == ObjectListTab::TypeRegistry
+        {
             continue;
         }
 
@@ -99,7 +197,10 @@ pub fn render_object_list(
 
         // Update or clear existing rows
         if let Ok(children) = children.get(content_entity) {
-            for child in children {
+            for child in children
+                .into_iter()
+                .filter(|child| !non_send_resource_entries.contains(**child))
+            {
                 if let Some(entry) = filtered_objects_iterator.next() {
                     let is_selected = state.selected_object == Some(entry.entity());
                     update_object_row(child, entry, is_selected, &config, &mut update_params);
@@ -120,6 +221,1081 @@ pub fn render_object_list(
     }
 }
 
+/// System that lists the non-`Send` resources after the other resources in the [`ObjectListTab::Resources`] tab,
+/// using [`InspectorCache::non_send_resources`].
+///
+/// Non-`Send` resources are only listed on the first page, filtered by name using the [`InspectorState::filter_text`],
+/// and the value of the [`InspectorState::selected_non_send_resource`] is shown below its row.
+pub fn render_non_send_resource_list(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+    entries: Query<(Entity, &ChildOf), With<NonSendResourceEntry>>,
+) {
+    if state.active_objects_tab != ObjectListTab::Resources {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::Resources {
+            continue;
+        }
+
+        for (entry, child_of) in &entries {
+            if child_of.parent() == content_entity {
+                commands.entity(entry).despawn();
+            }
+        }
+        if state.object_list_page != 0 {
+            continue;
+        }
+
+        commands.entity(content_entity).with_children(|list| {
+            for resource in &cache.non_send_resources {
+                let name = resource.name.shortname().to_string();
+                if !name.to_lowercase().contains(&filter) {
+                    continue;
+                }
+
+                let display_name = if name.len() > 20 {
+                    format!("{}...", &name[..17])
+                } else {
+                    name
+                };
+                let is_selected = state.selected_non_send_resource == Some(resource.component_id);
+
+                list.spawn((
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
+                    NonSendResourceEntry,
+                ))
+                .with_children(|entry| {
+                    entry.spawn(button_bundle(
+                        ButtonBundleProps::default(),
+                        NonSendResourceRow(resource.component_id),
+                        (
+                            bevy::prelude::Spawn((
+                                Text::new(format!("{display_name:20} {}", resource.memory_size)),
+                                TextFont {
+                                    font_size: FontSize::Px(config.small_font_size),
+                                    ..default()
+                                },
+                                TextColor(if is_selected {
+                                    Color::WHITE
+                                } else {
+                                    Color::srgba(0.9, 0.9, 0.9, 1.0)
+                                }),
+                            )),
+                            bevy::prelude::Spawn((
+                                Text::new("non-Send"),
+                                TextFont {
+                                    font_size: FontSize::Px(config.small_font_size),
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                                BackgroundColor(config.badge_color),
+                                Node {
+                                    margin: UiRect::left(Px(4.0)),
+                                    padding: UiRect::horizontal(Px(4.0)),
+                                    ..default()
+                                },
+                            )),
+                        ),
+                    ));
+
+                    if is_selected {
+                        entry.spawn((
+                            Text::new(resource.value.clone()),
+                            TextFont {
+                                font_size: FontSize::Px(config.small_font_size),
+                                ..default()
+                            },
+                            TextColor(config.muted_text_color),
+                            Node {
+                                padding: UiRect::left(Px(8.0)),
+                                ..default()
+                            },
+                        ));
+                    }
+                });
+            }
+        });
+    }
+}
+
+/// System that lists the systems in each schedule in the [`ObjectListTab::Systems`] tab,
+/// using [`InspectorCache::schedules`].
+///
+/// Systems are filtered by name using the [`InspectorState::filter_text`].
+pub fn render_schedule_list(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+) {
+    if state.active_objects_tab != ObjectListTab::Systems {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::Systems {
+            continue;
+        }
+
+        commands
+            .entity(content_entity)
+            .despawn_children()
+            .with_children(|list| {
+                for schedule in &cache.schedules {
+                    let systems: Vec<_> = schedule
+                        .systems
+                        .iter()
+                        .filter(|system| system.name.to_string().to_lowercase().contains(&filter))
+                        .collect();
+                    if systems.is_empty() && !filter.is_empty() {
+                        continue;
+                    }
+
+                    list.spawn((
+                        Text::new(format!("{} ({})", schedule.label, systems.len())),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        Node {
+                            margin: UiRect::top(config.item_gap),
+                            ..default()
+                        },
+                    ));
+
+                    for system in systems {
+                        let mut details = Vec::new();
+                        if !system.sets.is_empty() {
+                            details.push(format!("in {}", system.sets.join(", ")));
+                        }
+                        if let Some(run_conditions) = &system.run_conditions
+                            && !run_conditions.is_empty()
+                        {
+                            details.push(format!("if {}", format_conditions(run_conditions)));
+                        }
+                        details.push(match &system.access {
+                            Some(access) => access.to_string(),
+                            None => "unknown access".to_string(),
+                        });
+
+                        list.spawn(Node {
+                            display: Display::Flex,
+                            flex_direction: FlexDirection::Column,
+                            padding: UiRect::left(Px(8.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            row.spawn((
+                                Text::new(system.name.shortname().to_string()),
+                                TextFont {
+                                    font_size: FontSize::Px(config.small_font_size),
+                                    ..default()
+                                },
+                                TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                            ));
+                            row.spawn((
+                                Text::new(details.join(" | ")),
+                                TextFont {
+                                    font_size: FontSize::Px(config.small_font_size),
+                                    ..default()
+                                },
+                                TextColor(config.muted_text_color),
+                            ));
+                        });
+                    }
+                }
+            });
+    }
+}
+
+/// System that lists the buffered messages of each message type in the [`ObjectListTab::Messages`] tab,
+/// using [`InspectorCache::messages`].
+///
+/// Message types are filtered by name using the [`InspectorState::filter_text`].
+pub fn render_message_list(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+) {
+    if state.active_objects_tab != ObjectListTab::Messages {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::Messages {
+            continue;
+        }
+
+        commands
+            .entity(content_entity)
+            .despawn_children()
+            .with_children(|list| {
+                for message in &cache.messages {
+                    let name = message.name.shortname().to_string();
+                    if !name.to_lowercase().contains(&filter) {
+                        continue;
+                    }
+
+                    let (summary, pending) = match &message.buffers {
+                        Some(buffers) => (
+                            format!(
+                                "{} current | {} previous | {} total",
+                                buffers.current_len, buffers.previous_len, buffers.total_written
+                            ),
+                            buffers.pending.clone().unwrap_or_default(),
+                        ),
+                        None => (
+                            format!(
+                                "not registered for inspection: add it using `app.add_inspected_message::<{name}>()`"
+                            ),
+                            Vec::new(),
+                        ),
+                    };
+
+                    list.spawn(Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        margin: UiRect::top(config.item_gap),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(name),
+                            TextFont {
+                                font_size: FontSize::Px(config.body_font_size),
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                        row.spawn((
+                            Text::new(summary),
+                            TextFont {
+                                font_size: FontSize::Px(config.small_font_size),
+                                ..default()
+                            },
+                            TextColor(config.muted_text_color),
+                        ));
+                        for value in pending {
+                            row.spawn((
+                                Text::new(value),
+                                TextFont {
+                                    font_size: FontSize::Px(config.small_font_size),
+                                    ..default()
+                                },
+                                TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                                Node {
+                                    padding: UiRect::left(Px(8.0)),
+                                    ..default()
+                                },
+                            ));
+                        }
+                    });
+                }
+            });
+    }
+}
+
+/// The maximum number of characters of an asset's value shown in the [`ObjectListTab::Assets`] tab,
+/// as the values of assets such as images can be enormous.
+const MAX_ASSET_VALUE_LENGTH: usize = 2000;
+
+/// System that lists the assets in each asset collection in the [`ObjectListTab::Assets`] tab,
+/// using [`InspectorCache::assets`].
+///
+/// Assets are filtered by label using the [`InspectorState::filter_text`],
+/// and the value of the [`InspectorState::selected_asset`] is shown below its row.
+pub fn render_asset_list(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+) {
+    if state.active_objects_tab != ObjectListTab::Assets {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::Assets {
+            continue;
+        }
+
+        commands
+            .entity(content_entity)
+            .despawn_children()
+            .with_children(|list| {
+                for collection in &cache.assets {
+                    let assets: Vec<_> = collection
+                        .assets
+                        .iter()
+                        .filter(|asset| asset.label.to_lowercase().contains(&filter))
+                        .collect();
+                    if assets.is_empty() && !filter.is_empty() {
+                        continue;
+                    }
+
+                    list.spawn((
+                        Text::new(format!(
+                            "{} ({})",
+                            collection.asset_type.shortname(),
+                            assets.len()
+                        )),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        Node {
+                            margin: UiRect::top(config.item_gap),
+                            ..default()
+                        },
+                    ));
+
+                    for asset in assets {
+                        let Some(id) = asset.id else {
+                            continue;
+                        };
+                        let is_selected = state.selected_asset == Some(id);
+                        let label = match &asset.load_state {
+                            Some(load_state) => format!("{} [{load_state}]", asset.label),
+                            None => asset.label.clone(),
+                        };
+
+                        list.spawn(button_bundle(
+                            ButtonBundleProps::default(),
+                            AssetRow(id),
+                            bevy::prelude::Spawn((
+                                Text::new(label),
+                                TextFont {
+                                    font_size: FontSize::Px(config.small_font_size),
+                                    ..default()
+                                },
+                                TextColor(if is_selected {
+                                    Color::WHITE
+                                } else {
+                                    Color::srgba(0.9, 0.9, 0.9, 1.0)
+                                }),
+                            )),
+                        ));
+
+                        if is_selected && let Some(value) = &asset.value {
+                            let value = if value.len() > MAX_ASSET_VALUE_LENGTH {
+                                let end = (0..=MAX_ASSET_VALUE_LENGTH)
+                                    .rev()
+                                    .find(|&index| value.is_char_boundary(index))
+                                    .unwrap_or(0);
+                                format!("{}...", &value[..end])
+                            } else {
+                                value.clone()
+                            };
+                            list.spawn((
+                                Text::new(value),
+                                TextFont {
+                                    font_size: FontSize::Px(config.small_font_size),
+                                    ..default()
+                                },
+                                TextColor(config.muted_text_color),
+                                Node {
+                                    padding: UiRect::left(Px(8.0)),
+                                    ..default()
+                                },
+                            ));
+                        }
+                    }
+                }
+            });
+    }
+}
+
+/// The maximum number of recent transitions shown for each state in the [`ObjectListTab::States`] tab.
+const MAX_STATE_HISTORY_LENGTH: usize = 5;
+
+/// System that lists the current and pending value of each state type in the [`ObjectListTab::States`] tab,
+/// using [`InspectorCache::states`].
+///
+/// State types are filtered by name using the [`InspectorState::filter_text`].
+/// Each unit variant of a state is shown as a [`StateVariantButton`], with the current variant highlighted.
+pub fn render_state_list(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+) {
+    if state.active_objects_tab != ObjectListTab::States {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::States {
+            continue;
+        }
+
+        commands
+            .entity(content_entity)
+            .despawn_children()
+            .with_children(|list| {
+                for inspected_state in &cache.states {
+                    let name = inspected_state.name.shortname().to_string();
+                    if !name.to_lowercase().contains(&filter) {
+                        continue;
+                    }
+
+                    let mut summary = inspected_state
+                        .current
+                        .clone()
+                        .unwrap_or_else(|| "not present".to_string());
+                    if let Some(pending) = &inspected_state.pending {
+                        summary.push_str(&format!(" -> {pending} (pending)"));
+                    }
+
+                    list.spawn(Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        margin: UiRect::top(config.item_gap),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(name),
+                            TextFont {
+                                font_size: FontSize::Px(config.body_font_size),
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                        row.spawn((
+                            Text::new(summary),
+                            TextFont {
+                                font_size: FontSize::Px(config.small_font_size),
+                                ..default()
+                            },
+                            TextColor(config.muted_text_color),
+                        ));
+
+                        if inspected_state.can_queue_transitions {
+                            row.spawn(Node {
+                                display: Display::Flex,
+                                flex_wrap: FlexWrap::Wrap,
+                                column_gap: Px(4.0),
+                                padding: UiRect::left(Px(8.0)),
+                                ..default()
+                            })
+                            .with_children(|buttons| {
+                                for variant in &inspected_state.variants {
+                                    let is_current =
+                                        inspected_state.current_variant.as_ref() == Some(variant);
+                                    buttons.spawn(button_bundle(
+                                        ButtonBundleProps::default(),
+                                        StateVariantButton {
+                                            state: inspected_state.name.to_string(),
+                                            variant: variant.clone(),
+                                        },
+                                        bevy::prelude::Spawn((
+                                            Text::new(variant.clone()),
+                                            TextFont {
+                                                font_size: FontSize::Px(config.small_font_size),
+                                                ..default()
+                                            },
+                                            TextColor(if is_current {
+                                                Color::WHITE
+                                            } else {
+                                                config.muted_text_color
+                                            }),
+                                        )),
+                                    ));
+                                }
+                            });
+                        }
+
+                        let history = &inspected_state.history;
+                        let recent = history.len().saturating_sub(MAX_STATE_HISTORY_LENGTH);
+                        for transition in &history[recent..] {
+                            row.spawn((
+                                Text::new(transition.to_string()),
+                                TextFont {
+                                    font_size: FontSize::Px(config.small_font_size),
+                                    ..default()
+                                },
+                                TextColor(Color::srgba(0.9, 0.9, 0.9, 1.0)),
+                                Node {
+                                    padding: UiRect::left(Px(8.0)),
+                                    ..default()
+                                },
+                            ));
+                        }
+                    });
+                }
+            });
+    }
+}
+
+/// System that lists the non-empty archetypes in the [`ObjectListTab::Archetypes`] tab,
+/// using [`InspectorCache::archetypes`].
+///
+/// Archetypes are filtered by their components' names using the [`InspectorState::filter_text`].
+/// The [`InspectorState::selected_archetype`] is shown in detail below its row, using [`InspectorCache::archetype`]:
+/// its edges are [`ArchetypeRow`]s, and its entities are [`ObjectRow`]s.
+pub fn render_archetype_list(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+) {
+    if state.active_objects_tab != ObjectListTab::Archetypes {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::Archetypes {
+            continue;
+        }
+
+        commands
+            .entity(content_entity)
+            .despawn_children()
+            .with_children(|list| {
+                for archetype in &cache.archetypes {
+                    let signature = archetype.signature_short();
+                    if !signature.to_lowercase().contains(&filter) {
+                        continue;
+                    }
+                    let is_selected = state.selected_archetype == Some(archetype.archetype_id);
+
+                    list.spawn(button_bundle(
+                        ButtonBundleProps::default(),
+                        ArchetypeRow(archetype.archetype_id),
+                        bevy::prelude::Spawn((
+                            Text::new(format!("{signature} ({} entities)", archetype.entity_count)),
+                            TextFont {
+                                font_size: FontSize::Px(config.small_font_size),
+                                ..default()
+                            },
+                            TextColor(if is_selected {
+                                Color::WHITE
+                            } else {
+                                Color::srgba(0.9, 0.9, 0.9, 1.0)
+                            }),
+                        )),
+                    ));
+
+                    if let Some(inspection) = &cache.archetype
+                        && is_selected
+                        && inspection.archetype_id == archetype.archetype_id
+                    {
+                        spawn_archetype_details(list, inspection, &config);
+                    }
+                }
+            });
+    }
+}
+
+/// Spawns the storage, edges and entities of the selected archetype.
+fn spawn_archetype_details(
+    list: &mut ChildSpawnerCommands<'_>,
+    inspection: &ArchetypeInspection,
+    config: &InspectorConfig,
+) {
+    let detail_text = |text: String| {
+        (
+            Text::new(text),
+            TextFont {
+                font_size: FontSize::Px(config.small_font_size),
+                ..default()
+            },
+            TextColor(config.muted_text_color),
+            Node {
+                padding: UiRect::left(Px(8.0)),
+                ..default()
+            },
+        )
+    };
+
+    list.spawn(detail_text(format!(
+        "Table #{}: {} of {} rows used",
+        inspection.table_id.as_u32(),
+        inspection.table_rows,
+        inspection.table_capacity
+    )));
+    for component in &inspection.components {
+        let storage = match component.storage_type {
+            StorageType::Table => "table",
+            StorageType::SparseSet => "sparse set",
+        };
+        list.spawn(detail_text(format!("{component} [{storage}]")));
+    }
+
+    for edge in &inspection.edges {
+        list.spawn(button_bundle(
+            ButtonBundleProps::default(),
+            ArchetypeRow(edge.target),
+            bevy::prelude::Spawn((
+                Text::new(edge.to_string()),
+                TextFont {
+                    font_size: FontSize::Px(config.small_font_size),
+                    ..default()
+                },
+                TextColor(config.muted_text_color),
+            )),
+        ));
+    }
+
+    for entity in &inspection.entity_sample {
+        list.spawn(button_bundle(
+            ButtonBundleProps::default(),
+            ObjectRow {
+                selected_object: *entity,
+            },
+            bevy::prelude::Spawn((
+                Text::new(entity.to_string()),
+                TextFont {
+                    font_size: FontSize::Px(config.small_font_size),
+                    ..default()
+                },
+                TextColor(config.muted_text_color),
+            )),
+        ));
+    }
+    let omitted = inspection.entity_count - inspection.entity_sample.len();
+    if omitted > 0 {
+        list.spawn(detail_text(format!("... and {omitted} more entities")));
+    }
+}
+
+/// System that shows the entity count over time as a bar chart in the [`ObjectListTab::Trends`] tab,
+/// using [`InspectorCache::entity_count_history`], followed by the [`InspectorCache::summary_trend`].
+///
+/// The component count changes are filtered by name using the [`InspectorState::filter_text`].
+pub fn render_trend_chart(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+) {
+    if state.active_objects_tab != ObjectListTab::Trends {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::Trends {
+            continue;
+        }
+
+        commands
+            .entity(content_entity)
+            .despawn_children()
+            .with_children(|list| {
+                let small_text = |text: String, color: Color| {
+                    (
+                        Text::new(text),
+                        TextFont {
+                            font_size: FontSize::Px(config.small_font_size),
+                            ..default()
+                        },
+                        TextColor(color),
+                    )
+                };
+
+                let Some(entity_counts) = &cache.entity_count_history else {
+                    list.spawn(small_text(
+                        "Add the `SummaryHistoryPlugin` to record trends".to_string(),
+                        config.muted_text_color,
+                    ));
+                    return;
+                };
+                let max = entity_counts.iter().copied().max().unwrap_or(0).max(1);
+                let min = entity_counts.iter().copied().min().unwrap_or(0);
+
+                list.spawn((
+                    Text::new(format!("Entities ({} samples)", entity_counts.len())),
+                    TextFont {
+                        font_size: FontSize::Px(config.body_font_size),
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                list.spawn((
+                    Node {
+                        width: Percent(100.0),
+                        height: config.chart_height,
+                        display: Display::Flex,
+                        align_items: AlignItems::FlexEnd,
+                        column_gap: Px(1.0),
+                        border: UiRect::bottom(Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor::all(config.border_color),
+                ))
+                .with_children(|chart| {
+                    for &count in entity_counts {
+                        chart.spawn((
+                            Node {
+                                flex_grow: 1.0,
+                                height: Percent(count as f32 / max as f32 * 100.0),
+                                ..default()
+                            },
+                            BackgroundColor(config.chart_color),
+                        ));
+                    }
+                });
+                list.spawn(small_text(
+                    format!("min {min}, max {max}"),
+                    config.muted_text_color,
+                ));
+
+                let Some(trend) = &cache.summary_trend else {
+                    list.spawn(small_text(
+                        "Waiting for more samples...".to_string(),
+                        config.muted_text_color,
+                    ));
+                    return;
+                };
+                if trend.possible_leak {
+                    list.spawn(small_text(
+                        "The entity count has grown steadily: possible entity leak".to_string(),
+                        config.error_text_color,
+                    ));
+                }
+                list.spawn(small_text(
+                    format!(
+                        "{:+} entities since last sample, {:+.2}/s over {:.0}s",
+                        trend.entity_delta,
+                        trend.entities_per_second,
+                        trend.duration.as_secs_f64()
+                    ),
+                    Color::srgba(0.9, 0.9, 0.9, 1.0),
+                ));
+                if trend.new_archetypes > 0 {
+                    list.spawn(small_text(
+                        format!("{} new archetypes", trend.new_archetypes),
+                        Color::srgba(0.9, 0.9, 0.9, 1.0),
+                    ));
+                }
+                for component_delta in &trend.component_deltas {
+                    let name = component_delta.name.shortname().to_string();
+                    if !name.to_lowercase().contains(&filter) {
+                        continue;
+                    }
+                    list.spawn(small_text(
+                        component_delta.to_string(),
+                        config.muted_text_color,
+                    ));
+                }
+            });
+    }
+}
+
+/// System that shows how often each component type is changed as a table in the [`ObjectListTab::Changes`] tab,
+/// using [`InspectorCache::change_profile`], followed by the entities whose components changed most.
+///
+/// Component types are filtered by name using the [`InspectorState::filter_text`].
+/// Each entity is an [`ObjectRow`], which selects it when clicked.
+pub fn render_change_profile_table(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+) {
+    if state.active_objects_tab != ObjectListTab::Changes {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::Changes {
+            continue;
+        }
+
+        commands
+            .entity(content_entity)
+            .despawn_children()
+            .with_children(|list| {
+                let small_text = |text: String, color: Color| {
+                    (
+                        Text::new(text),
+                        TextFont {
+                            font_size: FontSize::Px(config.small_font_size),
+                            ..default()
+                        },
+                        TextColor(color),
+                    )
+                };
+
+                let Some((frames_sampled, window_frames)) = cache.change_profile_progress else {
+                    list.spawn(small_text(
+                        "Add the `ChangeProfilerPlugin` to profile component changes".to_string(),
+                        config.muted_text_color,
+                    ));
+                    return;
+                };
+                let Some(report) = &cache.change_profile else {
+                    list.spawn(small_text(
+                        format!("Sampling... ({frames_sampled}/{window_frames} frames)"),
+                        config.muted_text_color,
+                    ));
+                    return;
+                };
+
+                list.spawn((
+                    Text::new(format!("Component changes ({} frames)", report.frames)),
+                    TextFont {
+                        font_size: FontSize::Px(config.body_font_size),
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                list.spawn(Node {
+                    display: Display::Grid,
+                    grid_template_columns: vec![
+                        GridTrack::fr(1.0),
+                        GridTrack::auto(),
+                        GridTrack::auto(),
+                        GridTrack::auto(),
+                    ],
+                    column_gap: config.column_gap,
+                    row_gap: Px(2.0),
+                    ..default()
+                })
+                .with_children(|table| {
+                    for header in ["Component", "Per frame", "Peak", "Frames"] {
+                        table.spawn(small_text(header.to_string(), config.muted_text_color));
+                    }
+                    for component in &report.components {
+                        let name = component.name.shortname().to_string();
+                        if !name.to_lowercase().contains(&filter) {
+                            continue;
+                        }
+                        // Components changed every frame are the likeliest source of needless work
+                        let color = if component.changed_every_frame {
+                            config.error_text_color
+                        } else {
+                            Color::srgba(0.9, 0.9, 0.9, 1.0)
+                        };
+                        table.spawn(small_text(name, color));
+                        table.spawn(small_text(
+                            format!("{:.1}", component.changes_per_frame),
+                            color,
+                        ));
+                        table.spawn(small_text(
+                            component.max_changes_per_frame.to_string(),
+                            color,
+                        ));
+                        table.spawn(small_text(
+                            format!("{}/{}", component.frames_changed, report.frames),
+                            color,
+                        ));
+                    }
+                });
+
+                if report.entities.is_empty() {
+                    return;
+                }
+                list.spawn((
+                    Text::new("Hottest entities"),
+                    TextFont {
+                        font_size: FontSize::Px(config.body_font_size),
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                for entity in &report.entities {
+                    list.spawn(button_bundle(
+                        ButtonBundleProps::default(),
+                        ObjectRow {
+                            selected_object: entity.entity,
+                        },
+                        bevy::prelude::Spawn(small_text(
+                            entity.to_string(),
+                            Color::srgba(0.9, 0.9, 0.9, 1.0),
+                        )),
+                    ));
+                }
+            });
+    }
+}
+
+/// System that lists the types in the [`ObjectListTab::TypeRegistry`] tab,
+/// starting with the [`InspectorCache::missing_registrations`] and their hints,
+/// followed by the [`InspectorCache::registered_types`] and their type data.
+///
+/// Types are filtered by their path using the [`InspectorState::filter_text`],
+/// and at most [`InspectorConfig::object_list_page_size`] registered types are shown.
+pub fn render_type_registry_list(
+    mut commands: Commands,
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    list_content: Query<(Entity, &ObjectListContent)>,
+) {
+    if state.active_objects_tab != ObjectListTab::TypeRegistry {
+        return;
+    }
+
+    let filter = state.filter_text.to_lowercase();
+    for (content_entity, object_list_content) in &list_content {
+        if object_list_content.tab != ObjectListTab::TypeRegistry {
+            continue;
+        }
+
+        commands
+            .entity(content_entity)
+            .despawn_children()
+            .with_children(|list| {
+                let heading = |text: String| {
+                    (
+                        Text::new(text),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    )
+                };
+                let small_text = |text: String, color: Color| {
+                    (
+                        Text::new(text),
+                        TextFont {
+                            font_size: FontSize::Px(config.small_font_size),
+                            ..default()
+                        },
+                        TextColor(color),
+                    )
+                };
+
+                let missing: Vec<_> = cache
+                    .missing_registrations
+                    .iter()
+                    .filter(|missing| missing.name.to_lowercase().contains(&filter))
+                    .collect();
+                if !missing.is_empty() {
+                    list.spawn(heading(format!(
+                        "Missing registrations ({})",
+                        missing.len()
+                    )));
+                    for missing in missing {
+                        list.spawn(small_text(
+                            missing.name.shortname().to_string(),
+                            config.error_text_color,
+                        ));
+                        list.spawn((
+                            small_text(missing.hint.clone(), config.muted_text_color),
+                            Node {
+                                padding: UiRect::left(Px(8.0)),
+                                ..default()
+                            },
+                        ));
+                    }
+                }
+
+                let registered: Vec<_> = cache
+                    .registered_types
+                    .iter()
+                    .filter(|registered| registered.type_path.to_lowercase().contains(&filter))
+                    .collect();
+                list.spawn(heading(format!("Registered types ({})", registered.len())));
+                for registered in registered.iter().take(config.object_list_page_size) {
+                    list.spawn(small_text(
+                        registered.to_string(),
+                        Color::srgba(0.9, 0.9, 0.9, 1.0),
+                    ));
+                }
+                let omitted = registered
+                    .len()
+                    .saturating_sub(config.object_list_page_size);
+                if omitted > 0 {
+                    list.spawn(small_text(
+                        format!("... and {omitted} more types"),
+                        config.muted_text_color,
+                    ));
+                }
+            });
+    }
+}
+
+/// System that syncs the search bar text with the [`InspectorState`].
+///
+/// Shows a placeholder when no filter is set, and the parse error if the filter is not a valid query.
+pub fn render_search_input(
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    search_inputs: Query<&Children, With<SearchInput>>,
+    mut texts: Query<(&mut Text, &mut TextColor)>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+
+    let (content, color) = match &state.filter_error {
+        Some(error) => (
+            format!("{} ({error})", state.filter_text),
+            config.error_text_color,
+        ),
+        None if state.filter_text.is_empty() => ("Search...".to_string(), config.muted_text_color),
+        None => (state.filter_text.clone(), Color::WHITE),
+    };
+
+    for children in &search_inputs {
+        for child in children {
+            if let Ok((mut text, mut text_color)) = texts.get_mut(*child) {
+                text.0.clone_from(&content);
+                text_color.0 = color;
+            }
+        }
+    }
+}
+
+/// System that shows which objects are on the current page of the object list.
+pub fn render_object_list_pagination(
+    cache: Res<InspectorCache>,
+    state: Res<InspectorState>,
+    config: Res<InspectorConfig>,
+    mut labels: Query<&mut Text, With<ObjectListPageLabel>>,
+) {
+    if !cache.is_changed() && !state.is_changed() {
+        return;
+    }
+
+    let first = state.object_list_page * config.object_list_page_size;
+    let label = if cache.filtered_objects.is_empty() {
+        format!("0 of {}", cache.total_objects)
+    } else {
+        format!(
+            "{}-{} of {}",
+            first + 1,
+            first + cache.filtered_objects.len(),
+            cache.total_objects
+        )
+    };
+
+    for mut text in &mut labels {
+        text.0.clone_from(&label);
+    }
+}
+
 /// Spawns a single object row button.
 fn spawn_object_row(
     parent: &mut ChildSpawnerCommands<'_>,
@@ -214,51 +1390,200 @@ fn update_object_row(
     }
 }
 
-/// Observes [`TabActivated`] events to update the active objects tab
-/// in the [`InspectorState`].
-pub fn update_active_objects_tab_on_tab_activated(
-    on_activate_tab: On<TabActivated>,
-    has_contents: Query<&HasContent, With<Tab>>,
-    object_list_contents: Query<&ObjectListContent>,
+/// Observes [`TabActivated`] events to update the active objects tab
+/// in the [`InspectorState`].
+pub fn update_active_objects_tab_on_tab_activated(
+    on_activate_tab: On<TabActivated>,
+    has_contents: Query<&HasContent, With<Tab>>,
+    object_list_contents: Query<&ObjectListContent>,
+    children: Query<&Children>,
+    mut state: ResMut<InspectorState>,
+    mut refresh_cache: MessageWriter<RefreshCache>,
+) {
+    let event = on_activate_tab.event();
+    if let Ok(has_content) = has_contents.get(event.tab) {
+        let content_parent_entity = has_content.0;
+        if let Ok(children) = children.get(content_parent_entity) {
+            for child in children {
+                if let Ok(object_list_content) = object_list_contents.get(*child) {
+                    state.active_objects_tab = object_list_content.tab.clone();
+                    state.object_list_page = 0;
+                    refresh_cache.write_default();
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Global observer for object row clicks.
+/// Added in [`InspectorWindowPlugin`](crate::gui::plugin::InspectorWindowPlugin).
+///
+/// Expects the `Activate` event target to hold the [`ObjectRow`] component.
+pub fn on_object_row_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    rows: Query<&ObjectRow>,
+    mut refresh_cache: MessageWriter<RefreshCache>,
+) {
+    if let Ok(row) = rows.get(activate.entity) {
+        state.selected_object = Some(row.selected_object);
+        refresh_cache.write_default();
+    }
+}
+
+/// Global observer for asset row clicks.
+/// Added in [`InspectorWindowPlugin`](crate::gui::plugin::InspectorWindowPlugin).
+///
+/// Clicking the selected asset again deselects it.
+pub fn on_asset_row_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    rows: Query<&AssetRow>,
+    mut refresh_cache: MessageWriter<RefreshCache>,
+) {
+    if let Ok(row) = rows.get(activate.entity) {
+        state.selected_asset = if state.selected_asset == Some(row.0) {
+            None
+        } else {
+            Some(row.0)
+        };
+        refresh_cache.write_default();
+    }
+}
+
+/// Global observer for non-`Send` resource row clicks.
+/// Added in [`InspectorWindowPlugin`](crate::gui::plugin::InspectorWindowPlugin).
+///
+/// Clicking the selected resource again deselects it.
+pub fn on_non_send_resource_row_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    rows: Query<&NonSendResourceRow>,
+    mut refresh_cache: MessageWriter<RefreshCache>,
+) {
+    if let Ok(row) = rows.get(activate.entity) {
+        state.selected_non_send_resource = if state.selected_non_send_resource == Some(row.0) {
+            None
+        } else {
+            Some(row.0)
+        };
+        refresh_cache.write_default();
+    }
+}
+
+/// Global observer for archetype row clicks.
+/// Added in [`InspectorWindowPlugin`](crate::gui::plugin::InspectorWindowPlugin).
+///
+/// Clicking the selected archetype again deselects it.
+pub fn on_archetype_row_click(
+    activate: On<Activate>,
+    mut state: ResMut<InspectorState>,
+    rows: Query<&ArchetypeRow>,
+    mut refresh_cache: MessageWriter<RefreshCache>,
+) {
+    if let Ok(row) = rows.get(activate.entity) {
+        state.selected_archetype = if state.selected_archetype == Some(row.0) {
+            None
+        } else {
+            Some(row.0)
+        };
+        refresh_cache.write_default();
+    }
+}
+
+/// Global observer for [`StateVariantButton`] clicks.
+/// Added in [`InspectorWindowPlugin`](crate::gui::plugin::InspectorWindowPlugin).
+pub fn on_state_variant_button_click(
+    activate: On<Activate>,
+    mut commands: Commands,
+    buttons: Query<&StateVariantButton>,
+    mut refresh_cache: MessageWriter<RefreshCache>,
+) {
+    if let Ok(button) = buttons.get(activate.entity) {
+        let state = button.state.clone();
+        let variant = button.variant.clone();
+        commands.queue(move |world: &mut World| {
+            if let Err(error) = world.queue_state_transition(&state, &variant) {
+                warn!("Could not queue state transition: {error}");
+            }
+        });
+        // Show the pending transition
+        refresh_cache.write(RefreshCache { force: true });
+    }
+}
+
+/// Global observer for [`OpenAsset`] events.
+/// Added in [`InspectorWindowPlugin`](crate::gui::plugin::InspectorWindowPlugin).
+///
+/// Selects the asset, and activates the tab showing the [`ObjectListTab::Assets`] content.
+pub fn open_asset_on_open_asset(
+    open_asset: On<OpenAsset>,
+    tabs: Query<(Entity, &HasContent, &ChildOf), With<Tab>>,
     children: Query<&Children>,
+    object_list_contents: Query<&ObjectListContent>,
     mut state: ResMut<InspectorState>,
     mut refresh_cache: MessageWriter<RefreshCache>,
+    mut commands: Commands,
 ) {
-    let event = on_activate_tab.event();
-    if let Ok(has_content) = has_contents.get(event.tab) {
-        let content_parent_entity = has_content.0;
-        if let Ok(children) = children.get(content_parent_entity) {
-            for child in children {
-                if let Ok(object_list_content) = object_list_contents.get(*child) {
-                    let object_list_tab = object_list_content.tab;
+    state.selected_asset = Some(open_asset.0);
+    refresh_cache.write_default();
 
-                    state.active_objects_tab = object_list_tab;
-                    refresh_cache.write_default();
-                    return;
-                }
-            }
-        }
+    let assets_tab = tabs.iter().find(|(_, has_content, _)| {
+        children.get(has_content.0).is_ok_and(|children| {
+            children.into_iter().any(|child| {
+                object_list_contents
+                    .get(*child)
+                    .is_ok_and(|content| content.tab == ObjectListTab::Assets)
+            })
+        })
+    });
+    if let Some((tab, _, child_of)) = assets_tab {
+        commands.trigger(ActivateTab {
+            group: child_of.parent(),
+            tab,
+        });
     }
 }
 
-/// Global observer for object row clicks.
+/// Global observer for the [`ObjectListPageButton`]s.
 /// Added in [`InspectorWindowPlugin`](crate::gui::plugin::InspectorWindowPlugin).
-///
-/// Expects the `Activate` event target to hold the [`ObjectRow`] component.
-pub fn on_object_row_click(
+pub fn on_object_list_page_button_click(
     activate: On<Activate>,
+    buttons: Query<&ObjectListPageButton>,
+    cache: Res<InspectorCache>,
+    config: Res<InspectorConfig>,
     mut state: ResMut<InspectorState>,
-    rows: Query<&ObjectRow>,
     mut refresh_cache: MessageWriter<RefreshCache>,
 ) {
-    if let Ok(row) = rows.get(activate.entity) {
-        state.selected_object = Some(row.selected_object);
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+
+    let page_count = cache
+        .total_objects
+        .div_ceil(config.object_list_page_size.max(1));
+    let page = match button {
+        ObjectListPageButton::Previous => state.object_list_page.saturating_sub(1),
+        ObjectListPageButton::Next => {
+            (state.object_list_page + 1).min(page_count.saturating_sub(1))
+        }
+    };
+
+    if page != state.object_list_page {
+        state.object_list_page = page;
         refresh_cache.write_default();
     }
 }
 
 /// Spawns the object list panel structure.
-pub fn spawn_object_list_panel(parent: &mut ChildSpawnerCommands<'_>, config: &InspectorConfig) {
+///
+/// An extra tab is spawned for each of the `categories`, after the built-in tabs.
+pub fn spawn_object_list_panel(
+    parent: &mut ChildSpawnerCommands<'_>,
+    config: &InspectorConfig,
+    categories: &[EntityCategory],
+) {
     parent
         .spawn((
             Node {
@@ -330,15 +1655,147 @@ pub fn spawn_object_list_panel(parent: &mut ChildSpawnerCommands<'_>, config: &I
                     )),
                 ))
                 .id();
+            let systems_tab_entity = panel
+                .commands()
+                .spawn(button_bundle(
+                    ButtonBundleProps::default(),
+                    Tab,
+                    bevy::prelude::Spawn((
+                        Text::new("Systems"),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                    )),
+                ))
+                .id();
+            let messages_tab_entity = panel
+                .commands()
+                .spawn(button_bundle(
+                    ButtonBundleProps::default(),
+                    Tab,
+                    bevy::prelude::Spawn((
+                        Text::new("Messages"),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                    )),
+                ))
+                .id();
+            let assets_tab_entity = panel
+                .commands()
+                .spawn(button_bundle(
+                    ButtonBundleProps::default(),
+                    Tab,
+                    bevy::prelude::Spawn((
+                        Text::new("Assets"),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                    )),
+                ))
+                .id();
+            let states_tab_entity = panel
+                .commands()
+                .spawn(button_bundle(
+                    ButtonBundleProps::default(),
+                    Tab,
+                    bevy::prelude::Spawn((
+                        Text::new("States"),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                    )),
+                ))
+                .id();
+            let archetypes_tab_entity = panel
+                .commands()
+                .spawn(button_bundle(
+                    ButtonBundleProps::default(),
+                    Tab,
+                    bevy::prelude::Spawn((
+                        Text::new("Archetypes"),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                    )),
+                ))
+                .id();
+            let trends_tab_entity = panel
+                .commands()
+                .spawn(button_bundle(
+                    ButtonBundleProps::default(),
+                    Tab,
+                    bevy::prelude::Spawn((
+                        Text::new("Trends"),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                    )),
+                ))
+                .id();
+            let changes_tab_entity = panel
+                .commands()
+                .spawn(button_bundle(
+                    ButtonBundleProps::default(),
+                    Tab,
+                    bevy::prelude::Spawn((
+                        Text::new("Changes"),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                    )),
+                ))
+                .id();
+            let types_tab_entity = panel
+                .commands()
+                .spawn(button_bundle(
+                    ButtonBundleProps::default(),
+                    Tab,
+                    bevy::prelude::Spawn((
+                        Text::new("Types"),
+                        TextFont {
+                            font_size: FontSize::Px(config.body_font_size),
+                            ..default()
+                        },
+                    )),
+                ))
+                .id();
+            let category_tab_entities: Vec<Entity> = categories
+                .iter()
+                .map(|category| {
+                    panel
+                        .commands()
+                        .spawn(button_bundle(
+                            ButtonBundleProps::default(),
+                            Tab,
+                            bevy::prelude::Spawn((
+                                Text::new(category.to_string()),
+                                TextFont {
+                                    font_size: FontSize::Px(config.body_font_size),
+                                    ..default()
+                                },
+                            )),
+                        ))
+                        .id()
+                })
+                .collect();
             let _tab_group_entity = panel
                 .spawn((
                     Node {
                         width: Percent(100.0),
-                        height: config.tab_bar_height,
+                        min_height: config.tab_bar_height,
                         border: UiRect::bottom(Px(1.0)),
-                        // Space out tab items
+                        // Space out tab items, wrapping onto new rows when there are many categories
                         justify_content: JustifyContent::SpaceEvenly,
                         align_items: AlignItems::Center,
+                        flex_wrap: FlexWrap::Wrap,
                         ..default()
                     },
                     BorderColor::all(config.border_color),
@@ -349,7 +1806,16 @@ pub fn spawn_object_list_panel(parent: &mut ChildSpawnerCommands<'_>, config: &I
                     resources_tab_entity,
                     observers_tab_entity,
                     one_shot_systems_tab_entity,
+                    systems_tab_entity,
+                    messages_tab_entity,
+                    assets_tab_entity,
+                    states_tab_entity,
+                    archetypes_tab_entity,
+                    trends_tab_entity,
+                    changes_tab_entity,
+                    types_tab_entity,
                 ])
+                .add_children(&category_tab_entities)
                 .id();
 
             // Search bar placeholder
@@ -407,6 +1873,54 @@ pub fn spawn_object_list_panel(parent: &mut ChildSpawnerCommands<'_>, config: &I
                         ObjectListTab::OneShotSystems,
                         Display::None,
                     );
+                    let systems_list_entity = scrollable_area(
+                        content_panels_container,
+                        config,
+                        ObjectListTab::Systems,
+                        Display::None,
+                    );
+                    let messages_list_entity = scrollable_area(
+                        content_panels_container,
+                        config,
+                        ObjectListTab::Messages,
+                        Display::None,
+                    );
+                    let assets_list_entity = scrollable_area(
+                        content_panels_container,
+                        config,
+                        ObjectListTab::Assets,
+                        Display::None,
+                    );
+                    let states_list_entity = scrollable_area(
+                        content_panels_container,
+                        config,
+                        ObjectListTab::States,
+                        Display::None,
+                    );
+                    let archetypes_list_entity = scrollable_area(
+                        content_panels_container,
+                        config,
+                        ObjectListTab::Archetypes,
+                        Display::None,
+                    );
+                    let trends_list_entity = scrollable_area(
+                        content_panels_container,
+                        config,
+                        ObjectListTab::Trends,
+                        Display::None,
+                    );
+                    let changes_list_entity = scrollable_area(
+                        content_panels_container,
+                        config,
+                        ObjectListTab::Changes,
+                        Display::None,
+                    );
+                    let types_list_entity = scrollable_area(
+                        content_panels_container,
+                        config,
+                        ObjectListTab::TypeRegistry,
+                        Display::None,
+                    );
                     content_panels_container
                         .commands()
                         .entity(entities_tab_entity)
@@ -435,10 +1949,128 @@ pub fn spawn_object_list_panel(parent: &mut ChildSpawnerCommands<'_>, config: &I
                             HasContent(one_shot_systems_list_entity),
                             TabContentDisplayMode(Display::Grid),
                         ));
+                    content_panels_container
+                        .commands()
+                        .entity(systems_tab_entity)
+                        .insert((
+                            HasContent(systems_list_entity),
+                            TabContentDisplayMode(Display::Grid),
+                        ));
+                    content_panels_container
+                        .commands()
+                        .entity(messages_tab_entity)
+                        .insert((
+                            HasContent(messages_list_entity),
+                            TabContentDisplayMode(Display::Grid),
+                        ));
+                    content_panels_container
+                        .commands()
+                        .entity(assets_tab_entity)
+                        .insert((
+                            HasContent(assets_list_entity),
+                            TabContentDisplayMode(Display::Grid),
+                        ));
+                    content_panels_container
+                        .commands()
+                        .entity(states_tab_entity)
+                        .insert((
+                            HasContent(states_list_entity),
+                            TabContentDisplayMode(Display::Grid),
+                        ));
+                    content_panels_container
+                        .commands()
+                        .entity(archetypes_tab_entity)
+                        .insert((
+                            HasContent(archetypes_list_entity),
+                            TabContentDisplayMode(Display::Grid),
+                        ));
+                    content_panels_container
+                        .commands()
+                        .entity(trends_tab_entity)
+                        .insert((
+                            HasContent(trends_list_entity),
+                            TabContentDisplayMode(Display::Grid),
+                        ));
+                    content_panels_container
+                        .commands()
+                        .entity(changes_tab_entity)
+                        .insert((
+                            HasContent(changes_list_entity),
+                            TabContentDisplayMode(Display::Grid),
+                        ));
+                    content_panels_container
+                        .commands()
+                        .entity(types_tab_entity)
+                        .insert((
+                            HasContent(types_list_entity),
+                            TabContentDisplayMode(Display::Grid),
+                        ));
+                    for (category, tab_entity) in categories.iter().zip(&category_tab_entities) {
+                        let list_entity = scrollable_area(
+                            content_panels_container,
+                            config,
+                            ObjectListTab::Category(category.clone()),
+                            Display::None,
+                        );
+                        content_panels_container
+                            .commands()
+                            .entity(*tab_entity)
+                            .insert((
+                                HasContent(list_entity),
+                                TabContentDisplayMode(Display::Grid),
+                            ));
+                    }
+                });
+
+            // Pagination
+            panel
+                .spawn((
+                    Node {
+                        width: Percent(100.0),
+                        padding: config.panel_padding,
+                        border: UiRect::top(Px(1.0)),
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor::all(config.border_color),
+                ))
+                .with_children(|pagination| {
+                    pagination_button(pagination, config, ObjectListPageButton::Previous, "<");
+                    pagination.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: FontSize::Px(config.small_font_size),
+                            ..default()
+                        },
+                        TextColor(config.muted_text_color),
+                        ObjectListPageLabel,
+                    ));
+                    pagination_button(pagination, config, ObjectListPageButton::Next, ">");
                 });
         });
 }
 
+/// Spawns a button that moves between pages of the object list.
+fn pagination_button(
+    parent: &mut ChildSpawnerCommands<'_>,
+    config: &InspectorConfig,
+    button: ObjectListPageButton,
+    label: &str,
+) {
+    parent.spawn(button_bundle(
+        ButtonBundleProps::default(),
+        button,
+        bevy::prelude::Spawn((
+            Text::new(label),
+            TextFont {
+                font_size: FontSize::Px(config.body_font_size),
+                ..default()
+            },
+        )),
+    ));
+}
+
 fn scrollable_area(
     parent: &mut ChildSpawnerCommands<'_>,
     config: &InspectorConfig,
@@ -521,7 +2153,7 @@ mod tests {
             entity,
             display_name: "TestEntity".to_string(),
             component_count: 0,
-            memory_size: crate::memory_size::MemorySize(0),
+            memory_size: crate::memory_size::MemoryEstimate::Unknown,
         }
     }
 
@@ -555,4 +2187,37 @@ mod tests {
         let row_was_spawned = app.world().get::<ObjectRow>(children[0]).is_some();
         assert!(row_was_spawned);
     }
+
+    #[test]
+    fn pagination_label_shows_the_current_range() {
+        let mut app = setup_test_app();
+        let page_size = app
+            .world()
+            .resource::<InspectorConfig>()
+            .object_list_page_size;
+
+        let mut cache = app.world_mut().resource_mut::<InspectorCache>();
+        cache.filtered_objects = vec![
+            create_test_entry(Entity::from_bits(1)),
+            create_test_entry(Entity::from_bits(2)),
+        ];
+        cache.total_objects = page_size + 2;
+        app.world_mut()
+            .resource_mut::<InspectorState>()
+            .object_list_page = 1;
+
+        let label = app
+            .world_mut()
+            .spawn((Text::new(""), ObjectListPageLabel))
+            .id();
+
+        app.add_systems(Update, render_object_list_pagination);
+        app.update();
+
+        let text = app.world().get::<Text>(label).unwrap();
+        assert_eq!(
+            text.0,
+            format!("{}-{} of {}", page_size + 1, page_size + 2, page_size + 2)
+        );
+    }
 }
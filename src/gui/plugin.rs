@@ -17,12 +17,27 @@ use bevy::ui::Val::*;
 use bevy::ui_widgets::Activate;
 use bevy::window::{PrimaryWindow, WindowCloseRequested, WindowRef, WindowResolution};
 
-use crate::gui::cache::{InspectorCache, periodically_refresh_cache, update_inspector_cache};
-use crate::gui::panels::{on_object_row_click, update_active_objects_tab_on_tab_activated};
+use crate::entity_categories::CategoryRegistry;
+use crate::gui::cache::{
+    InspectorCache, periodically_refresh_cache, update_archetype_cache, update_asset_cache,
+    update_change_profile_cache, update_inspector_cache, update_message_cache,
+    update_non_send_resource_cache, update_schedule_cache, update_state_cache, update_trend_cache,
+    update_type_registry_cache,
+};
+use crate::gui::panels::{
+    on_archetype_row_click, on_asset_row_click, on_non_send_resource_row_click,
+    on_object_list_page_button_click, on_object_row_click, on_state_variant_button_click,
+    open_asset_on_open_asset, render_object_list_pagination,
+    update_active_objects_tab_on_tab_activated,
+};
+use crate::inspection::resource_inspection::NonSendThread;
 
 use super::config::InspectorConfig;
 use super::panels::{
-    render_detail_panel, render_object_list, spawn_detail_panel, spawn_object_list_panel,
+    render_archetype_list, render_asset_list, render_change_profile_table, render_detail_panel,
+    render_message_list, render_non_send_resource_list, render_object_list, render_schedule_list,
+    render_search_input, render_state_list, render_trend_chart, render_type_registry_list,
+    spawn_detail_panel, spawn_object_list_panel,
 };
 use super::state::{InspectorInternal, InspectorState};
 use super::widgets::drag_value::DragValuePlugin;
@@ -74,6 +89,8 @@ impl Plugin for InspectorWindowPlugin {
             .init_resource::<InspectorConfig>()
             .init_resource::<InspectorState>()
             .init_resource::<InspectorCache>()
+            // Non-`Send` resources are inserted on the thread that builds the app
+            .insert_resource(NonSendThread::current())
             // Messages
             .add_message::<SetInspectorWindow>()
             .add_message::<RefreshCache>()
@@ -106,6 +123,17 @@ impl Plugin for InspectorWindowPlugin {
             .add_systems(Startup, order_inspector_window_creation)
             // PreUpdate systems
             .add_systems(PreUpdate, periodically_refresh_cache)
+            // Last systems
+            // Schedules and messages are inspected after `Update` has finished running,
+            // see `update_schedule_cache` and `update_message_cache`
+            .add_systems(
+                Last,
+                (
+                    (update_schedule_cache, render_schedule_list).chain(),
+                    (update_message_cache, render_message_list).chain(),
+                )
+                    .run_if(on_message::<RefreshCache>),
+            )
             // Update systems
             .add_systems(
                 Update,
@@ -113,7 +141,17 @@ impl Plugin for InspectorWindowPlugin {
                     // Input handling
                     (handle_mouse_wheel_scroll, handle_toggle_key).in_set(InspectorSet::Input),
                     // Cache refresh
-                    update_inspector_cache.in_set(InspectorSet::CacheUpdate),
+                    (
+                        update_inspector_cache,
+                        update_asset_cache,
+                        update_state_cache,
+                        update_non_send_resource_cache,
+                        update_archetype_cache,
+                        update_trend_cache,
+                        update_change_profile_cache,
+                        update_type_registry_cache,
+                    )
+                        .in_set(InspectorSet::CacheUpdate),
                     // UI sync - chain these to avoid resource conflicts
                     (toggle_inspector_window, setup_inspector_ui)
                         .chain()
@@ -121,6 +159,15 @@ impl Plugin for InspectorWindowPlugin {
                     // Render systems (Unconditional)
                     (
                         render_object_list,
+                        render_non_send_resource_list,
+                        render_asset_list,
+                        render_state_list,
+                        render_archetype_list,
+                        render_trend_chart,
+                        render_change_profile_table,
+                        render_type_registry_list,
+                        render_object_list_pagination,
+                        render_search_input,
                         render_detail_panel,
                         update_toolbar_buttons,
                     )
@@ -131,6 +178,12 @@ impl Plugin for InspectorWindowPlugin {
             .add_observer(toggle_is_paused_on_activate)
             .add_observer(manual_refresh_on_activate)
             .add_observer(on_object_row_click)
+            .add_observer(on_asset_row_click)
+            .add_observer(on_non_send_resource_row_click)
+            .add_observer(on_state_variant_button_click)
+            .add_observer(on_archetype_row_click)
+            .add_observer(open_asset_on_open_asset)
+            .add_observer(on_object_list_page_button_click)
             .add_observer(update_active_objects_tab_on_tab_activated);
     }
 }
@@ -258,12 +311,16 @@ fn setup_inspector_ui(
     mut commands: Commands,
     config: Res<InspectorConfig>,
     state: Res<InspectorState>,
+    category_registry: Option<Res<CategoryRegistry>>,
     inspector_windows: Query<Entity, (With<InspectorWindow>, Without<InspectorUiInitialized>)>,
     mut refresh_cache: MessageWriter<RefreshCache>,
 ) {
     let Some(window_entity) = inspector_windows.iter().next() else {
         return;
     };
+    let categories = category_registry
+        .map(|registry| registry.categories())
+        .unwrap_or_default();
 
     // Mark window as initialized
     commands
@@ -309,7 +366,7 @@ fn setup_inspector_ui(
             },))
                 .with_children(|content| {
                     // Left panel: Object list
-                    spawn_object_list_panel(content, &config);
+                    spawn_object_list_panel(content, &config, &categories);
                     // Right panel: Detail view
                     spawn_detail_panel(content, &config);
                 });
@@ -5,15 +5,16 @@
 //!
 //! This information is then used to drive the UI rendering in the various panels.
 
+use bevy::asset::UntypedAssetId;
+use bevy::ecs::archetype::ArchetypeId;
 use bevy::ecs::component::ComponentId;
 use bevy::prelude::*;
 
-use crate::memory_size::MemorySize;
+use crate::entity_categories::EntityCategory;
+use crate::inspection::entity_query::EntityQueryError;
+use crate::memory_size::MemoryEstimate;
 
-/// Marker component for inspector-internal entities that should not appear in the entity list.
-/// Applied to cameras, observers, and other internal entities.
-#[derive(Component)]
-pub struct InspectorInternal;
+pub use crate::inspector_entities::{InspectorInternal, is_inspector_entity};
 
 /// Central UI state for the inspector.
 /// All UI-related state flows through this resource.
@@ -29,6 +30,14 @@ pub struct InspectorState {
     pub selected_object: Option<Entity>,
     /// Previous selected object for change detection.
     pub previous_selected_object: Option<Entity>,
+    /// Currently selected asset in the [`ObjectListTab::Assets`] tab, whose value is shown.
+    pub selected_asset: Option<UntypedAssetId>,
+    /// Currently selected non-`Send` resource in the [`ObjectListTab::Resources`] tab, whose value is shown.
+    ///
+    /// Non-`Send` resources are not stored on entities, so they can't be the [`Self::selected_object`].
+    pub selected_non_send_resource: Option<ComponentId>,
+    /// Currently selected archetype in the [`ObjectListTab::Archetypes`] tab, which is inspected in detail.
+    pub selected_archetype: Option<ArchetypeId>,
     /// Active tab in the object list panel.
     pub active_objects_tab: ObjectListTab,
     /// Active tab in the detail panel.
@@ -36,19 +45,51 @@ pub struct InspectorState {
     /// Previous active tab in the detail panel.
     pub previous_detail_tab: DetailTab,
     /// Current search/filter text for object list.
+    ///
+    /// This is parsed as an [`EntityQuery`](crate::inspection::entity_query::EntityQuery),
+    /// so it can filter by components as well as by name.
     pub filter_text: String,
+    /// The error produced by parsing [`Self::filter_text`], if it is not a valid query.
+    pub filter_error: Option<EntityQueryError>,
     /// Component filter: only show entities with these components.
     pub mandatory_components: Vec<ComponentId>,
+    /// The current page of the object list, starting at 0.
+    ///
+    /// See [`InspectorConfig::object_list_page_size`](crate::gui::config::InspectorConfig::object_list_page_size).
+    pub object_list_page: usize,
 }
 
 /// Active tab in the object list panel.
-#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
 pub enum ObjectListTab {
     #[default]
     Entities,
     Resources,
     Observers,
     OneShotSystems,
+    /// The systems in each schedule, rather than entities.
+    Systems,
+    /// The buffered messages of each message type, rather than entities.
+    Messages,
+    /// The assets in each asset collection, rather than entities.
+    Assets,
+    /// The current value of each state type, rather than entities.
+    States,
+    /// The non-empty archetypes of the world, rather than entities.
+    Archetypes,
+    /// The entity count over time, and how the world has recently changed, rather than entities.
+    ///
+    /// This is only populated when the [`SummaryHistoryPlugin`](crate::inspection::summary_history::SummaryHistoryPlugin) is added.
+    Trends,
+    /// How often each component type is changed, and which entities change most, rather than entities.
+    ///
+    /// This is only populated when the [`ChangeProfilerPlugin`](crate::inspection::change_profiler::ChangeProfilerPlugin) is added.
+    Changes,
+    /// The types registered for reflection, and the component and resource types that are missing a registration,
+    /// rather than entities.
+    TypeRegistry,
+    /// Entities in a category registered in the [`CategoryRegistry`](crate::entity_categories::CategoryRegistry).
+    Category(EntityCategory),
 }
 
 /// Active tab in the detail panel.
@@ -57,6 +98,10 @@ pub enum DetailTab {
     #[default]
     Components,
     Relationships,
+    /// The component lifecycle events recorded while the entity is traced.
+    ///
+    /// See [`ComponentTraces`](crate::inspection::component_trace::ComponentTraces).
+    History,
 }
 
 /// Data for a single entity in the object list.
@@ -68,7 +113,9 @@ pub struct ObjectListEntry {
     /// Number of components on this entity.
     pub component_count: usize,
     /// Total memory size of all components.
-    pub memory_size: MemorySize,
+    ///
+    /// This is [`MemoryEstimate::Unknown`] when the size could not be determined.
+    pub memory_size: MemoryEstimate,
 }
 
 impl ObjectListEntry {
@@ -0,0 +1,395 @@
+//! Types for inspecting a single [`Archetype`](bevy::ecs::archetype::Archetype) in detail.
+//!
+//! While [`ArchetypeSummary`](crate::inspection::world_summary::ArchetypeSummary) only lists the entity count
+//! and components of each archetype, an [`ArchetypeInspection`] describes how its data is stored,
+//! and which archetypes its entities move to when bundles are inserted or removed.
+//! This is useful for understanding archetype fragmentation.
+
+use bevy::{
+    ecs::{
+        archetype::ArchetypeId,
+        component::{ComponentId, StorageType},
+        storage::TableId,
+    },
+    prelude::*,
+};
+use core::fmt::Display;
+use thiserror::Error;
+
+use crate::memory_size::MemorySize;
+
+/// The result of inspecting an archetype.
+///
+/// Log this using the [`Display`] trait to see details about the archetype.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArchetypeInspection {
+    /// The id of the archetype.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::archetype_id")
+    )]
+    pub archetype_id: ArchetypeId,
+    /// How many entities are in the archetype.
+    pub entity_count: usize,
+    /// The id of the table that stores the archetype's table components.
+    ///
+    /// Archetypes which only differ by their sparse set components share a table.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_conversions::table_id"))]
+    pub table_id: TableId,
+    /// How many rows are in the table, across all archetypes that share it.
+    pub table_rows: usize,
+    /// How many rows the table has allocated memory for.
+    pub table_capacity: usize,
+    /// The components of the archetype, with table components first.
+    pub components: Vec<ArchetypeComponent>,
+    /// The archetypes that entities move to when bundles are inserted or removed.
+    ///
+    /// Bevy caches these transitions the first time they happen,
+    /// so only transitions that have happened at least once are listed.
+    pub edges: Vec<ArchetypeEdge>,
+    /// Some of the entities in the archetype.
+    ///
+    /// The number of entities is limited by [`ArchetypeInspectionSettings::entity_sample_size`].
+    pub entity_sample: Vec<Entity>,
+}
+
+impl Display for ArchetypeInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Archetype #{} ({} entities) in table #{} ({} of {} rows used)",
+            self.archetype_id.index(),
+            self.entity_count,
+            self.table_id.as_u32(),
+            self.table_rows,
+            self.table_capacity
+        )?;
+
+        for (storage_type, heading) in [
+            (StorageType::Table, "Table components"),
+            (StorageType::SparseSet, "Sparse set components"),
+        ] {
+            let components: Vec<_> = self
+                .components
+                .iter()
+                .filter(|component| component.storage_type == storage_type)
+                .collect();
+            if components.is_empty() {
+                continue;
+            }
+            write!(f, "\n{heading}:")?;
+            for component in components {
+                write!(f, "\n  {component}")?;
+            }
+        }
+
+        if !self.edges.is_empty() {
+            write!(f, "\nEdges:")?;
+            for edge in &self.edges {
+                write!(f, "\n  {edge}")?;
+            }
+        }
+
+        if !self.entity_sample.is_empty() {
+            let entities: Vec<String> = self
+                .entity_sample
+                .iter()
+                .map(|entity| entity.to_string())
+                .collect();
+            write!(f, "\nEntities: {}", entities.join(", "))?;
+            let omitted = self.entity_count - self.entity_sample.len();
+            if omitted > 0 {
+                write!(f, " ... and {omitted} more")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A component of an archetype, and the memory used to store it.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArchetypeComponent {
+    /// The [`ComponentId`] of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// How the component is stored.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::storage_type")
+    )]
+    pub storage_type: StorageType,
+    /// The shallow size of the component values stored.
+    ///
+    /// For table components, this covers every row of the table.
+    /// For sparse set components, this only covers the entities in this archetype.
+    /// Change detection ticks and heap allocations are not included.
+    pub memory_used: MemorySize,
+    /// The shallow size of the memory allocated to store the component values.
+    ///
+    /// For table components, this covers the capacity of the table's column.
+    /// For sparse set components, this is the same as [`Self::memory_used`].
+    pub memory_allocated: MemorySize,
+}
+
+impl Display for ArchetypeComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} used, {} allocated",
+            self.name.shortname(),
+            self.memory_used,
+            self.memory_allocated
+        )
+    }
+}
+
+/// A cached transition from one archetype to another.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArchetypeEdge {
+    /// Whether the bundle is inserted or removed.
+    pub kind: ArchetypeEdgeKind,
+    /// The type names of the components in the bundle.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::vec_debug_name")
+    )]
+    pub bundle: Vec<DebugName>,
+    /// The archetype that entities move to.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::archetype_id")
+    )]
+    pub target: ArchetypeId,
+}
+
+impl Display for ArchetypeEdge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = match self.kind {
+            ArchetypeEdgeKind::Insert => '+',
+            ArchetypeEdgeKind::Remove => '-',
+        };
+        let components: Vec<String> = self
+            .bundle
+            .iter()
+            .map(|name| name.shortname().to_string())
+            .collect();
+        write!(
+            f,
+            "{sign}({}) -> #{}",
+            components.join(", "),
+            self.target.index()
+        )
+    }
+}
+
+/// The kind of change that causes an [`ArchetypeEdge`] to be taken.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArchetypeEdgeKind {
+    /// A bundle is inserted.
+    Insert,
+    /// A bundle is removed.
+    Remove,
+}
+
+/// Settings that can be used to customize archetype inspection,
+/// changing how [`ArchetypeInspection`] is generated.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArchetypeInspectionSettings {
+    /// The maximum number of entities listed in [`ArchetypeInspection::entity_sample`].
+    ///
+    /// Defaults to 10.
+    pub entity_sample_size: usize,
+}
+
+impl Default for ArchetypeInspectionSettings {
+    fn default() -> Self {
+        Self {
+            entity_sample_size: 10,
+        }
+    }
+}
+
+/// An error that can occur when attempting to inspect an archetype.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ArchetypeInspectionError {
+    /// No archetype with this id exists in the world.
+    #[error("Archetype {0:?} not found in world")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::archetype_id")
+    )]
+    ArchetypeNotFound(ArchetypeId),
+}
+
+impl ArchetypeInspection {
+    /// Inspects the archetype identified by `archetype_id`.
+    pub fn new(
+        world: &World,
+        archetype_id: ArchetypeId,
+        settings: ArchetypeInspectionSettings,
+    ) -> Result<Self, ArchetypeInspectionError> {
+        let archetype = world
+            .archetypes()
+            .get(archetype_id)
+            .ok_or(ArchetypeInspectionError::ArchetypeNotFound(archetype_id))?;
+        let entity_count = archetype.len() as usize;
+
+        let table = world.storages().tables.get(archetype.table_id());
+        let table_rows = table.map_or(0, |table| table.entity_count() as usize);
+        let table_capacity = table.map_or(0, |table| table.entity_capacity());
+
+        let components = archetype
+            .table_components()
+            .map(|component_id| (component_id, StorageType::Table, table_rows, table_capacity))
+            .chain(archetype.sparse_set_components().map(|component_id| {
+                (
+                    component_id,
+                    StorageType::SparseSet,
+                    entity_count,
+                    entity_count,
+                )
+            }))
+            .filter_map(|(component_id, storage_type, used_rows, allocated_rows)| {
+                let info = world.components().get_info(component_id)?;
+                let size = info.layout().size();
+                Some(ArchetypeComponent {
+                    component_id,
+                    name: info.name(),
+                    storage_type,
+                    memory_used: MemorySize::new(size * used_rows),
+                    memory_allocated: MemorySize::new(size * allocated_rows),
+                })
+            })
+            .collect();
+
+        // `Edges` can't be iterated, so every known bundle is checked instead
+        let edges = world
+            .bundles()
+            .iter()
+            .flat_map(|bundle_info| {
+                let edges = archetype.edges();
+                let insert = edges
+                    .get_archetype_after_bundle_insert(bundle_info.id())
+                    .map(|target| (ArchetypeEdgeKind::Insert, target));
+                let remove = edges
+                    .get_archetype_after_bundle_remove(bundle_info.id())
+                    .flatten()
+                    .map(|target| (ArchetypeEdgeKind::Remove, target));
+                insert
+                    .into_iter()
+                    .chain(remove)
+                    // Inserting components that are already present doesn't change the archetype
+                    .filter(|(_, target)| *target != archetype_id)
+                    .map(move |(kind, target)| ArchetypeEdge {
+                        kind,
+                        bundle: bundle_info
+                            .explicit_components()
+                            .iter()
+                            .filter_map(|component_id| world.components().get_name(*component_id))
+                            .collect(),
+                        target,
+                    })
+            })
+            .collect();
+
+        let entity_sample = archetype
+            .entities()
+            .iter()
+            .take(settings.entity_sample_size)
+            .map(|archetype_entity| archetype_entity.id())
+            .collect();
+
+        Ok(Self {
+            archetype_id,
+            entity_count,
+            table_id: archetype.table_id(),
+            table_rows,
+            table_capacity,
+            components,
+            edges,
+            entity_sample,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension_methods::WorldInspectionExtensionTrait;
+
+    #[derive(Component)]
+    struct Position(#[expect(dead_code, reason = "only the size is inspected")] u64);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct Marker;
+
+    #[test]
+    fn components_are_split_by_storage_type() {
+        let mut world = World::new();
+        let entity = world.spawn((Position(1), Marker)).id();
+        world.spawn((Position(2), Marker));
+
+        let archetype_id = world.entity(entity).archetype().id();
+        let inspection = world
+            .inspect_archetype(archetype_id, ArchetypeInspectionSettings::default())
+            .unwrap();
+        assert_eq!(inspection.entity_count, 2);
+        assert_eq!(inspection.table_rows, 2);
+        assert_eq!(inspection.entity_sample.len(), 2);
+
+        let position = &inspection.components[0];
+        assert_eq!(position.name.shortname().to_string(), "Position");
+        assert_eq!(position.storage_type, StorageType::Table);
+        assert_eq!(position.memory_used, MemorySize::new(16));
+
+        let marker = &inspection.components[1];
+        assert_eq!(marker.storage_type, StorageType::SparseSet);
+    }
+
+    #[test]
+    fn edges_list_cached_transitions() {
+        let mut world = World::new();
+        let entity = world.spawn(Position(1)).id();
+        let before = world.entity(entity).archetype().id();
+        world.entity_mut(entity).insert(Marker);
+        let after = world.entity(entity).archetype().id();
+        world.entity_mut(entity).remove::<Marker>();
+
+        let inspection = world
+            .inspect_archetype(before, ArchetypeInspectionSettings::default())
+            .unwrap();
+        let edge = inspection
+            .edges
+            .iter()
+            .find(|edge| edge.kind == ArchetypeEdgeKind::Insert)
+            .unwrap();
+        assert_eq!(edge.target, after);
+        assert_eq!(edge.bundle[0].shortname().to_string(), "Marker");
+
+        let inspection = world
+            .inspect_archetype(after, ArchetypeInspectionSettings::default())
+            .unwrap();
+        assert!(
+            inspection
+                .edges
+                .iter()
+                .any(|edge| edge.kind == ArchetypeEdgeKind::Remove && edge.target == before)
+        );
+    }
+}
@@ -0,0 +1,464 @@
+//! Types for inspecting [`Assets`] collections and the [`Handle`]s that point into them.
+//!
+//! Each asset type is stored in its own [`Assets<A>`] resource,
+//! which can only be read without knowing its type through the [`ReflectAsset`] type data.
+//! As a result, only asset types registered using [`AssetApp::register_asset_reflect`]
+//! can be inspected. Most of Bevy's own asset types are registered this way.
+//!
+//! Similarly, [`Handle`]s are resolved using the [`ReflectHandle`] type data,
+//! which is registered alongside [`ReflectAsset`].
+
+use bevy::{
+    asset::{LoadState, ReflectAsset, ReflectHandle, UntypedAssetId},
+    prelude::*,
+    reflect::{ReflectKind, TypeRegistry},
+};
+use core::fmt::Display;
+use thiserror::Error;
+
+use crate::reflection_tools::{ValueFormat, ValueFormatter, format_reflected_value};
+
+/// The result of inspecting the [`Assets`] collection of a single asset type.
+///
+/// Log this using the [`Display`] trait to see the assets in the collection.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetCollectionInspection {
+    /// The type name of the assets in the collection.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub asset_type: DebugName,
+    /// The assets in the collection, sorted by [`AssetInspection::label`].
+    pub assets: Vec<AssetInspection>,
+}
+
+impl Display for AssetCollectionInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({} assets)",
+            self.asset_type.shortname(),
+            self.assets.len()
+        )?;
+        for asset in &self.assets {
+            write!(f, "\n  {asset}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of inspecting a single asset.
+///
+/// Log this using the [`Display`] trait to see details about the asset.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetInspection {
+    /// The id of the asset.
+    ///
+    /// Asset ids are only meaningful inside of the running app, so this is not serialized.
+    /// Use [`AssetInspection::label`] to identify the asset instead.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub id: Option<UntypedAssetId>,
+    /// The path of the asset if it has one, or its id otherwise.
+    pub label: String,
+    /// The path that the asset was loaded from.
+    ///
+    /// This is `None` for assets that were added directly to their [`Assets`] collection.
+    pub path: Option<String>,
+    /// Whether the asset has been loaded by the [`AssetServer`].
+    ///
+    /// This is `None` for assets that the [`AssetServer`] is not tracking,
+    /// such as assets that were added directly to their [`Assets`] collection.
+    pub load_state: Option<AssetLoadState>,
+    /// The reflected value of the asset.
+    ///
+    /// This is only included if [`AssetInspectionSettings::include_values`] is `true`.
+    pub value: Option<String>,
+}
+
+impl Display for AssetInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)?;
+        if let Some(load_state) = &self.load_state {
+            write!(f, " [{load_state}]")?;
+        }
+        if let Some(value) = &self.value {
+            write!(f, "\n{value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl AssetInspection {
+    /// Inspects the asset with the given `id`, without reading its value.
+    ///
+    /// The path and load state are read from the [`AssetServer`], if it exists.
+    pub fn new(world: &World, id: UntypedAssetId) -> Self {
+        let asset_server = world.get_resource::<AssetServer>();
+        let path = asset_server
+            .and_then(|server| server.get_path(id))
+            .map(|path| path.to_string());
+        let load_state = asset_server
+            .and_then(|server| server.get_load_state(id))
+            .map(|load_state| AssetLoadState::from(&load_state));
+
+        Self {
+            id: Some(id),
+            label: path.clone().unwrap_or_else(|| asset_id_label(id)),
+            path,
+            load_state,
+            value: None,
+        }
+    }
+}
+
+/// A serializable version of the [`LoadState`] of an asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssetLoadState {
+    /// The asset has not started loading yet.
+    NotLoaded,
+    /// The asset is in the process of loading.
+    Loading,
+    /// The asset has been loaded and added to its [`Assets`] collection.
+    Loaded,
+    /// The asset failed to load, with the given error message.
+    Failed(String),
+}
+
+impl From<&LoadState> for AssetLoadState {
+    fn from(load_state: &LoadState) -> Self {
+        match load_state {
+            LoadState::NotLoaded => Self::NotLoaded,
+            LoadState::Loading => Self::Loading,
+            LoadState::Loaded => Self::Loaded,
+            LoadState::Failed(error) => Self::Failed(error.to_string()),
+        }
+    }
+}
+
+impl Display for AssetLoadState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotLoaded => write!(f, "not loaded"),
+            Self::Loading => write!(f, "loading"),
+            Self::Loaded => write!(f, "loaded"),
+            Self::Failed(error) => write!(f, "failed: {error}"),
+        }
+    }
+}
+
+/// The asset that a reflected [`Handle`] points to.
+///
+/// Log this using the [`Display`] trait to see the asset's path and load state.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandleInspection {
+    /// The type name of the asset that the handle points to.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub asset_type: DebugName,
+    /// The asset that the handle points to.
+    ///
+    /// The [`AssetInspection::value`] is not included.
+    pub asset: AssetInspection,
+}
+
+impl Display for HandleInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Handle<{}>({})",
+            self.asset_type.shortname(),
+            self.asset.label
+        )?;
+        if let Some(load_state) = &self.asset.load_state {
+            write!(f, " [{load_state}]")?;
+        }
+        Ok(())
+    }
+}
+
+impl HandleInspection {
+    /// Resolves the asset that the `reflected` value points to, if it is a [`Handle`].
+    ///
+    /// Returns `None` if the value is not a handle,
+    /// or if its type is not registered with [`ReflectHandle`] type data in the `type_registry`.
+    pub fn resolve(
+        world: &World,
+        type_registry: &TypeRegistry,
+        reflected: &dyn PartialReflect,
+    ) -> Option<Self> {
+        // Handles are reflected as enums, so other values can be skipped without a registry lookup
+        if reflected.reflect_kind() != ReflectKind::Enum {
+            return None;
+        }
+        let type_id = reflected.get_represented_type_info()?.type_id();
+        let reflect_handle = type_registry.get_type_data::<ReflectHandle>(type_id)?;
+        let handle =
+            reflect_handle.downcast_handle_untyped(reflected.try_as_reflect()?.as_any())?;
+
+        Some(Self {
+            asset_type: asset_type_name(type_registry, reflect_handle.asset_type_id()),
+            asset: AssetInspection::new(world, handle.id()),
+        })
+    }
+}
+
+/// A [`ValueFormatter`] that displays [`Handle`]s as the [`HandleInspection`] of the asset they point to.
+///
+/// This borrows the [`TypeRegistry`], so the [`AppTypeRegistry`] only needs to be locked once
+/// for the whole value, rather than for every nested field.
+#[derive(Clone, Copy)]
+pub struct HandleResolver<'a> {
+    world: &'a World,
+    type_registry: &'a TypeRegistry,
+}
+
+impl<'a> HandleResolver<'a> {
+    /// Creates a resolver that reads asset paths and load states from the `world`.
+    pub fn new(world: &'a World, type_registry: &'a TypeRegistry) -> Self {
+        Self {
+            world,
+            type_registry,
+        }
+    }
+
+    /// Resolves the asset that the `reflected` value points to, if it is a [`Handle`].
+    ///
+    /// See [`HandleInspection::resolve`] for details.
+    pub fn resolve(&self, reflected: &dyn PartialReflect) -> Option<HandleInspection> {
+        HandleInspection::resolve(self.world, self.type_registry, reflected)
+    }
+}
+
+impl ValueFormatter for HandleResolver<'_> {
+    fn format_value(&self, reflected: &dyn PartialReflect) -> Option<String> {
+        self.resolve(reflected).map(|handle| handle.to_string())
+    }
+}
+
+/// Settings that can be used to customize asset inspection,
+/// changing how [`AssetInspection`] is generated and displayed.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssetInspectionSettings {
+    /// Whether or not the reflected value of each asset should be included.
+    ///
+    /// Assets such as images and meshes can be very large,
+    /// so this defaults to `false`.
+    pub include_values: bool,
+    /// Whether or not full type names should be displayed.
+    ///
+    /// Defaults to `false`.
+    pub full_type_names: bool,
+}
+
+/// An error that occurs when inspecting an asset.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssetInspectionError {
+    /// The asset type is not registered using [`AssetApp::register_asset_reflect`].
+    #[error("Asset type {0} is not registered for reflection")]
+    AssetTypeNotReflected(String),
+    /// The asset does not exist in its [`Assets`] collection.
+    #[error("Asset {0} was not found")]
+    AssetNotFound(String),
+}
+
+impl AssetCollectionInspection {
+    /// Inspects every [`Assets`] collection whose asset type is registered with [`ReflectAsset`] type data,
+    /// sorted by the name of the asset type.
+    pub fn inspect_all(world: &World, settings: AssetInspectionSettings) -> Vec<Self> {
+        let Some(type_registry) = world.get_resource::<AppTypeRegistry>() else {
+            return Vec::new();
+        };
+        let type_registry = type_registry.read();
+
+        let mut inspections: Vec<Self> = type_registry
+            .iter_with_data::<ReflectAsset>()
+            .filter(|(_, reflect_asset)| {
+                // `ReflectAsset` panics if the `Assets` resource is missing
+                world
+                    .components()
+                    .get_id(reflect_asset.assets_resource_type_id())
+                    .is_some_and(|component_id| {
+                        world.resource_entities().get(component_id).is_some()
+                    })
+            })
+            .map(|(registration, reflect_asset)| {
+                let mut assets: Vec<AssetInspection> = reflect_asset
+                    .ids(world)
+                    .map(|id| {
+                        inspect_asset_value(world, &type_registry, reflect_asset, id, settings)
+                    })
+                    .collect();
+                assets.sort_by(|a, b| a.label.cmp(&b.label));
+
+                Self {
+                    asset_type: DebugName::owned(registration.type_info().type_path().to_string()),
+                    assets,
+                }
+            })
+            .collect();
+        inspections.sort_by_cached_key(|inspection| inspection.asset_type.shortname().to_string());
+        inspections
+    }
+}
+
+/// Inspects the asset with the given `id`, including its reflected value
+/// if [`AssetInspectionSettings::include_values`] is `true`.
+pub(crate) fn inspect_single_asset(
+    world: &World,
+    id: UntypedAssetId,
+    settings: AssetInspectionSettings,
+) -> Result<AssetInspection, AssetInspectionError> {
+    let Some(type_registry) = world.get_resource::<AppTypeRegistry>() else {
+        return Err(AssetInspectionError::AssetTypeNotReflected(format!(
+            "{:?}",
+            id.type_id()
+        )));
+    };
+    let type_registry = type_registry.read();
+    let reflect_asset = type_registry
+        .get_type_data::<ReflectAsset>(id.type_id())
+        .ok_or_else(|| {
+            AssetInspectionError::AssetTypeNotReflected(
+                asset_type_name(&type_registry, id.type_id()).to_string(),
+            )
+        })?;
+    if reflect_asset.get(world, id).is_none() {
+        return Err(AssetInspectionError::AssetNotFound(id.to_string()));
+    }
+
+    Ok(inspect_asset_value(
+        world,
+        &type_registry,
+        reflect_asset,
+        id,
+        settings,
+    ))
+}
+
+fn inspect_asset_value(
+    world: &World,
+    type_registry: &TypeRegistry,
+    reflect_asset: &ReflectAsset,
+    id: UntypedAssetId,
+    settings: AssetInspectionSettings,
+) -> AssetInspection {
+    let value = settings
+        .include_values
+        .then(|| reflect_asset.get(world, id))
+        .flatten()
+        .map(|asset| {
+            format_reflected_value(
+                asset.as_partial_reflect(),
+                ValueFormat {
+                    full_type_names: settings.full_type_names,
+                    formatter: Some(&HandleResolver::new(world, type_registry)),
+                },
+            )
+        });
+
+    AssetInspection {
+        value,
+        ..AssetInspection::new(world, id)
+    }
+}
+
+/// A short label for an asset without a path.
+///
+/// The [`Display`] implementation of [`UntypedAssetId`] includes the [`TypeId`](core::any::TypeId),
+/// which is not useful when the asset type is already known.
+fn asset_id_label(id: UntypedAssetId) -> String {
+    match id {
+        UntypedAssetId::Index { index, .. } => format!("{index:?}"),
+        UntypedAssetId::Uuid { uuid, .. } => format!("Uuid({uuid})"),
+    }
+}
+
+/// The name of the asset type with the given [`TypeId`](core::any::TypeId),
+/// falling back to a placeholder if it is not registered.
+fn asset_type_name(type_registry: &TypeRegistry, type_id: core::any::TypeId) -> DebugName {
+    match type_registry.get(type_id) {
+        Some(registration) => DebugName::owned(registration.type_info().type_path().to_string()),
+        None => DebugName::borrowed("<Unregistered Asset>"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_name_resolution::NameResolutionPlugin;
+    use crate::extension_methods::WorldInspectionExtensionTrait;
+    use bevy::asset::AssetPlugin;
+
+    #[derive(Asset, Reflect)]
+    struct Note {
+        text: String,
+    }
+
+    #[derive(Component, Reflect)]
+    struct Pinned(Handle<Note>);
+
+    fn note_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), NameResolutionPlugin))
+            .init_asset::<Note>()
+            .register_asset_reflect::<Note>()
+            .register_type::<Pinned>();
+        app
+    }
+
+    #[test]
+    fn asset_collections_list_their_assets() {
+        let mut app = note_app();
+        let world = app.world_mut();
+        world.resource_mut::<Assets<Note>>().add(Note {
+            text: "Buy milk".to_string(),
+        });
+
+        let settings = AssetInspectionSettings {
+            include_values: true,
+            ..default()
+        };
+        let collections = world.inspect_assets(settings);
+        let notes = collections
+            .iter()
+            .find(|collection| collection.asset_type.shortname().to_string() == "Note")
+            .unwrap();
+        assert_eq!(notes.assets.len(), 1);
+
+        let note = &notes.assets[0];
+        assert_eq!(note.path, None);
+        assert_eq!(note.load_state, None);
+        assert!(note.value.as_ref().unwrap().contains("Buy milk"));
+    }
+
+    #[test]
+    fn handles_are_resolved_when_pretty_printing() {
+        let mut app = note_app();
+        let world = app.world_mut();
+        let handle = world.resource_mut::<Assets<Note>>().add(Note {
+            text: "Buy milk".to_string(),
+        });
+        let entity = world.spawn(Pinned(handle.clone())).id();
+
+        let inspection = world
+            .inspect_component::<Pinned>(entity, default())
+            .unwrap();
+        let value = inspection.value.unwrap();
+        assert!(value.contains("Handle<Note>(AssetIndex"));
+
+        let asset = world
+            .inspect_asset(handle.id().untyped(), default())
+            .unwrap();
+        assert_eq!(asset.id, Some(handle.id().untyped()));
+        assert!(asset.value.is_none());
+    }
+}
@@ -0,0 +1,426 @@
+//! Profiling how often each component type is changed.
+//!
+//! Components that are changed every frame, often by systems that write to them unconditionally,
+//! cause every `Changed<T>` query and change-detecting system downstream to do needless work.
+//! The opt-in [`ChangeProfilerPlugin`] compares the change ticks of every component once per frame,
+//! counting how many entities had each component type changed.
+//! After each sampling window of [`ChangeProfiler::window_frames`] frames,
+//! the counts are summarized in a [`ChangeProfileReport`], listing the hottest component types and entities.
+
+use std::cmp::Reverse;
+
+use bevy::{
+    ecs::{change_detection::Tick, component::ComponentId, resource::IsResource},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use core::fmt::Display;
+
+use crate::{
+    entity_name_resolution::{EntityName, resolve_entity_name},
+    inspector_entities::{is_inspector_entity, may_contain_inspector_entities},
+};
+
+/// A summary of how often components were changed over a sampling window of the [`ChangeProfiler`].
+///
+/// Log this using the [`Display`] trait to see a report of the hottest component types and entities.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeProfileReport {
+    /// The number of frames sampled.
+    pub frames: u32,
+    /// The component types that were changed at least once,
+    /// sorted by their total number of changes, most changed first.
+    pub components: Vec<ComponentChangeFrequency>,
+    /// The entities whose components were changed most often, most changed first.
+    ///
+    /// This is limited to [`ChangeProfiler::max_entities`] entities.
+    pub entities: Vec<EntityChangeFrequency>,
+}
+
+impl Display for ChangeProfileReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Component changes over {} frames:", self.frames)?;
+        if self.components.is_empty() {
+            write!(f, "\n  No components were changed")?;
+        }
+        for component in &self.components {
+            write!(f, "\n  {component}")?;
+        }
+        if !self.entities.is_empty() {
+            write!(f, "\nHottest entities:")?;
+            for entity in &self.entities {
+                write!(f, "\n  {entity}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How often a component type was changed, as part of a [`ChangeProfileReport`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComponentChangeFrequency {
+    /// The id of the component type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// The number of times an entity had this component changed, summed over every frame.
+    ///
+    /// Adding a component counts as changing it.
+    pub total_changes: u64,
+    /// The number of frames in which at least one entity had this component changed.
+    pub frames_changed: u32,
+    /// The largest number of entities that had this component changed in a single frame.
+    pub max_changes_per_frame: u32,
+    /// The average number of entities that had this component changed per frame.
+    pub changes_per_frame: f64,
+    /// Whether this component was changed on at least one entity in every sampled frame.
+    pub changed_every_frame: bool,
+}
+
+impl Display for ComponentChangeFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {:.1}/frame (peak {}, changed in {} frames)",
+            self.name.shortname(),
+            self.changes_per_frame,
+            self.max_changes_per_frame,
+            self.frames_changed
+        )?;
+        if self.changed_every_frame {
+            write!(f, " [every frame]")?;
+        }
+        Ok(())
+    }
+}
+
+/// How often the components of an entity were changed, as part of a [`ChangeProfileReport`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityChangeFrequency {
+    /// The entity.
+    pub entity: Entity,
+    /// The resolved name of the entity, if it still exists.
+    pub name: Option<EntityName>,
+    /// The number of component changes on this entity, summed over every frame.
+    pub total_changes: u64,
+}
+
+impl Display for EntityChangeFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.name.as_ref().map_or("Entity", |name| name.as_str());
+        write!(
+            f,
+            "{name} ({}): {} changes",
+            self.entity, self.total_changes
+        )
+    }
+}
+
+/// Counts component changes over a sampling window, filled by the [`ChangeProfilerPlugin`].
+///
+/// To change the sampling window,
+/// insert this resource using [`ChangeProfiler::new`] before adding the plugin.
+#[derive(Resource, Debug)]
+pub struct ChangeProfiler {
+    /// The number of frames in each sampling window.
+    ///
+    /// Defaults to 60.
+    pub window_frames: u32,
+    /// The maximum number of entities listed in each [`ChangeProfileReport`].
+    ///
+    /// Defaults to 20.
+    pub max_entities: usize,
+    /// The tick when components were last compared, or `None` before the first frame.
+    last_tick: Option<Tick>,
+    frames_sampled: u32,
+    components: HashMap<ComponentId, ChangeCounter>,
+    entities: HashMap<Entity, u64>,
+    report: Option<ChangeProfileReport>,
+}
+
+impl Default for ChangeProfiler {
+    fn default() -> Self {
+        Self::new(60)
+    }
+}
+
+/// The running change counts of a single component type.
+#[derive(Debug, Default)]
+struct ChangeCounter {
+    total_changes: u64,
+    frames_changed: u32,
+    max_changes_per_frame: u32,
+}
+
+impl ChangeProfiler {
+    /// Creates a profiler which reports after every `window_frames` frames.
+    pub fn new(window_frames: u32) -> Self {
+        Self {
+            window_frames,
+            max_entities: 20,
+            last_tick: None,
+            frames_sampled: 0,
+            components: HashMap::default(),
+            entities: HashMap::default(),
+            report: None,
+        }
+    }
+
+    /// Returns the report of the most recently completed sampling window, if any.
+    pub fn report(&self) -> Option<&ChangeProfileReport> {
+        self.report.as_ref()
+    }
+
+    /// Returns the number of frames sampled so far in the current window.
+    pub fn frames_sampled(&self) -> u32 {
+        self.frames_sampled
+    }
+
+    /// Discards the counts of the current window and the latest report.
+    pub fn reset(&mut self) {
+        self.frames_sampled = 0;
+        self.components.clear();
+        self.entities.clear();
+        self.report = None;
+    }
+
+    /// Summarizes the counts of the current window into a [`ChangeProfileReport`].
+    fn build_report(&self, world: &World) -> ChangeProfileReport {
+        let frames = self.frames_sampled.max(1);
+
+        let mut components: Vec<ComponentChangeFrequency> = self
+            .components
+            .iter()
+            .filter_map(|(component_id, counter)| {
+                Some(ComponentChangeFrequency {
+                    component_id: *component_id,
+                    name: world.components().get_name(*component_id)?,
+                    total_changes: counter.total_changes,
+                    frames_changed: counter.frames_changed,
+                    max_changes_per_frame: counter.max_changes_per_frame,
+                    changes_per_frame: counter.total_changes as f64 / frames as f64,
+                    changed_every_frame: counter.frames_changed >= self.frames_sampled,
+                })
+            })
+            .collect();
+        components.sort_by_key(|component| {
+            (
+                Reverse(component.total_changes),
+                component.component_id.index(),
+            )
+        });
+
+        let mut entities: Vec<(Entity, u64)> = self
+            .entities
+            .iter()
+            .map(|(entity, total_changes)| (*entity, *total_changes))
+            .collect();
+        entities.sort_by_key(|(entity, total_changes)| (Reverse(*total_changes), *entity));
+        entities.truncate(self.max_entities);
+        let entities = entities
+            .into_iter()
+            .map(|(entity, total_changes)| EntityChangeFrequency {
+                entity,
+                name: world.get_entity(entity).ok().and_then(|entity_ref| {
+                    resolve_entity_name(world, entity, entity_ref.archetype().components())
+                }),
+                total_changes,
+            })
+            .collect();
+
+        ChangeProfileReport {
+            frames: self.frames_sampled,
+            components,
+            entities,
+        }
+    }
+}
+
+/// Exclusive system that counts the components changed since it last ran in the [`ChangeProfiler`],
+/// producing a [`ChangeProfileReport`] at the end of each sampling window.
+///
+/// This reads the change ticks of every component on every entity,
+/// so it gets slower as the world grows.
+/// Resources are not included, and neither are the inspector's own entities,
+/// as described by [`is_inspector_entity`].
+pub fn profile_component_changes(world: &mut World) {
+    world.resource_scope(|world, mut profiler: Mut<ChangeProfiler>| {
+        let this_tick = world.read_change_tick();
+        let Some(last_tick) = profiler.last_tick.replace(this_tick) else {
+            // Everything is new on the first frame, so only record the starting tick
+            return;
+        };
+
+        let resource_component_id = world.components().component_id::<IsResource>();
+        let mut frame_counts: HashMap<ComponentId, u32> = HashMap::default();
+        for archetype in world.archetypes().iter() {
+            if resource_component_id.is_some_and(|id| archetype.contains(id)) {
+                continue;
+            }
+            let may_contain_inspector = may_contain_inspector_entities(world, archetype);
+            for archetype_entity in archetype.entities() {
+                let entity = archetype_entity.id();
+                let Ok(entity_ref) = world.get_entity(entity) else {
+                    continue;
+                };
+                if may_contain_inspector && is_inspector_entity(world, &entity_ref) {
+                    continue;
+                }
+                let mut entity_changes = 0;
+                for &component_id in archetype.components() {
+                    if entity_ref
+                        .get_change_ticks_by_id(component_id)
+                        .is_some_and(|ticks| ticks.is_changed(last_tick, this_tick))
+                    {
+                        *frame_counts.entry(component_id).or_default() += 1;
+                        entity_changes += 1;
+                    }
+                }
+                if entity_changes > 0 {
+                    *profiler.entities.entry(entity).or_default() += entity_changes;
+                }
+            }
+        }
+
+        for (component_id, count) in frame_counts {
+            let counter = profiler.components.entry(component_id).or_default();
+            counter.total_changes += count as u64;
+            counter.frames_changed += 1;
+            counter.max_changes_per_frame = counter.max_changes_per_frame.max(count);
+        }
+
+        profiler.frames_sampled += 1;
+        if profiler.frames_sampled >= profiler.window_frames.max(1) {
+            let report = profiler.build_report(world);
+            profiler.reset();
+            profiler.report = Some(report);
+        }
+    });
+}
+
+/// A plugin which counts how often each component type is changed,
+/// storing a [`ChangeProfileReport`] in the [`ChangeProfiler`] resource after each sampling window.
+///
+/// Changes are compared at the end of every frame.
+/// As this reads the change ticks of every component each frame, it is not added by default.
+///
+/// When upstreamed, this plugin should not be necessary,
+/// as changes can be counted as they are made.
+pub struct ChangeProfilerPlugin;
+
+impl Plugin for ChangeProfilerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ChangeProfiler>()
+            .add_systems(Last, profile_component_changes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector_entities::InspectorInternal;
+
+    #[derive(Component)]
+    struct Position(f32);
+
+    #[derive(Component)]
+    struct Velocity;
+
+    /// Simulates a frame in which the `moving` entities have their [`Position`] changed.
+    fn run_frame(world: &mut World, moving: &[Entity]) {
+        world.increment_change_tick();
+        for &entity in moving {
+            world.get_mut::<Position>(entity).unwrap().0 += 1.0;
+        }
+        profile_component_changes(world);
+    }
+
+    #[test]
+    fn changes_are_counted_per_component_type() {
+        let mut world = World::new();
+        world.insert_resource(ChangeProfiler::new(2));
+        let entities: Vec<Entity> = (0..3)
+            .map(|_| world.spawn((Position(0.0), Velocity)).id())
+            .collect();
+        // The first frame only records the starting tick
+        profile_component_changes(&mut world);
+
+        run_frame(&mut world, &entities[..2]);
+        assert!(world.resource::<ChangeProfiler>().report().is_none());
+        run_frame(&mut world, &entities[..1]);
+
+        let profiler = world.resource::<ChangeProfiler>();
+        assert_eq!(profiler.frames_sampled(), 0);
+        let report = profiler.report().unwrap();
+        assert_eq!(report.frames, 2);
+        assert_eq!(report.components.len(), 1);
+
+        let position = &report.components[0];
+        assert_eq!(position.name.shortname().to_string(), "Position");
+        assert_eq!(position.total_changes, 3);
+        assert_eq!(position.max_changes_per_frame, 2);
+        assert_eq!(position.changes_per_frame, 1.5);
+        assert!(position.changed_every_frame);
+
+        assert_eq!(report.entities[0].entity, entities[0]);
+        assert_eq!(report.entities[0].total_changes, 2);
+    }
+
+    #[test]
+    fn unchanged_components_are_not_reported() {
+        let mut world = World::new();
+        world.insert_resource(ChangeProfiler::new(1));
+        world.spawn((Position(0.0), Velocity));
+        profile_component_changes(&mut world);
+
+        run_frame(&mut world, &[]);
+
+        let report = world.resource::<ChangeProfiler>().report().unwrap();
+        assert!(report.components.is_empty());
+        assert!(report.entities.is_empty());
+    }
+
+    #[test]
+    fn inspector_entities_are_not_profiled() {
+        let mut world = World::new();
+        world.insert_resource(ChangeProfiler::new(1));
+        let internal = world.spawn((Position(0.0), InspectorInternal)).id();
+        profile_component_changes(&mut world);
+
+        run_frame(&mut world, &[internal]);
+
+        let report = world.resource::<ChangeProfiler>().report().unwrap();
+        assert!(report.components.is_empty());
+        assert!(report.entities.is_empty());
+    }
+
+    #[test]
+    fn the_plugin_reports_without_other_inspector_plugins() {
+        let mut app = App::new();
+        app.add_plugins(ChangeProfilerPlugin)
+            .insert_resource(ChangeProfiler::new(1));
+        let entity = app.world_mut().spawn(Position(0.0)).id();
+        app.update();
+
+        app.world_mut().get_mut::<Position>(entity).unwrap().0 += 1.0;
+        app.update();
+
+        let report = app.world().resource::<ChangeProfiler>().report().unwrap();
+        assert_eq!(report.components.len(), 1);
+        assert_eq!(
+            report.components[0].name.shortname().to_string(),
+            "Position"
+        );
+    }
+}
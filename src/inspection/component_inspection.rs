@@ -12,7 +12,10 @@ use core::fmt::Display;
 use thiserror::Error;
 
 use crate::entity_name_resolution::{NameDefinitionPriority, NameResolutionRegistry};
-use crate::memory_size::MemorySize;
+use crate::inspection::observer_inspection::ObserverInspection;
+use crate::inspection::relationship_inspection::RelationshipKind;
+use crate::inspection::system_inspection::AccessKind;
+use crate::memory_size::{MemoryDetailLevel, MemoryEstimate, MemorySize};
 use crate::reflection_tools::clone_incomplete;
 
 /// The result of inspecting a component.
@@ -45,11 +48,16 @@ pub struct ComponentInspection {
         serde(with = "crate::serde_conversions::debug_name")
     )]
     pub name: DebugName,
-    /// The shallow size of the component in memory.
+    /// The size of the component in memory.
     ///
-    /// Note that this may differ from the size of the component type
-    /// if the component is a dynamically-sized type: heap-allocated data is not included.
-    pub memory_size: MemorySize,
+    /// By default, only the shallow size is measured: heap-allocated data is not included.
+    /// Set [`ComponentInspectionSettings::memory_detail_level`] to [`MemoryDetailLevel::Deep`]
+    /// to also estimate the heap memory owned by the component.
+    pub memory_size: MemoryEstimate,
+    /// When the component was added and last changed, and by which code.
+    ///
+    /// This is `None` if the change ticks could not be read.
+    pub change_details: Option<ChangeDetails>,
     /// The value of the component as a string.
     ///
     /// This information is gathered via reflection,
@@ -76,6 +84,7 @@ impl Clone for ComponentInspection {
             component_id: self.component_id,
             name: self.name.clone(),
             memory_size: self.memory_size,
+            change_details: self.change_details.clone(),
             value: self.value.clone(),
             reflected_value,
         }
@@ -86,9 +95,92 @@ impl Display for ComponentInspection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let shortname = self.name.shortname();
 
-        match &self.value {
-            Some(value) => write!(f, "{shortname} ({}): {value}", &self.memory_size)?,
-            None => write!(f, "{shortname} ({})", &self.memory_size)?,
+        write!(f, "{shortname} ({}", &self.memory_size)?;
+        if let Some(change_details) = &self.change_details {
+            write!(f, "; {change_details}")?;
+        }
+        write!(f, ")")?;
+
+        if let Some(value) = &self.value {
+            write!(f, ": {value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Change detection information for a component or resource.
+///
+/// Ticks are recorded as the raw values of the [`Tick`](bevy::ecs::change_detection::Tick)s
+/// stored by Bevy, and can be compared against [`World::read_change_tick`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeDetails {
+    /// The tick on which the value was added.
+    pub added_tick: u32,
+    /// The tick on which the value was last changed.
+    ///
+    /// Adding a value counts as changing it.
+    pub changed_tick: u32,
+    /// The source location that last changed the value.
+    ///
+    /// This is only available when Bevy's `track_location` feature is enabled.
+    pub changed_by: Option<String>,
+}
+
+impl ChangeDetails {
+    /// Reads the change information for the component identified by `component_id` on `entity`.
+    ///
+    /// Resources are stored as components on a dedicated backing entity, so this serves both
+    /// component and resource inspection.
+    ///
+    /// Returns `None` if the entity does not exist or does not have the component.
+    // When upstreamed, this should be a method on `EntityRef`,
+    // exposing the `changed_by` location without the need for `unsafe`.
+    pub fn new(world: &World, entity: Entity, component_id: ComponentId) -> Option<Self> {
+        let entity_ref = world.get_entity(entity).ok()?;
+        let ticks = entity_ref.get_change_ticks_by_id(component_id)?;
+
+        let storage_type = world.components().get_info(component_id)?.storage_type();
+        let location = entity_ref.location();
+        let changed_by_cell = match storage_type {
+            StorageType::Table => world
+                .storages()
+                .tables
+                .get(location.table_id)
+                .map(|table| table.get_changed_by(component_id, location.table_row)),
+            StorageType::SparseSet => world
+                .storages()
+                .sparse_sets
+                .get(component_id)
+                .map(|sparse_set| sparse_set.get_changed_by(entity)),
+        };
+
+        let changed_by = changed_by_cell
+            .and_then(|cell| cell.into_option().flatten())
+            // SAFETY: we hold a shared reference to the world,
+            // so nothing can be writing to the location concurrently.
+            .map(|cell| unsafe { *cell.get() })
+            .map(|location| location.to_string());
+
+        Some(Self {
+            added_tick: ticks.added.get(),
+            changed_tick: ticks.changed.get(),
+            changed_by,
+        })
+    }
+}
+
+impl Display for ChangeDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "added on tick {}, changed on tick {}",
+            self.added_tick, self.changed_tick
+        )?;
+
+        if let Some(changed_by) = &self.changed_by {
+            write!(f, " by {changed_by}")?;
         }
 
         Ok(())
@@ -159,6 +251,8 @@ pub struct ComponentTypeMetadata {
         serde(with = "crate::serde_conversions::slice_component_id")
     )]
     pub required_components: Vec<ComponentId>,
+    /// Which side of a relationship this component type represents, if any.
+    pub relationship_kind: Option<RelationshipKind>,
     /// The type information of the component.
     ///
     /// This contains metadata about the component's type,
@@ -212,6 +306,7 @@ impl ComponentTypeMetadata {
             storage_type: component_info.storage_type(),
             is_send_and_sync: component_info.is_send_and_sync(),
             required_components: component_info.required_components().iter_ids().collect(),
+            relationship_kind: RelationshipKind::of(component_info),
             type_registration,
         })
     }
@@ -249,6 +344,19 @@ pub struct ComponentTypeInspection {
     /// This information does not vary based on the state of the world,
     /// and can safely be cached and reused.
     pub metadata: ComponentTypeMetadata,
+    /// The systems which read or write this component or resource.
+    ///
+    /// Systems in built schedules are only listed if their access was recorded by the
+    /// [`ScheduleInspectionPlugin`](crate::inspection::schedule_inspection::ScheduleInspectionPlugin),
+    /// and one-shot systems are only listed if they have
+    /// [`OneShotSystemMetadata`](crate::inspection::system_inspection::OneShotSystemMetadata).
+    /// Systems with exclusive world access are not listed, as they could access any type.
+    pub accessing_systems: Vec<ComponentAccessor>,
+    /// The observers which watch this component, such as observers for `On<Add, C>`.
+    ///
+    /// Bevy does not expose the data accessed by observers,
+    /// so observers which only read or write the component in their system are not listed.
+    pub observers: Vec<ObserverInspection>,
 }
 
 impl Display for ComponentTypeInspection {
@@ -257,7 +365,60 @@ impl Display for ComponentTypeInspection {
             f,
             "{}\n Entities with this component: {}",
             self.metadata, self.entity_count
-        )
+        )?;
+
+        for access_kind in [AccessKind::Write, AccessKind::Read] {
+            let systems: Vec<String> = self
+                .accessing_systems
+                .iter()
+                .filter(|accessor| accessor.access_kind == access_kind)
+                .map(ToString::to_string)
+                .collect();
+            if !systems.is_empty() {
+                let label = match access_kind {
+                    AccessKind::Read => "Read by",
+                    AccessKind::Write => "Written by",
+                };
+                write!(f, "\n {label}: [{}]", systems.join(", "))?;
+            }
+        }
+
+        if !self.observers.is_empty() {
+            let observers: Vec<String> = self
+                .observers
+                .iter()
+                .map(|observer| observer.system_name.shortname().to_string())
+                .collect();
+            write!(f, "\n Observed by: [{}]", observers.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A system which accesses a component or resource type,
+/// as reported in [`ComponentTypeInspection::accessing_systems`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComponentAccessor {
+    /// The name of the system.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub system_name: DebugName,
+    /// The label of the schedule which contains the system, or `None` for one-shot systems.
+    pub schedule: Option<String>,
+    /// Whether the system writes or only reads the type.
+    pub access_kind: AccessKind,
+}
+
+impl Display for ComponentAccessor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.schedule {
+            Some(schedule) => write!(f, "{} ({schedule})", self.system_name.shortname()),
+            None => write!(f, "{} (one-shot)", self.system_name.shortname()),
+        }
     }
 }
 
@@ -394,6 +555,13 @@ pub struct ComponentInspectionSettings {
     ///
     /// Defaults to `false`.
     pub store_reflected_value: bool,
+    /// How thoroughly the memory used by the component should be measured.
+    ///
+    /// Deep measurement requires the component type to be reflected,
+    /// and falls back to a shallow measurement otherwise.
+    ///
+    /// Defaults to [`MemoryDetailLevel::Shallow`].
+    pub memory_detail_level: MemoryDetailLevel,
 }
 
 /// The amount of component information to include when inspecting an entity.
@@ -420,6 +588,79 @@ impl Default for ComponentInspectionSettings {
             detail_level: ComponentDetailLevel::Values,
             full_type_names: false,
             store_reflected_value: false,
+            memory_detail_level: MemoryDetailLevel::Shallow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_name_resolution::NameResolutionPlugin;
+    use crate::extension_methods::WorldInspectionExtensionTrait;
+    use crate::inspection::schedule_inspection::ScheduleInspectionPlugin;
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    fn heal(mut healths: Query<&mut Health>) {
+        for mut health in &mut healths {
+            health.0 += 1;
         }
     }
+
+    fn count_health(healths: Query<&Health>) {
+        let _total: u32 = healths.iter().map(|health| health.0).sum();
+    }
+
+    #[test]
+    fn change_details_track_mutations() {
+        let mut world = World::new();
+        let entity = world.spawn(Health(10)).id();
+        let component_id = world.component_id::<Health>().unwrap();
+
+        let added = ChangeDetails::new(&world, entity, component_id).unwrap();
+        assert_eq!(added.added_tick, added.changed_tick);
+
+        world.increment_change_tick();
+        world.get_mut::<Health>(entity).unwrap().0 = 5;
+
+        let changed = ChangeDetails::new(&world, entity, component_id).unwrap();
+        assert_eq!(changed.added_tick, added.added_tick);
+        assert!(changed.changed_tick > added.changed_tick);
+        assert!(changed.changed_by.is_some());
+    }
+
+    #[test]
+    fn change_details_require_the_component() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let component_id = world.register_component::<Health>();
+
+        assert!(ChangeDetails::new(&world, entity, component_id).is_none());
+    }
+
+    #[test]
+    fn component_types_list_accessing_systems_and_observers() {
+        let mut app = App::new();
+        app.add_plugins((ScheduleInspectionPlugin, NameResolutionPlugin))
+            .add_systems(Update, (heal, count_health))
+            .add_observer(|_: On<Add, Health>| {});
+        app.finish();
+        app.update();
+
+        let inspection = app.world().inspect_component_type::<Health>().unwrap();
+
+        let accessor = |name: &str| {
+            inspection
+                .accessing_systems
+                .iter()
+                .find(|accessor| accessor.system_name.shortname().to_string() == name)
+                .unwrap()
+        };
+        assert_eq!(accessor("heal").access_kind, AccessKind::Write);
+        assert_eq!(accessor("count_health").access_kind, AccessKind::Read);
+        assert_eq!(accessor("heal").schedule.as_deref(), Some("Update"));
+        assert_eq!(inspection.observers.len(), 1);
+    }
 }
@@ -0,0 +1,373 @@
+//! Tracing the component lifecycle events of individual entities.
+//!
+//! Inspecting an entity only shows its current components.
+//! To see how it got there, [`trace_entity`] installs observers on the entity,
+//! which record each [`Add`], [`Insert`], [`Discard`], [`Remove`] and [`Despawn`] event
+//! for its components as a [`ComponentTraceRecord`] in the [`ComponentTraces`] resource.
+//!
+//! Only events that happen while the entity is traced are recorded.
+
+use std::collections::VecDeque;
+
+use bevy::{
+    ecs::{
+        component::Components, event::EntityComponentsTrigger, observer::ObservedBy,
+        system::SystemChangeTick,
+    },
+    platform::collections::HashMap,
+    prelude::*,
+};
+use core::fmt::Display;
+use thiserror::Error;
+
+/// The kind of component lifecycle event described by a [`ComponentTraceRecord`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComponentLifecycleEvent {
+    /// The components were added to the entity, which did not have them before.
+    Add,
+    /// The components were inserted, whether or not the entity already had them.
+    Insert,
+    /// The existing values of the components were about to be discarded,
+    /// because they were replaced or removed.
+    Discard,
+    /// The components were about to be removed from the entity.
+    Remove,
+    /// The entity was being despawned with these components.
+    ///
+    /// This is recorded before the components are discarded and removed.
+    Despawn,
+}
+
+/// A single component lifecycle event on a traced entity.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComponentTraceRecord {
+    /// The kind of lifecycle event.
+    pub event: ComponentLifecycleEvent,
+    /// The type names of the components affected by the event.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::vec_debug_name")
+    )]
+    pub components: Vec<DebugName>,
+    /// The change tick when the event was observed.
+    pub tick: u32,
+    /// The source location that caused the event.
+    ///
+    /// This is only available when Bevy's `track_location` feature is enabled.
+    pub caller: Option<String>,
+}
+
+impl Display for ComponentTraceRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let components: Vec<String> = self
+            .components
+            .iter()
+            .map(|name| name.shortname().to_string())
+            .collect();
+        write!(
+            f,
+            "[tick {}] {:?} [{}]",
+            self.tick,
+            self.event,
+            components.join(", ")
+        )?;
+        if let Some(caller) = &self.caller {
+            write!(f, " by {caller}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The recorded component lifecycle events of each entity traced with [`trace_entity`].
+#[derive(Resource, Debug)]
+pub struct ComponentTraces {
+    /// The maximum number of records kept for each entity,
+    /// after which the oldest records of that entity are discarded.
+    ///
+    /// Defaults to 256.
+    pub capacity: usize,
+    traces: HashMap<Entity, EntityTrace>,
+}
+
+impl Default for ComponentTraces {
+    fn default() -> Self {
+        Self {
+            capacity: 256,
+            traces: HashMap::default(),
+        }
+    }
+}
+
+/// The observers and records of a single traced entity.
+#[derive(Debug, Default)]
+struct EntityTrace {
+    /// The observers recording events, which are empty once tracing has stopped.
+    observers: Vec<Entity>,
+    records: VecDeque<ComponentTraceRecord>,
+}
+
+impl ComponentTraces {
+    /// Returns `true` if the `entity` is currently being traced.
+    pub fn is_traced(&self, entity: Entity) -> bool {
+        self.traces
+            .get(&entity)
+            .is_some_and(|trace| !trace.observers.is_empty())
+    }
+
+    /// Returns the recorded events of the `entity`, oldest first.
+    ///
+    /// Records are kept after tracing stops, until [`Self::clear`] is called.
+    pub fn records(
+        &self,
+        entity: Entity,
+    ) -> impl DoubleEndedIterator<Item = &ComponentTraceRecord> {
+        self.traces
+            .get(&entity)
+            .into_iter()
+            .flat_map(|trace| trace.records.iter())
+    }
+
+    /// Returns the entities which are currently being traced.
+    pub fn traced_entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.traces
+            .iter()
+            .filter(|(_, trace)| !trace.observers.is_empty())
+            .map(|(entity, _)| *entity)
+    }
+
+    /// Removes the recorded events of the `entity`, without affecting whether it is traced.
+    pub fn clear(&mut self, entity: Entity) {
+        if let Some(trace) = self.traces.get_mut(&entity) {
+            trace.records.clear();
+        }
+    }
+
+    fn push(&mut self, entity: Entity, record: ComponentTraceRecord) {
+        let capacity = self.capacity.max(1);
+        let trace = self.traces.entry(entity).or_default();
+        while trace.records.len() >= capacity {
+            trace.records.pop_front();
+        }
+        trace.records.push_back(record);
+    }
+}
+
+/// An error that can occur when attempting to trace an entity.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComponentTraceError {
+    /// The entity does not exist in the world.
+    #[error("Entity not found: {0}")]
+    EntityNotFound(Entity),
+}
+
+/// Starts recording the component lifecycle events of the `entity` in the [`ComponentTraces`] resource,
+/// initializing it if needed.
+///
+/// Returns the observer entities that record the events,
+/// which are despawned by [`stop_tracing_entity`] or when the `entity` is despawned.
+/// Tracing an entity which is already traced returns its existing observers.
+pub fn trace_entity(world: &mut World, entity: Entity) -> Result<Vec<Entity>, ComponentTraceError> {
+    if world.get_entity(entity).is_err() {
+        return Err(ComponentTraceError::EntityNotFound(entity));
+    }
+
+    world.init_resource::<ComponentTraces>();
+    if let Some(trace) = world.resource::<ComponentTraces>().traces.get(&entity)
+        && !trace.observers.is_empty()
+    {
+        return Ok(trace.observers.clone());
+    }
+
+    let observers: Vec<Entity> = [
+        trace_observer::<Add>(ComponentLifecycleEvent::Add, entity),
+        trace_observer::<Insert>(ComponentLifecycleEvent::Insert, entity),
+        trace_observer::<Discard>(ComponentLifecycleEvent::Discard, entity),
+        trace_observer::<Remove>(ComponentLifecycleEvent::Remove, entity),
+        trace_observer::<Despawn>(ComponentLifecycleEvent::Despawn, entity),
+        enable_lifecycle_event::<Add>(entity),
+        enable_lifecycle_event::<Insert>(entity),
+        enable_lifecycle_event::<Discard>(entity),
+        enable_lifecycle_event::<Remove>(entity),
+        enable_lifecycle_event::<Despawn>(entity),
+    ]
+    .into_iter()
+    .map(|observer| world.spawn(observer).id())
+    .collect();
+    // Observers are registered by a queued command, so they would miss the next events otherwise
+    world.flush();
+
+    world
+        .resource_mut::<ComponentTraces>()
+        .traces
+        .entry(entity)
+        .or_default()
+        .observers
+        .clone_from(&observers);
+    Ok(observers)
+}
+
+/// Stops recording the component lifecycle events of the `entity`, despawning its observers.
+///
+/// The events recorded so far are kept in the [`ComponentTraces`] resource.
+pub fn stop_tracing_entity(world: &mut World, entity: Entity) {
+    let Some(mut traces) = world.get_resource_mut::<ComponentTraces>() else {
+        return;
+    };
+    let observers = traces
+        .traces
+        .get_mut(&entity)
+        .map(|trace| core::mem::take(&mut trace.observers))
+        .unwrap_or_default();
+    for observer in observers {
+        // The observers are already gone if the traced entity was despawned
+        if let Ok(observer) = world.get_entity_mut(observer) {
+            observer.despawn();
+        }
+    }
+}
+
+/// Creates an observer that does nothing, watching the `E` event for the [`ObservedBy`] component of the `entity`.
+///
+/// Bevy only triggers lifecycle events on archetypes with a component type that has an observer for that event,
+/// so the observers created by [`trace_observer`], which are not tied to any component, never run on their own.
+/// Every traced entity has the [`ObservedBy`] component, so watching it ensures that the events are triggered.
+fn enable_lifecycle_event<E>(entity: Entity) -> Observer
+where
+    E: EntityEvent + for<'a> Event<Trigger<'a> = EntityComponentsTrigger<'a>>,
+{
+    Observer::new(|_: On<E, ObservedBy>| {}).with_entity(entity)
+}
+
+/// Creates an observer that records each `E` event targeting the `entity`, for any of its components.
+fn trace_observer<E>(event: ComponentLifecycleEvent, entity: Entity) -> Observer
+where
+    E: EntityEvent + for<'a> Event<Trigger<'a> = EntityComponentsTrigger<'a>>,
+{
+    Observer::new(
+        move |on: On<E>,
+              components: &Components,
+              ticks: SystemChangeTick,
+              mut traces: ResMut<ComponentTraces>| {
+            // `ObservedBy` is added and changed by the tracing observers themselves
+            let observed_by = components.component_id::<ObservedBy>();
+            let affected: Vec<DebugName> = on
+                .trigger()
+                .components
+                .iter()
+                .filter(|component_id| Some(**component_id) != observed_by)
+                .filter_map(|component_id| components.get_name(*component_id))
+                .collect();
+            if affected.is_empty() && event != ComponentLifecycleEvent::Despawn {
+                return;
+            }
+
+            let record = ComponentTraceRecord {
+                event,
+                components: affected,
+                tick: ticks.this_run().get(),
+                caller: on
+                    .caller()
+                    .into_option()
+                    .map(|location| location.to_string()),
+            };
+            traces.push(entity, record);
+
+            if event == ComponentLifecycleEvent::Despawn
+                && let Some(trace) = traces.traces.get_mut(&entity)
+            {
+                // Bevy despawns observers watching an entity along with it
+                trace.observers.clear();
+            }
+        },
+    )
+    .with_entity(entity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Health;
+
+    #[derive(Component)]
+    struct Poisoned;
+
+    #[test]
+    fn lifecycle_events_are_recorded_in_order() {
+        let mut world = World::new();
+        let entity = world.spawn(Health).id();
+        trace_entity(&mut world, entity).unwrap();
+        assert!(world.resource::<ComponentTraces>().is_traced(entity));
+
+        world.entity_mut(entity).insert(Poisoned);
+        world.entity_mut(entity).remove::<Poisoned>();
+
+        let events: Vec<_> = world
+            .resource::<ComponentTraces>()
+            .records(entity)
+            .map(|record| record.event)
+            .collect();
+        assert_eq!(
+            events,
+            [
+                ComponentLifecycleEvent::Add,
+                ComponentLifecycleEvent::Insert,
+                ComponentLifecycleEvent::Discard,
+                ComponentLifecycleEvent::Remove,
+            ]
+        );
+
+        let record = world
+            .resource::<ComponentTraces>()
+            .records(entity)
+            .next()
+            .unwrap();
+        assert_eq!(record.components.len(), 1);
+        assert_eq!(record.components[0].shortname().to_string(), "Poisoned");
+    }
+
+    #[test]
+    fn despawning_records_despawn_before_discard_and_remove() {
+        let mut world = World::new();
+        let entity = world.spawn(Health).id();
+        trace_entity(&mut world, entity).unwrap();
+
+        world.despawn(entity);
+
+        let traces = world.resource::<ComponentTraces>();
+        let events: Vec<_> = traces.records(entity).map(|record| record.event).collect();
+        assert_eq!(
+            events,
+            [
+                ComponentLifecycleEvent::Despawn,
+                ComponentLifecycleEvent::Discard,
+                ComponentLifecycleEvent::Remove,
+            ]
+        );
+        assert!(!traces.is_traced(entity));
+    }
+
+    #[test]
+    fn stopping_a_trace_keeps_its_records() {
+        let mut world = World::new();
+        let entity = world.spawn(Health).id();
+        let observers = trace_entity(&mut world, entity).unwrap();
+
+        world.entity_mut(entity).insert(Poisoned);
+        stop_tracing_entity(&mut world, entity);
+        world.entity_mut(entity).remove::<Poisoned>();
+
+        let traces = world.resource::<ComponentTraces>();
+        assert!(!traces.is_traced(entity));
+        assert_eq!(traces.records(entity).count(), 2);
+        assert!(
+            observers
+                .iter()
+                .all(|observer| world.get_entity(*observer).is_err())
+        );
+    }
+}
@@ -8,22 +8,32 @@
 
 use bevy::{
     ecs::{
+        archetype::{Archetype, ArchetypeId},
         component::ComponentId,
         entity::EntityNotSpawnedError,
+        entity_disabling::DefaultQueryFilters,
         query::{QueryEntityError, SpawnDetails},
     },
+    platform::collections::HashSet,
     prelude::*,
 };
 use core::fmt::Display;
+use std::borrow::Cow;
 use thiserror::Error;
 
 use crate::{
+    entity_categories::{CategoryRegistry, EntityCategory},
     entity_grouping::GroupingStrategy,
     entity_name_resolution::EntityName,
-    inspection::component_inspection::{
-        ComponentDetailLevel, ComponentInspection, ComponentInspectionSettings,
+    inspection::{
+        component_inspection::{
+            ComponentDetailLevel, ComponentInspection, ComponentInspectionSettings,
+        },
+        entity_lifecycle::LifecycleRecord,
+        relationship_inspection::RelationshipInspection,
+        value_predicate::ValuePredicate,
     },
-    memory_size::MemorySize,
+    memory_size::MemoryEstimate,
 };
 
 /// The result of inspecting an entity.
@@ -36,15 +46,27 @@ pub struct EntityInspection {
     pub name: Option<EntityName>,
     /// The total size of the entity in memory.
     ///
-    /// This is computed as the sum of the sizes of all its components,
-    /// and is likely to be an underestimate as non-reflected components
-    /// will not contribute to the total size.
+    /// This is computed as the sum of the sizes of all its components.
+    /// When measuring deeply, non-reflected components only contribute their shallow size,
+    /// so the heap size is likely to be an underestimate.
     ///
     /// If [`include_components`](EntityInspectionSettings::include_components) is false,
-    /// this will always be [`None`].
-    pub total_memory_size: Option<MemorySize>,
+    /// this will always be [`MemoryEstimate::Unknown`].
+    pub total_memory_size: MemoryEstimate,
     /// The components on the entity, in inspection form.
     pub components: Option<Vec<ComponentInspection>>,
+    /// The relationships this entity participates in, such as its parent or children.
+    ///
+    /// Each relationship component on the entity is inspected separately.
+    ///
+    /// If [`include_relationships`](EntityInspectionSettings::include_relationships) is false,
+    /// this will always be `None`.
+    pub relationships: Option<Vec<RelationshipInspection>>,
+    /// The [`EntityCategory`]s this entity belongs to, in registration order.
+    ///
+    /// Categories are determined by the rules in the [`CategoryRegistry`].
+    /// If that resource does not exist, this will always be empty.
+    pub categories: Vec<EntityCategory>,
     /// Information about how this entity was spawned.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub spawn_details: Option<SpawnDetails>,
@@ -59,8 +81,13 @@ impl Display for EntityInspection {
             None => "Entity",
         };
         display_str.push_str(&format!("{name_str} ({})", self.entity));
-        if let Some(total_size) = &self.total_memory_size {
-            display_str.push_str(&format!("\nMemory Size: {}", total_size));
+        if self.total_memory_size.is_known() {
+            display_str.push_str(&format!("\nMemory Size: {}", self.total_memory_size));
+        }
+        if !self.categories.is_empty() {
+            let categories: Vec<&str> =
+                self.categories.iter().map(EntityCategory::as_str).collect();
+            display_str.push_str(&format!("\nCategories: {}", categories.join(", ")));
         }
 
         if let Some(spawn_details) = self.spawn_details {
@@ -87,6 +114,15 @@ impl Display for EntityInspection {
                 display_str.push_str(&format!("\n- {}", component));
             }
         }
+
+        if let Some(relationships) = &self.relationships
+            && !relationships.is_empty()
+        {
+            display_str.push_str("\nRelationships:");
+            for relationship in relationships {
+                display_str.push_str(&format!("\n- {relationship}"));
+            }
+        }
         write!(f, "{display_str}")?;
 
         Ok(())
@@ -101,6 +137,13 @@ pub enum EntityInspectionError {
     #[error("Entity not found: {0}")]
     #[cfg_attr(feature = "serde", serde(skip))]
     EntityNotFound(EntityNotSpawnedError),
+    /// The entity has been despawned, as recorded in the
+    /// [`EntityLifecycleHistory`](crate::inspection::entity_lifecycle::EntityLifecycleHistory).
+    ///
+    /// This is returned instead of [`EntityInspectionError::EntityNotFound`]
+    /// when the [`EntityLifecyclePlugin`](crate::inspection::entity_lifecycle::EntityLifecyclePlugin) remembers the entity.
+    #[error("{0}")]
+    Despawned(Box<LifecycleRecord>),
     /// A catch-all variant for inspection errors that should never happen
     /// when just querying an entity and its metadata.
     #[error("Unexpected QueryEntityError: {0}")]
@@ -131,6 +174,12 @@ pub struct EntityInspectionSettings {
     /// The detail level of component information can be further configured
     /// using [`ComponentInspectionSettings::detail_level`].
     pub include_components: bool,
+    /// Should relationship information be included in the inspection?
+    ///
+    /// This covers every [`Relationship`](bevy::ecs::relationship::Relationship)
+    /// and [`RelationshipTarget`](bevy::ecs::relationship::RelationshipTarget) component,
+    /// not just the entity hierarchy.
+    pub include_relationships: bool,
     /// Settings used when inspecting components on the entity.
     pub component_settings: ComponentInspectionSettings,
 }
@@ -139,6 +188,7 @@ impl Default for EntityInspectionSettings {
     fn default() -> Self {
         Self {
             include_components: true,
+            include_relationships: true,
             component_settings: ComponentInspectionSettings::default(),
         }
     }
@@ -173,12 +223,53 @@ pub struct MultipleEntityInspectionSettings {
         serde(with = "crate::serde_conversions::slice_component_id")
     )]
     pub without_component_filter: Vec<ComponentId>,
+    /// Components that must have changed since the last frame on each entity to be inspected.
+    ///
+    /// More precisely, the components must have changed since the world's change trackers
+    /// were last cleared, which usually happens once per frame.
+    /// If empty, no change filtering will be applied.
+    ///
+    /// Defaults to an empty list.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::slice_component_id")
+    )]
+    pub changed_component_filter: Vec<ComponentId>,
+    /// Predicates over reflected component fields that each entity to be inspected must satisfy.
+    ///
+    /// Entities that lack a component used by a predicate are filtered out.
+    /// If empty, no value filtering will be applied.
+    ///
+    /// Defaults to an empty list.
+    pub value_predicates: Vec<ValuePredicate>,
+    /// Categories that each entity to be inspected must belong to.
+    ///
+    /// Categories are determined by the rules in the [`CategoryRegistry`].
+    /// If that resource does not exist, no entities will match a non-empty filter.
+    /// If empty, no category filtering will be applied.
+    ///
+    /// Defaults to an empty list.
+    pub category_filter: Vec<EntityCategory>,
+    /// The number of matching entities to skip before inspecting.
+    ///
+    /// Matching entities are counted in the order given by [`EntityGrouping::flatten`](crate::entity_grouping::EntityGrouping::flatten),
+    /// which is stable as long as the world does not change.
+    /// Combine this with [`Self::limit`] to page through large worlds.
+    ///
+    /// Defaults to 0.
+    pub offset: usize,
+    /// The maximum number of entities to inspect.
+    ///
+    /// If `None`, every matching entity after the [`Self::offset`] is inspected.
+    ///
+    /// Defaults to `None`.
+    pub limit: Option<usize>,
     /// Settings used when inspecting each individual entity.
     ///
     /// Note that the default values are not the same as [`EntityInspectionSettings::default`].
     ///
-    /// By default, only component names are included to improve performance
-    /// and improve readability when inspecting many entities at once.
+    /// By default, only component names are included and relationships are skipped,
+    /// to improve performance and readability when inspecting many entities at once.
     pub entity_settings: EntityInspectionSettings,
     /// Specifies how entities should be grouped.
     pub grouping_strategy: GroupingStrategy,
@@ -190,11 +281,17 @@ impl Default for MultipleEntityInspectionSettings {
             name_filter: None,
             with_component_filter: Vec::new(),
             without_component_filter: Vec::new(),
+            changed_component_filter: Vec::new(),
+            value_predicates: Vec::new(),
+            category_filter: Vec::new(),
+            offset: 0,
+            limit: None,
             entity_settings: EntityInspectionSettings {
                 component_settings: ComponentInspectionSettings {
                     detail_level: ComponentDetailLevel::Names,
                     ..Default::default()
                 },
+                include_relationships: false,
                 ..Default::default()
             },
             grouping_strategy: GroupingStrategy::Hierarchy,
@@ -202,16 +299,65 @@ impl Default for MultipleEntityInspectionSettings {
     }
 }
 
+/// A single page of results from inspecting multiple entities.
+///
+/// Iterate over this to get the individual inspection results.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityInspectionPage {
+    /// The results of inspecting each entity on this page, in the order given by
+    /// [`EntityGrouping::flatten`](crate::entity_grouping::EntityGrouping::flatten).
+    pub inspections: Vec<Result<EntityInspection, EntityInspectionError>>,
+    /// The number of entities that matched the filters, across all pages.
+    pub total_matches: usize,
+    /// The position of the first entity on this page among all matching entities.
+    pub offset: usize,
+}
+
+impl EntityInspectionPage {
+    /// Returns true if there are more matching entities after this page.
+    pub fn has_more(&self) -> bool {
+        self.offset + self.inspections.len() < self.total_matches
+    }
+
+    /// Returns the [`MultipleEntityInspectionSettings::offset`] to use for requesting the next page.
+    pub fn next_offset(&self) -> usize {
+        self.offset + self.inspections.len()
+    }
+}
+
+impl IntoIterator for EntityInspectionPage {
+    type Item = Result<EntityInspection, EntityInspectionError>;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inspections.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a EntityInspectionPage {
+    type Item = &'a Result<EntityInspection, EntityInspectionError>;
+    type IntoIter = core::slice::Iter<'a, Result<EntityInspection, EntityInspectionError>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inspections.iter()
+    }
+}
+
 /// A filter for named entities.
 ///
 /// For convenience, the [`From`] trait has been implemented
 /// for converting from [`String`], `&String` and [`&str`],
 /// so you can construct using `NameFilter::from("name")`.
 /// Keep in mind that in this case the matches will be case-insensitive.
+///
+/// If the query contains a `*` wildcard, the whole name must match the pattern,
+/// with each `*` standing in for any sequence of characters (e.g. `duck*`).
+/// Otherwise, any name containing the query matches.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NameFilter {
-    /// The substring that entities have to match to be included.
+    /// The substring (or wildcard pattern) that entities have to match to be included.
     query: String,
     /// Whether case matters.
     case_sensitive: bool,
@@ -233,14 +379,45 @@ impl NameFilter {
 
     /// Whether the given `name` matches this filter.
     pub fn matches(&self, name: &str) -> bool {
-        if self.case_sensitive {
-            name.contains(&self.query)
+        let name = if self.case_sensitive {
+            Cow::Borrowed(name)
         } else {
-            name.to_lowercase().contains(&self.query)
+            Cow::Owned(name.to_lowercase())
+        };
+
+        if self.query.contains('*') {
+            matches_wildcard_pattern(&self.query, &name)
+        } else {
+            name.contains(&self.query)
         }
     }
 }
 
+/// Checks whether the whole of `text` matches `pattern`, where `*` matches any sequence of characters.
+fn matches_wildcard_pattern(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // There is always at least one part, even for an empty pattern
+    let first = parts.next().unwrap_or_default();
+    let Some(mut remaining) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let mut parts = parts.peekable();
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            // The last part must be at the very end of the text
+            return remaining.ends_with(part);
+        }
+        match remaining.find(part) {
+            Some(index) => remaining = &remaining[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    // No wildcards at all, so the whole text must have been consumed
+    remaining.is_empty()
+}
+
 impl From<String> for NameFilter {
     fn from(value: String) -> Self {
         Self {
@@ -268,41 +445,134 @@ impl From<&str> for NameFilter {
     }
 }
 
+impl MultipleEntityInspectionSettings {
+    /// Checks whether the entities in the `archetype` pass the component presence filters of these settings.
+    ///
+    /// All entities in an archetype share the same set of components,
+    /// so these filters can be checked once per archetype rather than once per entity.
+    pub fn matches_archetype(&self, archetype: &Archetype) -> bool {
+        self.with_component_filter
+            .iter()
+            .all(|component_id| archetype.contains(*component_id))
+            && !self
+                .without_component_filter
+                .iter()
+                .any(|component_id| archetype.contains(*component_id))
+            // Components can only have changed if they are present
+            && self
+                .changed_component_filter
+                .iter()
+                .all(|component_id| archetype.contains(*component_id))
+    }
+}
+
+/// Returns every entity in the `world` that matches the provided [`MultipleEntityInspectionSettings`].
+///
+/// Only the archetypes that pass [`MultipleEntityInspectionSettings::matches_archetype`] are visited,
+/// so entities whose components rule them out are never looked at individually.
+///
+/// As with queries, entities with a disabling component from the [`DefaultQueryFilters`]
+/// are skipped, unless that component is in the [`MultipleEntityInspectionSettings::with_component_filter`].
+pub fn entities_matching_inspection_filter(
+    world: &World,
+    settings: &MultipleEntityInspectionSettings,
+) -> Vec<Entity> {
+    let default_filters = world.get_resource::<DefaultQueryFilters>();
+    let is_disabled = |archetype: &Archetype| {
+        default_filters.is_some_and(|filters| {
+            filters.disabling_ids().any(|component_id| {
+                archetype.contains(component_id)
+                    && !settings.with_component_filter.contains(&component_id)
+            })
+        })
+    };
+
+    world
+        .archetypes()
+        .iter()
+        .filter(|archetype| settings.matches_archetype(archetype) && !is_disabled(archetype))
+        .flat_map(Archetype::entities)
+        .map(|archetype_entity| world.entity(archetype_entity.id()))
+        .filter(|entity_ref| does_entity_match_inspection_filter(world, *entity_ref, settings))
+        .map(|entity_ref| entity_ref.id())
+        .collect()
+}
+
 /// Filters the provided entity list in-place according to the provided [`MultipleEntityInspectionSettings`].
-// PERF: this might be faster if you build a dynamic query instead of checking each entity individually.
+///
+/// Component presence is checked once per archetype (see [`MultipleEntityInspectionSettings::matches_archetype`]),
+/// while the remaining filters are only checked for the entities in matching archetypes.
+///
+/// Each entity in the list still has to be looked up,
+/// so prefer [`entities_matching_inspection_filter`] when filtering every entity in the world.
 pub fn filter_entity_list_for_inspection(
     world: &World,
     entities: &mut Vec<Entity>,
     settings: &MultipleEntityInspectionSettings,
 ) {
-    entities.retain(|entity| does_entity_match_inspection_filter(world, *entity, settings));
+    let matching_archetypes: HashSet<ArchetypeId> = world
+        .archetypes()
+        .iter()
+        .filter(|archetype| settings.matches_archetype(archetype))
+        .map(Archetype::id)
+        .collect();
+
+    entities.retain(|entity| {
+        world.get_entity(*entity).is_ok_and(|entity_ref| {
+            matching_archetypes.contains(&entity_ref.archetype().id())
+                && does_entity_match_inspection_filter(world, entity_ref, settings)
+        })
+    });
 }
 
-/// Checks if a single entity matches the provided [`MultipleEntityInspectionSettings`].
+/// Checks if a single entity matches the filters in the provided [`MultipleEntityInspectionSettings`]
+/// that depend on more than its archetype: its name, categories, change ticks and component values.
 fn does_entity_match_inspection_filter(
     world: &World,
-    entity: Entity,
+    e: EntityRef,
     settings: &MultipleEntityInspectionSettings,
 ) -> bool {
-    let Ok(e) = world.get_entity(entity) else {
-        return false;
-    };
-    let include = &settings.with_component_filter;
-    let exclude = &settings.without_component_filter;
+    let changed = &settings.changed_component_filter;
+    let (last_run, this_run) = (world.last_change_tick(), world.read_change_tick());
 
     let matches_name = settings.name_filter.as_ref().is_none_or(|filter| {
         e.get::<Name>()
             .is_some_and(|name| filter.matches(name.as_str()))
     });
 
+    let matches_categories = settings.category_filter.is_empty()
+        || world
+            .get_resource::<CategoryRegistry>()
+            .is_some_and(|registry| {
+                settings
+                    .category_filter
+                    .iter()
+                    .all(|category| registry.is_in_category(e, category))
+            });
+
     matches_name
-        && include.iter().all(|cid| e.contains_id(*cid))
-        && !exclude.iter().any(|cid| e.contains_id(*cid))
+        && matches_categories
+        && changed.iter().all(|cid| {
+            e.get_change_ticks_by_id(*cid)
+                .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+        })
+        && settings
+            .value_predicates
+            .iter()
+            .all(|predicate| predicate.matches(world, e.id()))
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::inspection::entity_inspection::NameFilter;
+    use bevy::{ecs::entity_disabling::Disabled, prelude::*};
+
+    use crate::{
+        entity_categories::{CategoryRegistry, EntityCategory},
+        inspection::entity_inspection::{
+            MultipleEntityInspectionSettings, NameFilter, entities_matching_inspection_filter,
+            filter_entity_list_for_inspection,
+        },
+    };
 
     #[test]
     fn case_insensitive() {
@@ -336,4 +606,94 @@ mod tests {
             assert_eq!(matches, expected)
         }
     }
+
+    #[test]
+    fn wildcards() {
+        let filter = NameFilter::from("duck*");
+        assert!(filter.matches("Duck"));
+        assert!(filter.matches("duckling"));
+        assert!(!filter.matches("big duck"));
+
+        let filter = NameFilter::from("*d*ck");
+        assert!(filter.matches("big duck"));
+        assert!(filter.matches("dock"));
+        assert!(!filter.matches("ducks"));
+    }
+
+    #[derive(Component)]
+    struct Bird;
+
+    #[derive(Component)]
+    struct Hidden;
+
+    #[test]
+    fn component_filters_match_archetypes() {
+        let mut world = World::new();
+        let bird = world.register_component::<Bird>();
+        let hidden = world.register_component::<Hidden>();
+        let duck = world.spawn((Name::new("Duck"), Bird)).id();
+        let goose = world.spawn((Name::new("Goose"), Bird)).id();
+        let hidden_duck = world.spawn((Name::new("Duck"), Bird, Hidden)).id();
+        let empty = world.spawn_empty().id();
+
+        let settings = MultipleEntityInspectionSettings {
+            name_filter: Some(NameFilter::from("duck")),
+            with_component_filter: vec![bird],
+            without_component_filter: vec![hidden],
+            ..default()
+        };
+        let mut entities = vec![empty, hidden_duck, goose, duck];
+        filter_entity_list_for_inspection(&world, &mut entities, &settings);
+
+        assert_eq!(entities, vec![duck]);
+    }
+
+    #[test]
+    fn matching_entities_are_found_per_archetype() {
+        let mut world = World::new();
+        let bird = world.register_component::<Bird>();
+        let hidden = world.register_component::<Hidden>();
+        let duck = world.spawn((Name::new("Duck"), Bird)).id();
+        world.spawn((Name::new("Goose"), Bird));
+        world.spawn((Name::new("Duck"), Bird, Hidden));
+        world.spawn((Name::new("Duck"), Bird, Disabled));
+        world.spawn(Name::new("Duck"));
+
+        let settings = MultipleEntityInspectionSettings {
+            name_filter: Some(NameFilter::from("duck")),
+            with_component_filter: vec![bird],
+            without_component_filter: vec![hidden],
+            ..default()
+        };
+
+        assert_eq!(
+            entities_matching_inspection_filter(&world, &settings),
+            vec![duck]
+        );
+    }
+
+    #[test]
+    fn category_filter_requires_every_category() {
+        let mut world = World::new();
+        let mut registry = CategoryRegistry::new();
+        registry.register_component_category::<Bird>("Animals");
+        registry.register_component_category::<Hidden>("Hidden");
+        world.insert_resource(registry);
+
+        let duck = world.spawn(Bird).id();
+        let hidden_duck = world.spawn((Bird, Hidden)).id();
+        let empty = world.spawn_empty().id();
+
+        let settings = MultipleEntityInspectionSettings {
+            category_filter: vec![
+                EntityCategory::from("Animals"),
+                EntityCategory::from("Hidden"),
+            ],
+            ..default()
+        };
+        let mut entities = vec![empty, duck, hidden_duck];
+        filter_entity_list_for_inspection(&world, &mut entities, &settings);
+
+        assert_eq!(entities, vec![hidden_duck]);
+    }
 }
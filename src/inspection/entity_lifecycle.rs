@@ -0,0 +1,460 @@
+//! Recording when entities are spawned and despawned.
+//!
+//! Once an entity is despawned, everything about it is removed from the [`World`],
+//! so inspecting a stale [`Entity`] can only report that it was not found.
+//! The opt-in [`EntityLifecyclePlugin`] keeps a bounded [`EntityLifecycleHistory`] of
+//! [`LifecycleRecord`]s, which remember the name and components of each entity after it is gone.
+//!
+//! When this history is present, inspecting a despawned entity fails with
+//! [`EntityInspectionError::Despawned`](crate::inspection::entity_inspection::EntityInspectionError::Despawned),
+//! which describes when and where it was despawned.
+
+use std::collections::VecDeque;
+
+use bevy::{
+    ecs::{archetype::ArchetypeId, component::ComponentId, world::DeferredWorld},
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use core::fmt::Display;
+
+use crate::{
+    entity_name_resolution::{EntityName, resolve_entity_name},
+    inspector_entities::{InspectorInternal, is_inspector_entity, may_contain_inspector_entities},
+};
+
+/// Whether a [`LifecycleRecord`] describes an entity being spawned or despawned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LifecycleEventKind {
+    /// The entity was spawned.
+    Spawned,
+    /// The entity was despawned.
+    Despawned,
+}
+
+/// A record of an entity being spawned or despawned, kept in the [`EntityLifecycleHistory`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LifecycleRecord {
+    /// The entity that was spawned or despawned.
+    pub entity: Entity,
+    /// Whether the entity was spawned or despawned.
+    pub kind: LifecycleEventKind,
+    /// The change tick when the entity was spawned or despawned.
+    ///
+    /// This is `None` if Bevy no longer records it,
+    /// such as when the entity's index was reused before the despawn was observed.
+    pub tick: Option<u32>,
+    /// The source location that spawned or despawned the entity.
+    ///
+    /// This is only available when Bevy's `track_location` feature is enabled.
+    pub caller: Option<String>,
+    /// The resolved name of the entity.
+    ///
+    /// For despawned entities, this is the name they had when they were despawned.
+    pub name: Option<EntityName>,
+    /// The type names of the entity's components.
+    ///
+    /// For despawned entities, these are the components they had when they were despawned.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::vec_debug_name")
+    )]
+    pub components: Vec<DebugName>,
+}
+
+impl Display for LifecycleRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = self.name.as_ref().map_or("Entity", |name| name.as_str());
+        let action = match self.kind {
+            LifecycleEventKind::Spawned => "spawned",
+            LifecycleEventKind::Despawned => "despawned",
+        };
+        write!(f, "{name} ({}) {action}", self.entity)?;
+        if let Some(tick) = self.tick {
+            write!(f, " at tick {tick}")?;
+        }
+        if let Some(caller) = &self.caller {
+            write!(f, " by {caller}")?;
+        }
+        if !self.components.is_empty() {
+            let components: Vec<String> = self
+                .components
+                .iter()
+                .map(|name| name.shortname().to_string())
+                .collect();
+            write!(f, " with [{}]", components.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The recent spawns and despawns of entities, recorded by the [`EntityLifecyclePlugin`].
+#[derive(Resource, Debug)]
+pub struct EntityLifecycleHistory {
+    /// The maximum number of records kept, after which the oldest records are discarded.
+    ///
+    /// Defaults to 1024.
+    pub capacity: usize,
+    records: VecDeque<LifecycleRecord>,
+    /// The entities that were alive when last observed.
+    tracked: HashMap<Entity, TrackedEntity>,
+    /// The components watched by an observer, so that despawns of entities with them are recorded.
+    observed_components: HashSet<ComponentId>,
+}
+
+impl Default for EntityLifecycleHistory {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            records: VecDeque::new(),
+            tracked: HashMap::default(),
+            observed_components: HashSet::default(),
+        }
+    }
+}
+
+/// What is remembered about a live entity, so that it can be described once it is despawned.
+#[derive(Debug)]
+struct TrackedEntity {
+    archetype_id: ArchetypeId,
+    name: Option<EntityName>,
+}
+
+impl EntityLifecycleHistory {
+    /// Returns every record, oldest first.
+    pub fn records(&self) -> impl DoubleEndedIterator<Item = &LifecycleRecord> + '_ {
+        self.records.iter()
+    }
+
+    /// Returns the records of the `entity`, oldest first.
+    ///
+    /// As entities are identified by both their index and generation,
+    /// this does not include records of other entities that reused the same index.
+    pub fn records_for(
+        &self,
+        entity: Entity,
+    ) -> impl DoubleEndedIterator<Item = &LifecycleRecord> + '_ {
+        self.records
+            .iter()
+            .filter(move |record| record.entity == entity)
+    }
+
+    /// Returns the record of the `entity` being despawned, if it is still in the history.
+    pub fn despawn_record(&self, entity: Entity) -> Option<&LifecycleRecord> {
+        self.records_for(entity)
+            .rev()
+            .find(|record| record.kind == LifecycleEventKind::Despawned)
+    }
+
+    /// Removes every record, without forgetting which entities are alive.
+    pub fn clear(&mut self) {
+        self.records.clear();
+    }
+
+    fn push(&mut self, record: LifecycleRecord) {
+        while self.records.len() >= self.capacity.max(1) {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+}
+
+/// Exclusive system that compares the entities in the world with those alive when it last ran,
+/// recording spawns in the [`EntityLifecycleHistory`].
+///
+/// Despawns are recorded as they happen by the [`record_despawn`] observer,
+/// so this only records the despawns of entities without any components,
+/// which Bevy does not trigger [`Despawn`] for.
+///
+/// This visits every entity in the world, so it gets slower as the world grows.
+/// The inspector's own entities are skipped, as described by [`is_inspector_entity`].
+pub fn track_entity_lifecycles(world: &mut World) {
+    world.resource_scope(|world, mut history: Mut<EntityLifecycleHistory>| {
+        let mut alive = HashSet::with_capacity(history.tracked.len());
+        for archetype in world.archetypes().iter() {
+            let may_contain_inspector = may_contain_inspector_entities(world, archetype);
+            for archetype_entity in archetype.entities() {
+                let entity = archetype_entity.id();
+                if may_contain_inspector && is_inspector_entity(world, &world.entity(entity)) {
+                    continue;
+                }
+                alive.insert(entity);
+                match history.tracked.get_mut(&entity) {
+                    Some(tracked) if tracked.archetype_id == archetype.id() => {}
+                    Some(tracked) => tracked.archetype_id = archetype.id(),
+                    None => {
+                        let tracked = TrackedEntity {
+                            archetype_id: archetype.id(),
+                            name: resolve_entity_name(world, entity, archetype.components()),
+                        };
+                        let record =
+                            new_record(world, entity, LifecycleEventKind::Spawned, &tracked);
+                        history.push(record);
+                        history.tracked.insert(entity, tracked);
+                    }
+                }
+            }
+        }
+
+        // Despawned entities that had components were already removed by `record_despawn`
+        let despawned: Vec<Entity> = history
+            .tracked
+            .keys()
+            .filter(|entity| !alive.contains(*entity))
+            .copied()
+            .collect();
+        for entity in despawned {
+            if let Some(tracked) = history.tracked.remove(&entity) {
+                let record = new_record(world, entity, LifecycleEventKind::Despawned, &tracked);
+                history.push(record);
+            }
+        }
+    });
+}
+
+/// Exclusive system that watches every component registered since it last ran for despawns.
+///
+/// Bevy only triggers [`Despawn`] for entities with at least one component that is watched
+/// by an observer of that event, even when a global observer like [`record_despawn`] exists.
+/// This spawns an observer that does nothing, but makes sure that [`record_despawn`] runs.
+pub fn observe_new_components(world: &mut World) {
+    let observed = &world
+        .resource::<EntityLifecycleHistory>()
+        .observed_components;
+    let new_components: Vec<ComponentId> = world
+        .components()
+        .iter_registered()
+        .map(|info| info.id())
+        .filter(|component_id| !observed.contains(component_id))
+        .collect();
+    if new_components.is_empty() {
+        return;
+    }
+
+    world
+        .resource_mut::<EntityLifecycleHistory>()
+        .observed_components
+        .extend(new_components.iter().copied());
+    world.spawn((
+        Observer::new(|_: On<Despawn>| {}).with_components(new_components),
+        InspectorInternal,
+    ));
+    // Observers are registered by a command, so make sure that they apply to the next despawn
+    world.flush();
+}
+
+/// Observer that records the despawn of an entity in the [`EntityLifecycleHistory`],
+/// before its name and components are removed.
+///
+/// Entities that are spawned and despawned before [`track_entity_lifecycles`] notices them
+/// also get a record of their spawn.
+pub fn record_despawn(despawn: On<Despawn>, mut world: DeferredWorld) {
+    let entity = despawn.entity;
+    let Ok(entity_ref) = world.get_entity(entity) else {
+        return;
+    };
+    if !world.contains_resource::<EntityLifecycleHistory>()
+        || is_inspector_entity(&world, &entity_ref)
+    {
+        return;
+    }
+
+    let archetype = entity_ref.archetype();
+    let tracked = TrackedEntity {
+        archetype_id: archetype.id(),
+        name: resolve_entity_name(&world, entity, archetype.components()),
+    };
+    let spawn_record = (!world
+        .resource::<EntityLifecycleHistory>()
+        .tracked
+        .contains_key(&entity))
+    .then(|| new_record(&world, entity, LifecycleEventKind::Spawned, &tracked));
+    // Bevy has not recorded the despawn yet, so the tick and caller come from the event
+    let despawn_record = LifecycleRecord {
+        tick: Some(world.read_change_tick().get()),
+        caller: despawn
+            .caller()
+            .into_option()
+            .map(|location| location.to_string()),
+        ..new_record(&world, entity, LifecycleEventKind::Despawned, &tracked)
+    };
+
+    let mut history = world.resource_mut::<EntityLifecycleHistory>();
+    history.tracked.remove(&entity);
+    if let Some(spawn_record) = spawn_record {
+        history.push(spawn_record);
+    }
+    history.push(despawn_record);
+}
+
+/// Describes the `entity` being spawned or despawned,
+/// using what Bevy records about its most recent spawn or despawn.
+fn new_record(
+    world: &World,
+    entity: Entity,
+    kind: LifecycleEventKind,
+    tracked: &TrackedEntity,
+) -> LifecycleRecord {
+    let entities = world.entities();
+    LifecycleRecord {
+        entity,
+        kind,
+        tick: entities
+            .entity_get_spawn_or_despawn_tick(entity)
+            .map(|tick| tick.get()),
+        caller: entities
+            .entity_get_spawned_or_despawned_by(entity)
+            .into_option()
+            .flatten()
+            .map(|location| location.to_string()),
+        name: tracked.name.clone(),
+        components: world
+            .archetypes()
+            .get(tracked.archetype_id)
+            .map(|archetype| {
+                archetype
+                    .components()
+                    .iter()
+                    .filter_map(|component_id| world.components().get_name(*component_id))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// A plugin which records when entities are spawned and despawned
+/// in the [`EntityLifecycleHistory`] resource.
+///
+/// Despawns are recorded as they happen by the [`record_despawn`] observer,
+/// while spawns are found by comparing the entities at the end of every frame.
+/// As this visits every entity each frame, it is not added by default.
+///
+/// When upstreamed, this plugin should not be necessary,
+/// as spawns can be recorded as they happen too.
+pub struct EntityLifecyclePlugin;
+
+impl Plugin for EntityLifecyclePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EntityLifecycleHistory>().add_systems(
+            Last,
+            (observe_new_components, track_entity_lifecycles).chain(),
+        );
+        app.world_mut()
+            .spawn((Observer::new(record_despawn), InspectorInternal));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        extension_methods::WorldInspectionExtensionTrait,
+        inspection::entity_inspection::{EntityInspectionError, EntityInspectionSettings},
+    };
+
+    #[derive(Component)]
+    struct Enemy;
+
+    fn lifecycle_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(EntityLifecyclePlugin);
+        app
+    }
+
+    #[test]
+    fn spawns_and_despawns_are_recorded() {
+        let mut app = lifecycle_app();
+
+        let entity = app.world_mut().spawn((Name::new("Goblin"), Enemy)).id();
+        app.update();
+        app.world_mut().despawn(entity);
+
+        let history = app.world().resource::<EntityLifecycleHistory>();
+        let records: Vec<_> = history.records_for(entity).collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].kind, LifecycleEventKind::Spawned);
+
+        let despawn = history.despawn_record(entity).unwrap();
+        assert_eq!(despawn.name.as_ref().unwrap().as_str(), "Goblin");
+        assert!(
+            despawn
+                .components
+                .iter()
+                .any(|name| name.shortname().to_string() == "Enemy")
+        );
+    }
+
+    #[test]
+    fn entities_despawned_in_the_frame_they_were_spawned_are_recorded() {
+        let mut app = lifecycle_app();
+        // Register the components, so that they are observed
+        app.world_mut().spawn((Name::new("Goblin"), Enemy));
+        app.update();
+
+        let entity = app.world_mut().spawn((Name::new("Orc"), Enemy)).id();
+        app.world_mut().despawn(entity);
+        app.update();
+
+        let history = app.world().resource::<EntityLifecycleHistory>();
+        let kinds: Vec<_> = history
+            .records_for(entity)
+            .map(|record| record.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            [LifecycleEventKind::Spawned, LifecycleEventKind::Despawned]
+        );
+
+        let despawn = history.despawn_record(entity).unwrap();
+        assert_eq!(despawn.name.as_ref().unwrap().as_str(), "Orc");
+        assert!(
+            despawn
+                .components
+                .iter()
+                .any(|name| name.shortname().to_string() == "Enemy")
+        );
+    }
+
+    #[test]
+    fn entities_without_components_are_recorded_when_polled() {
+        let mut app = lifecycle_app();
+
+        let entity = app.world_mut().spawn_empty().id();
+        app.update();
+        app.world_mut().despawn(entity);
+        app.update();
+
+        let history = app.world().resource::<EntityLifecycleHistory>();
+        assert!(history.despawn_record(entity).is_some());
+    }
+
+    #[test]
+    fn inspecting_a_despawned_entity_reports_the_despawn() {
+        let mut app = lifecycle_app();
+
+        let entity = app.world_mut().spawn(Enemy).id();
+        app.update();
+        app.world_mut().despawn(entity);
+
+        let error = app
+            .world()
+            .inspect(entity, EntityInspectionSettings::default())
+            .unwrap_err();
+        assert!(matches!(error, EntityInspectionError::Despawned(_)));
+    }
+
+    #[test]
+    fn inspector_entities_are_not_recorded() {
+        let mut app = lifecycle_app();
+
+        let internal = app.world_mut().spawn(InspectorInternal).id();
+        app.update();
+        app.world_mut().despawn(internal);
+        app.update();
+
+        let history = app.world().resource::<EntityLifecycleHistory>();
+        assert_eq!(history.records_for(internal).count(), 0);
+    }
+}
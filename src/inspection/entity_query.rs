@@ -0,0 +1,482 @@
+//! A small textual query language for selecting entities to inspect.
+//!
+//! Building [`MultipleEntityInspectionSettings`] by hand requires [`ComponentId`]s,
+//! which is painful from a log console or a remote client.
+//! Instead, an [`EntityQuery`] can be parsed from a string like:
+//!
+//! ```text
+//! with:Sprite without:Camera name:"duck*" changed:Transform category:UI where:"Transform.translation.y < 0"
+//! ```
+//!
+//! The following filters are supported:
+//!
+//! - `with:A,B`: entities must have all of the listed components.
+//! - `without:A,B`: entities must not have any of the listed components.
+//! - `changed:A,B`: the listed components must be present and have changed since the last frame.
+//! - `name:text`: the entity name must match the [`NameFilter`].
+//!   A `*` matches any sequence of characters; otherwise any name containing the text matches.
+//! - `category:A,B`: entities must belong to all of the listed [`EntityCategory`]s.
+//!   Category names are case-sensitive.
+//! - `where:expression`: a reflected field of a component must satisfy the [`ValuePredicateExpression`],
+//!   like `where:Health.0==0`. This filter can be repeated.
+//!
+//! Words without a filter prefix are treated as part of the name filter,
+//! so a plain search string works as you would expect.
+//! Values containing whitespace can be wrapped in double quotes, escaping inner quotes with `\"`.
+//!
+//! Component names are resolved via [`fuzzy_name_mapping`](crate::entity_name_resolution::fuzzy_name_mapping),
+//! so they are case-insensitive and do not need module paths.
+
+use bevy::{ecs::component::ComponentId, prelude::*};
+use core::fmt::Display;
+use core::str::FromStr;
+use thiserror::Error;
+
+use crate::{
+    entity_categories::EntityCategory,
+    entity_name_resolution::fuzzy_name_mapping::{
+        DEFAULT_FUZZY_MATCH_THRESHOLD, fuzzy_component_name_to_id,
+    },
+    inspection::{
+        entity_inspection::{MultipleEntityInspectionSettings, NameFilter},
+        value_predicate::{ValuePredicateExpression, ValuePredicateParseError},
+    },
+};
+
+/// A parsed, but not yet resolved, entity query.
+///
+/// Create this by parsing a string using [`EntityQuery::parse`] (or [`str::parse`]),
+/// then turn it into [`MultipleEntityInspectionSettings`] using [`EntityQuery::apply`]
+/// or [`MultipleEntityInspectionSettings::from_query`].
+///
+/// See the [module documentation](self) for the syntax.
+/// The [`Display`] implementation produces a query string that parses back into the same query.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityQuery {
+    /// The text that entity names must match, if any.
+    pub name: Option<String>,
+    /// The names of components that must be present.
+    pub with: Vec<String>,
+    /// The names of components that must not be present.
+    pub without: Vec<String>,
+    /// The names of components that must have changed since the last frame.
+    pub changed: Vec<String>,
+    /// The names of categories that entities must belong to.
+    pub categories: Vec<String>,
+    /// Predicates over reflected component fields that must all be satisfied.
+    pub predicates: Vec<ValuePredicateExpression>,
+}
+
+impl EntityQuery {
+    /// Parses a query string.
+    ///
+    /// An empty (or whitespace-only) string produces an empty query, which matches every entity.
+    pub fn parse(query: &str) -> Result<Self, EntityQueryParseError> {
+        let mut parsed = EntityQuery::default();
+        // Bare words are collected separately, so they can be joined into a single name filter
+        let mut bare_words: Vec<String> = Vec::new();
+        let mut bare_words_position = None;
+        let mut name_position = None;
+
+        for token in tokenize(query)? {
+            let Some(key) = token.key else {
+                bare_words_position.get_or_insert(token.position);
+                bare_words.push(token.value);
+                continue;
+            };
+
+            if token.value.trim().is_empty() {
+                return Err(EntityQueryParseError::MissingValue {
+                    filter: key,
+                    position: token.position,
+                });
+            }
+
+            let component_list = match key.to_lowercase().as_str() {
+                "name" => {
+                    if name_position.is_some() {
+                        return Err(EntityQueryParseError::DuplicateNameFilter(token.position));
+                    }
+                    name_position = Some(token.position);
+                    parsed.name = Some(token.value);
+                    continue;
+                }
+                "with" => &mut parsed.with,
+                "without" => &mut parsed.without,
+                "changed" => &mut parsed.changed,
+                "category" => &mut parsed.categories,
+                "where" => {
+                    let predicate =
+                        ValuePredicateExpression::parse(&token.value).map_err(|error| {
+                            EntityQueryParseError::InvalidPredicate {
+                                position: token.position,
+                                error,
+                            }
+                        })?;
+                    parsed.predicates.push(predicate);
+                    continue;
+                }
+                _ => {
+                    return Err(EntityQueryParseError::UnknownFilter {
+                        filter: key,
+                        position: token.position,
+                    });
+                }
+            };
+
+            let names: Vec<String> = token
+                .value
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_string)
+                .collect();
+            if names.is_empty() {
+                return Err(EntityQueryParseError::MissingValue {
+                    filter: key,
+                    position: token.position,
+                });
+            }
+            component_list.extend(names);
+        }
+
+        if let Some(position) = bare_words_position {
+            if let Some(name_position) = name_position {
+                return Err(EntityQueryParseError::DuplicateNameFilter(
+                    name_position.max(position),
+                ));
+            }
+            parsed.name = Some(bare_words.join(" "));
+        }
+
+        Ok(parsed)
+    }
+
+    /// Returns true if this query does not filter anything out.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.with.is_empty()
+            && self.without.is_empty()
+            && self.changed.is_empty()
+            && self.categories.is_empty()
+            && self.predicates.is_empty()
+    }
+
+    /// Resolves the component names in this query, and adds the resulting filters to `settings`.
+    ///
+    /// Existing component filters are kept, while the name filter is replaced if this query has one.
+    /// All other settings (such as [`MultipleEntityInspectionSettings::entity_settings`]) are untouched.
+    pub fn apply(
+        &self,
+        world: &World,
+        settings: &mut MultipleEntityInspectionSettings,
+    ) -> Result<(), EntityQueryError> {
+        let resolve_all = |names: &[String]| -> Result<Vec<ComponentId>, EntityQueryError> {
+            names
+                .iter()
+                .map(|name| resolve_component_name(world, name))
+                .collect()
+        };
+
+        // Resolve everything before mutating, so that `settings` is unchanged on failure
+        let with = resolve_all(&self.with)?;
+        let without = resolve_all(&self.without)?;
+        let changed = resolve_all(&self.changed)?;
+        let predicates = self
+            .predicates
+            .iter()
+            .map(|predicate| {
+                resolve_component_name(world, &predicate.component)
+                    .map(|component_id| predicate.resolve(component_id))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        settings.with_component_filter.extend(with);
+        settings.without_component_filter.extend(without);
+        settings.changed_component_filter.extend(changed);
+        settings.value_predicates.extend(predicates);
+        settings
+            .category_filter
+            .extend(self.categories.iter().cloned().map(EntityCategory::from));
+        if let Some(name) = &self.name {
+            settings.name_filter = Some(NameFilter::from(name));
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for EntityQuery {
+    type Err = EntityQueryParseError;
+
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        EntityQuery::parse(query)
+    }
+}
+
+impl Display for EntityQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut terms = Vec::new();
+
+        for (filter, names) in [
+            ("with", &self.with),
+            ("without", &self.without),
+            ("changed", &self.changed),
+            ("category", &self.categories),
+        ] {
+            if !names.is_empty() {
+                terms.push(format!("{filter}:{}", quote_if_needed(&names.join(","))));
+            }
+        }
+        for predicate in &self.predicates {
+            terms.push(format!("where:{}", quote_if_needed(&predicate.to_string())));
+        }
+        if let Some(name) = &self.name {
+            terms.push(format!("name:{}", quote_if_needed(name)));
+        }
+
+        write!(f, "{}", terms.join(" "))
+    }
+}
+
+impl MultipleEntityInspectionSettings {
+    /// Creates settings from a textual [`EntityQuery`], using the default values for everything else.
+    ///
+    /// See the [`entity_query`](crate::inspection::entity_query) module for the syntax.
+    pub fn from_query(world: &World, query: &str) -> Result<Self, EntityQueryError> {
+        let mut settings = Self::default();
+        EntityQuery::parse(query)?.apply(world, &mut settings)?;
+        Ok(settings)
+    }
+}
+
+/// Finds the [`ComponentId`] that best matches the provided component name.
+fn resolve_component_name(world: &World, name: &str) -> Result<ComponentId, EntityQueryError> {
+    fuzzy_component_name_to_id(world, name, DEFAULT_FUZZY_MATCH_THRESHOLD)
+        .first()
+        .map(|(_score, id)| *id)
+        .ok_or_else(|| EntityQueryError::UnknownComponent(name.to_string()))
+}
+
+/// Wraps `value` in quotes if it would otherwise not be parsed back as a single value.
+fn quote_if_needed(value: &str) -> String {
+    if value.is_empty() || value.contains(|c: char| c.is_whitespace() || c == '"' || c == ':') {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// A single whitespace-separated term of a query, like `with:Sprite`.
+struct Token {
+    /// The byte offset of the start of the token in the query string.
+    position: usize,
+    /// The filter name before the first unquoted `:`, if any.
+    key: Option<String>,
+    /// The (unquoted) value of the token.
+    value: String,
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, EntityQueryParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = query.char_indices().peekable();
+
+    while let Some(&(position, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut key = None;
+        let mut value = String::new();
+        let mut has_quotes = false;
+
+        while let Some(&(index, c)) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            chars.next();
+
+            match c {
+                '"' => {
+                    has_quotes = true;
+                    let mut terminated = false;
+                    while let Some((_, c)) = chars.next() {
+                        match c {
+                            '"' => {
+                                terminated = true;
+                                break;
+                            }
+                            '\\' => match chars.next() {
+                                Some((_, escaped)) => value.push(escaped),
+                                None => break,
+                            },
+                            _ => value.push(c),
+                        }
+                    }
+                    if !terminated {
+                        return Err(EntityQueryParseError::UnterminatedQuote(index));
+                    }
+                }
+                // Only the first unquoted colon separates the filter from its value
+                ':' if key.is_none() && !has_quotes => key = Some(core::mem::take(&mut value)),
+                _ => value.push(c),
+            }
+        }
+
+        tokens.push(Token {
+            position,
+            key,
+            value,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// An error encountered while parsing an [`EntityQuery`].
+///
+/// Positions are byte offsets into the query string.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntityQueryParseError {
+    /// A filter other than `with`, `without`, `name`, `changed`, `category` or `where` was used.
+    #[error(
+        "Unknown filter `{filter}` at position {position}: expected `with`, `without`, `name`, `changed`, `category` or `where`"
+    )]
+    UnknownFilter {
+        /// The name of the unknown filter.
+        filter: String,
+        /// Where the filter starts.
+        position: usize,
+    },
+    /// A filter was not followed by a value, like `with:`.
+    #[error("Filter `{filter}` at position {position} is missing a value")]
+    MissingValue {
+        /// The name of the filter.
+        filter: String,
+        /// Where the filter starts.
+        position: usize,
+    },
+    /// A quoted value was not closed.
+    #[error("Unterminated quote at position {0}")]
+    UnterminatedQuote(usize),
+    /// The entity name was filtered more than once.
+    ///
+    /// Note that bare words (without a filter prefix) are also used as a name filter.
+    #[error("Name filter at position {0} conflicts with an earlier name filter")]
+    DuplicateNameFilter(usize),
+    /// The expression of a `where` filter could not be parsed.
+    #[error("Invalid `where` filter at position {position}: {error}")]
+    InvalidPredicate {
+        /// Where the filter starts.
+        position: usize,
+        /// Why the expression is invalid.
+        error: ValuePredicateParseError,
+    },
+}
+
+/// An error encountered while turning a textual query into [`MultipleEntityInspectionSettings`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntityQueryError {
+    /// The query could not be parsed.
+    #[error(transparent)]
+    Parse(#[from] EntityQueryParseError),
+    /// No registered component matched the provided name.
+    #[error("No component matching `{0}` was found")]
+    UnknownComponent(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_all_filters() {
+        let query = EntityQuery::parse(
+            r#"with:Sprite,Transform without:Camera name:"duck*" changed:Transform category:UI,Audio"#,
+        )
+        .unwrap();
+
+        assert_eq!(query.with, vec!["Sprite", "Transform"]);
+        assert_eq!(query.without, vec!["Camera"]);
+        assert_eq!(query.changed, vec!["Transform"]);
+        assert_eq!(query.categories, vec!["UI", "Audio"]);
+        assert_eq!(query.name.as_deref(), Some("duck*"));
+    }
+
+    #[test]
+    fn bare_words_form_a_name_filter() {
+        let query = EntityQuery::parse("  big   duck with:Sprite").unwrap();
+
+        assert_eq!(query.name.as_deref(), Some("big duck"));
+        assert_eq!(query.with, vec!["Sprite"]);
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert!(EntityQuery::parse("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn quoted_values_may_contain_whitespace_and_escapes() {
+        let query = EntityQuery::parse(r#"name:"the \"big\" duck""#).unwrap();
+
+        assert_eq!(query.name.as_deref(), Some(r#"the "big" duck"#));
+    }
+
+    #[test]
+    fn where_filters_are_parsed() {
+        let query =
+            EntityQuery::parse(r#"where:"Transform.translation.y < 0" where:Health.0==0"#).unwrap();
+
+        assert_eq!(query.predicates.len(), 2);
+        assert_eq!(query.predicates[0].component, "Transform");
+        assert_eq!(query.predicates[1].path, ".0");
+        assert_eq!(
+            EntityQuery::parse("where:Health.0"),
+            Err(EntityQueryParseError::InvalidPredicate {
+                position: 0,
+                error: ValuePredicateParseError::MissingOperator,
+            })
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let query = EntityQuery::parse(
+            r#"with:Sprite changed:Transform name:"big duck" where:"Name == \"Big duck\"""#,
+        )
+        .unwrap();
+
+        assert_eq!(EntityQuery::parse(&query.to_string()).unwrap(), query);
+    }
+
+    #[test]
+    fn parse_errors_report_positions() {
+        assert_eq!(
+            EntityQuery::parse("with:Sprite whith:Camera"),
+            Err(EntityQueryParseError::UnknownFilter {
+                filter: "whith".to_string(),
+                position: 12,
+            })
+        );
+        assert_eq!(
+            EntityQuery::parse("without:"),
+            Err(EntityQueryParseError::MissingValue {
+                filter: "without".to_string(),
+                position: 0,
+            })
+        );
+        assert_eq!(
+            EntityQuery::parse(r#"name:"duck"#),
+            Err(EntityQueryParseError::UnterminatedQuote(5))
+        );
+        assert_eq!(
+            EntityQuery::parse("duck name:goose"),
+            Err(EntityQueryParseError::DuplicateNameFilter(5))
+        );
+    }
+}
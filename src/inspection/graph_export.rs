@@ -0,0 +1,395 @@
+//! Exporting the graphs formed by ECS data as Graphviz DOT or Mermaid text.
+//!
+//! Two kinds of graphs can be exported as an [`ExportedGraph`]:
+//!
+//! - [`ExportedGraph::required_components`]: which component types require which others,
+//!   based on [`ComponentTypeMetadata::required_components`].
+//! - [`ExportedGraph::relationships`]: the entities reachable from a root entity through a relationship,
+//!   such as the [`Children`] of the entity hierarchy.
+//!
+//! Component types are labelled by their short type names,
+//! and entities by their resolved [`EntityName`](crate::entity_name_resolution::EntityName).
+//! The resulting text can be rendered with `dot -Tsvg`, or pasted into any Markdown viewer that supports Mermaid.
+
+use bevy::{
+    ecs::component::ComponentId,
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use core::fmt::Write;
+use std::collections::VecDeque;
+use thiserror::Error;
+
+use crate::{
+    entity_name_resolution::resolve_entity_name,
+    inspection::{
+        component_inspection::ComponentTypeMetadata,
+        relationship_inspection::{RelationshipKind, related_entities},
+    },
+};
+
+/// The text format to export an [`ExportedGraph`] as.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GraphFormat {
+    /// The Graphviz DOT language.
+    #[default]
+    Dot,
+    /// A Mermaid flowchart.
+    Mermaid,
+}
+
+/// A node of an [`ExportedGraph`], representing a component type or an entity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphNode {
+    /// The label shown on the node.
+    pub label: String,
+}
+
+/// A directed edge between two nodes of an [`ExportedGraph`],
+/// identified by their index in [`ExportedGraph::nodes`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GraphEdge {
+    /// The index of the node the edge starts from.
+    pub from: usize,
+    /// The index of the node the edge points to.
+    pub to: usize,
+}
+
+/// A directed graph built from ECS data, which can be exported as text using [`ExportedGraph::export`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExportedGraph {
+    /// The nodes of the graph, in the order they were visited.
+    pub nodes: Vec<GraphNode>,
+    /// The edges of the graph.
+    pub edges: Vec<GraphEdge>,
+    /// Whether nodes were left out because [`RelationshipGraphSettings::max_nodes`] was reached.
+    pub truncated: bool,
+}
+
+/// Settings for [`ExportedGraph::relationships`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationshipGraphSettings {
+    /// How many levels below the root entity to include.
+    ///
+    /// If `None`, the whole subtree is included.
+    ///
+    /// Defaults to `None`.
+    pub max_depth: Option<usize>,
+    /// The maximum number of entities to include,
+    /// as large graphs are slow to render and hard to read.
+    ///
+    /// Defaults to 500.
+    pub max_nodes: usize,
+}
+
+impl Default for RelationshipGraphSettings {
+    fn default() -> Self {
+        Self {
+            max_depth: None,
+            max_nodes: 500,
+        }
+    }
+}
+
+/// An error that can occur when attempting to build an [`ExportedGraph`].
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GraphExportError {
+    /// The root entity does not exist in the world.
+    #[error("Entity not found: {0}")]
+    EntityNotFound(Entity),
+    /// The component type is not registered in the world.
+    #[error("ComponentId {0:?} not registered in world")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    ComponentNotFound(ComponentId),
+    /// The component type is not the [`RelationshipKind::RelationshipTarget`] side of a relationship,
+    /// such as [`Children`], so it cannot be followed from the root down.
+    #[error("Component {0} is not a relationship target")]
+    NotARelationshipTarget(String),
+}
+
+impl ExportedGraph {
+    /// Builds the graph of which component types require which others,
+    /// starting from the provided `component_ids` and following their required components.
+    ///
+    /// An edge from `A` to `B` means that `A` requires `B`.
+    /// Only direct requirements are drawn:
+    /// if `A` requires `B` which requires `C`, there is no edge from `A` to `C`.
+    pub fn required_components(world: &World, component_ids: &[ComponentId]) -> Self {
+        let mut requirements: HashMap<ComponentId, (DebugName, Vec<ComponentId>)> =
+            HashMap::default();
+        let mut order = Vec::new();
+        let mut queue: VecDeque<ComponentId> = component_ids.iter().copied().collect();
+        while let Some(component_id) = queue.pop_front() {
+            if requirements.contains_key(&component_id) {
+                continue;
+            }
+            let Ok(metadata) = ComponentTypeMetadata::new(world, component_id) else {
+                continue;
+            };
+            queue.extend(metadata.required_components.iter().copied());
+            order.push(component_id);
+            requirements.insert(component_id, (metadata.name, metadata.required_components));
+        }
+
+        let mut graph = Self::default();
+        let mut indices: HashMap<ComponentId, usize> = HashMap::default();
+        for component_id in &order {
+            let (name, _) = &requirements[component_id];
+            indices.insert(*component_id, graph.add_node(name.shortname().to_string()));
+        }
+        for component_id in &order {
+            // Bevy stores every inherited requirement as well,
+            // so skip those which are already required through another requirement
+            let required = &requirements[component_id].1;
+            for target in required {
+                let inherited = required.iter().any(|other| {
+                    requirements
+                        .get(other)
+                        .is_some_and(|(_, transitive)| transitive.contains(target))
+                });
+                if !inherited && let Some(&to) = indices.get(target) {
+                    graph.edges.push(GraphEdge {
+                        from: indices[component_id],
+                        to,
+                    });
+                }
+            }
+        }
+        graph
+    }
+
+    /// Builds the graph of the entities reachable from the `root` entity
+    /// through the relationship target component identified by `relationship_target`, like [`Children`].
+    ///
+    /// An edge from `A` to `B` means that `B` is in `A`'s relationship target,
+    /// such as `B` being a child of `A`.
+    pub fn relationships(
+        world: &World,
+        root: Entity,
+        relationship_target: ComponentId,
+        settings: RelationshipGraphSettings,
+    ) -> Result<Self, GraphExportError> {
+        if world.get_entity(root).is_err() {
+            return Err(GraphExportError::EntityNotFound(root));
+        }
+        let component_info = world
+            .components()
+            .get_info(relationship_target)
+            .ok_or(GraphExportError::ComponentNotFound(relationship_target))?;
+        if RelationshipKind::of(component_info) != Some(RelationshipKind::RelationshipTarget) {
+            return Err(GraphExportError::NotARelationshipTarget(
+                component_info.name().to_string(),
+            ));
+        }
+
+        let mut graph = Self::default();
+        let mut indices: HashMap<Entity, usize> = HashMap::default();
+        indices.insert(root, graph.add_node(entity_label(world, root)));
+        let mut queue = VecDeque::from([(root, 0)]);
+        let mut visited = HashSet::from([root]);
+        while let Some((entity, depth)) = queue.pop_front() {
+            if settings
+                .max_depth
+                .is_some_and(|max_depth| depth >= max_depth)
+            {
+                continue;
+            }
+            let Some(related_entities) = world
+                .get_entity(entity)
+                .ok()
+                .and_then(|entity_ref| related_entities(entity_ref, component_info))
+            else {
+                continue;
+            };
+            for related in related_entities {
+                let to = match indices.get(&related) {
+                    Some(&index) => index,
+                    None if graph.nodes.len() >= settings.max_nodes => {
+                        graph.truncated = true;
+                        continue;
+                    }
+                    None => {
+                        let index = graph.add_node(entity_label(world, related));
+                        indices.insert(related, index);
+                        index
+                    }
+                };
+                graph.edges.push(GraphEdge {
+                    from: indices[&entity],
+                    to,
+                });
+                // Guard against cycles in malformed relationships
+                if visited.insert(related) {
+                    queue.push_back((related, depth + 1));
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Builds the graph of the entity hierarchy below the `root` entity,
+    /// following its [`Children`].
+    ///
+    /// See [`Self::relationships`] for more details.
+    pub fn hierarchy(
+        world: &World,
+        root: Entity,
+        settings: RelationshipGraphSettings,
+    ) -> Result<Self, GraphExportError> {
+        match world.components().component_id::<Children>() {
+            Some(children) => Self::relationships(world, root, children, settings),
+            // No entity has ever had children, so the root is on its own
+            None => {
+                if world.get_entity(root).is_err() {
+                    return Err(GraphExportError::EntityNotFound(root));
+                }
+                let mut graph = Self::default();
+                graph.add_node(entity_label(world, root));
+                Ok(graph)
+            }
+        }
+    }
+
+    /// Writes the graph as text in the provided `format`.
+    pub fn export(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Mermaid => self.to_mermaid(),
+        }
+    }
+
+    /// Writes the graph in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let label = node.label.replace('\\', "\\\\").replace('"', "\\\"");
+            let _ = writeln!(dot, "    n{index} [label=\"{label}\"];");
+        }
+        for edge in &self.edges {
+            let _ = writeln!(dot, "    n{} -> n{};", edge.from, edge.to);
+        }
+        dot.push('}');
+        dot
+    }
+
+    /// Writes the graph as a top-down Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart TD");
+        for (index, node) in self.nodes.iter().enumerate() {
+            // Mermaid uses HTML entity codes to escape characters within labels
+            let label = node
+                .label
+                .replace('"', "#quot;")
+                .replace('<', "#lt;")
+                .replace('>', "#gt;");
+            let _ = write!(mermaid, "\n    n{index}[\"{label}\"]");
+        }
+        for edge in &self.edges {
+            let _ = write!(mermaid, "\n    n{} --> n{}", edge.from, edge.to);
+        }
+        mermaid
+    }
+
+    fn add_node(&mut self, label: String) -> usize {
+        self.nodes.push(GraphNode { label });
+        self.nodes.len() - 1
+    }
+}
+
+/// Labels an entity node with its resolved name, if any, and its id.
+fn entity_label(world: &World, entity: Entity) -> String {
+    let name = world.get_entity(entity).ok().and_then(|entity_ref| {
+        resolve_entity_name(world, entity, entity_ref.archetype().components())
+    });
+    match name {
+        Some(name) => format!("{} ({entity})", name.as_str()),
+        None => entity.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_name_resolution::NameResolutionRegistry;
+
+    #[derive(Component, Default)]
+    struct Health;
+
+    #[derive(Component, Default)]
+    #[require(Health)]
+    struct Creature;
+
+    #[derive(Component, Default)]
+    #[require(Creature, Health)]
+    struct Goblin;
+
+    #[test]
+    fn only_direct_requirements_are_drawn() {
+        let mut world = World::new();
+        // Required to generate component metadata
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<NameResolutionRegistry>();
+        let goblin = world.register_component::<Goblin>();
+
+        let graph = ExportedGraph::required_components(&world, &[goblin]);
+        let index = |label: &str| {
+            graph
+                .nodes
+                .iter()
+                .position(|node| node.label == label)
+                .unwrap()
+        };
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(index("Goblin"), 0);
+        // `Goblin -> Health` is inherited through `Creature`
+        assert_eq!(graph.edges.len(), 2);
+        assert!(graph.edges.contains(&GraphEdge {
+            from: index("Goblin"),
+            to: index("Creature"),
+        }));
+        assert!(graph.edges.contains(&GraphEdge {
+            from: index("Creature"),
+            to: index("Health"),
+        }));
+
+        let dot = graph.export(GraphFormat::Dot);
+        assert!(dot.contains("n0 [label=\"Goblin\"];"));
+        assert!(dot.contains(&format!("n0 -> n{};", index("Creature"))));
+        let mermaid = graph.export(GraphFormat::Mermaid);
+        assert!(mermaid.starts_with("flowchart TD"));
+        assert!(mermaid.contains(&format!("n{} --> n{}", index("Creature"), index("Health"))));
+    }
+
+    #[test]
+    fn hierarchy_uses_entity_names_and_respects_depth() {
+        let mut app = App::new();
+        app.register_type::<Children>();
+        let world = app.world_mut();
+        let root = world.spawn(Name::new("Root")).id();
+        let child = world.spawn((Name::new("Child"), ChildOf(root))).id();
+        world.spawn((Name::new("Grandchild"), ChildOf(child)));
+
+        let graph = ExportedGraph::hierarchy(
+            app.world(),
+            root,
+            RelationshipGraphSettings {
+                max_depth: Some(1),
+                ..default()
+            },
+        )
+        .unwrap();
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.nodes[0].label, format!("Root ({root})"));
+        assert_eq!(graph.nodes[1].label, format!("Child ({child})"));
+        assert_eq!(graph.edges, [GraphEdge { from: 0, to: 1 }]);
+    }
+}
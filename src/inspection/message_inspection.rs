@@ -0,0 +1,355 @@
+//! Types for inspecting the [`Messages`] buffered in the world.
+//!
+//! Each message type is stored in its own [`Messages<M>`] resource,
+//! which double-buffers the messages written during the current and previous update.
+//! Inspecting these buffers shows how messages flow between systems,
+//! without needing to add logging systems that read them.
+//!
+//! The buffers are generic over the message type, so they can only be read
+//! for message types registered in the [`MessageInspectionRegistry`].
+//! The [`MessageInspectionPlugin`] registers Bevy's common input and window messages,
+//! while your own messages can be added and registered at once using
+//! [`AppInspectionExtensionTrait::add_inspected_message`](crate::extension_methods::AppInspectionExtensionTrait::add_inspected_message).
+
+use bevy::{
+    app::AppExit,
+    ecs::{component::ComponentId, message::Message},
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    },
+    platform::collections::HashMap,
+    prelude::*,
+    window::{CursorMoved, WindowResized},
+};
+use core::any::{TypeId, type_name};
+use core::fmt::Display;
+
+use crate::reflection_tools::reflected_value_to_string;
+
+/// The result of inspecting the [`Messages`] resource of a single message type.
+///
+/// Log this using the [`Display`] trait to see the buffered messages.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageInspection {
+    /// The type name of the message.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// The [`ComponentId`] of the [`Messages`] resource that stores the messages.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The contents of the message buffers.
+    ///
+    /// This is `None` if the message type is not registered in the [`MessageInspectionRegistry`].
+    pub buffers: Option<MessageBuffers>,
+}
+
+impl Display for MessageInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name.shortname())?;
+        match &self.buffers {
+            Some(buffers) => write!(f, ": {buffers}"),
+            None => write!(
+                f,
+                ": unknown buffer contents (not registered for inspection)"
+            ),
+        }
+    }
+}
+
+/// The messages stored in a [`Messages`] resource.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageBuffers {
+    /// The number of messages written during the current update.
+    pub current_len: usize,
+    /// The number of messages written during the previous update,
+    /// which will be dropped at the start of the next update.
+    pub previous_len: usize,
+    /// The number of messages of this type written since the app started.
+    pub total_written: usize,
+    /// The reflected values of the buffered messages, oldest first.
+    ///
+    /// This is `None` if the message type was registered without reflection,
+    /// using [`MessageInspectionRegistry::register`].
+    /// The number of messages is limited by [`MessageInspectionSettings::pending_message_limit`].
+    pub pending: Option<Vec<String>>,
+}
+
+impl MessageBuffers {
+    /// Reads the buffers of the `messages`, without their values.
+    pub fn new<M: Message>(messages: &Messages<M>) -> Self {
+        let current_len = messages.iter_current_update_messages().len();
+        Self {
+            current_len,
+            previous_len: messages.len() - current_len,
+            total_written: messages.oldest_message_count() + messages.len(),
+            pending: None,
+        }
+    }
+
+    /// Reads the buffers of the `messages`, including their reflected values.
+    pub fn new_reflected<M: Message + Reflect>(
+        messages: &Messages<M>,
+        settings: &MessageInspectionSettings,
+    ) -> Self {
+        let pending = messages
+            .get_cursor()
+            .read(messages)
+            .take(settings.pending_message_limit.unwrap_or(usize::MAX))
+            .map(|message| {
+                reflected_value_to_string(message.as_partial_reflect(), settings.full_type_names)
+            })
+            .collect();
+
+        Self {
+            pending: Some(pending),
+            ..Self::new(messages)
+        }
+    }
+}
+
+impl Display for MessageBuffers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} current, {} previous, {} written in total",
+            self.current_len, self.previous_len, self.total_written
+        )?;
+        if let Some(pending) = &self.pending {
+            for message in pending {
+                write!(f, "\n  {message}")?;
+            }
+            let omitted = self.current_len + self.previous_len - pending.len();
+            if omitted > 0 {
+                write!(f, "\n  ... and {omitted} more")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Settings that can be used to customize message inspection,
+/// changing how [`MessageInspection`] is generated and displayed.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MessageInspectionSettings {
+    /// Whether or not full type names should be displayed.
+    ///
+    /// Defaults to `false`.
+    pub full_type_names: bool,
+    /// The maximum number of buffered messages to reflect for each message type.
+    ///
+    /// Defaults to `None`, which reflects every buffered message.
+    pub pending_message_limit: Option<usize>,
+}
+
+/// Reads the buffers of a type-erased [`Messages`] resource.
+type BufferInspector = fn(&World, &MessageInspectionSettings) -> Option<MessageBuffers>;
+
+/// Stores how to read the [`Messages`] resource of each message type,
+/// as the resource can't be read without knowing its type.
+///
+/// # Usage
+///
+/// Message types are most easily registered by adding them using
+/// [`AppInspectionExtensionTrait::add_inspected_reflected_message`] if they implement [`Reflect`],
+/// or [`AppInspectionExtensionTrait::add_inspected_message`] otherwise, instead of [`App::add_message`].
+///
+/// Messages added by other plugins can be registered using [`MessageInspectionRegistry::register_reflected`]
+/// or [`MessageInspectionRegistry::register`].
+///
+/// [`AppInspectionExtensionTrait::add_inspected_message`]: crate::extension_methods::AppInspectionExtensionTrait::add_inspected_message
+/// [`AppInspectionExtensionTrait::add_inspected_reflected_message`]: crate::extension_methods::AppInspectionExtensionTrait::add_inspected_reflected_message
+// When upstreamed, `App::add_message` should register this information automatically.
+#[derive(Debug, Resource, Default)]
+pub struct MessageInspectionRegistry {
+    /// Keyed by the [`TypeId`] of the [`Messages`] resource.
+    inspectors: HashMap<TypeId, BufferInspector>,
+}
+
+impl MessageInspectionRegistry {
+    /// Allows the number of buffered messages of type `M` to be inspected.
+    pub fn register<M: Message>(&mut self) {
+        self.inspectors
+            .insert(TypeId::of::<Messages<M>>(), |world, _settings| {
+                world.get_resource::<Messages<M>>().map(MessageBuffers::new)
+            });
+    }
+
+    /// Allows the number and values of buffered messages of type `M` to be inspected.
+    pub fn register_reflected<M: Message + Reflect>(&mut self) {
+        self.inspectors
+            .insert(TypeId::of::<Messages<M>>(), |world, settings| {
+                world
+                    .get_resource::<Messages<M>>()
+                    .map(|messages| MessageBuffers::new_reflected(messages, settings))
+            });
+    }
+}
+
+impl MessageInspection {
+    /// Inspects the [`Messages`] resource identified by `component_id`.
+    ///
+    /// Returns `None` if the resource is not a [`Messages`] resource.
+    pub fn new(
+        world: &World,
+        component_id: ComponentId,
+        settings: &MessageInspectionSettings,
+    ) -> Option<Self> {
+        let info = world.components().get_info(component_id)?;
+        let resource_name = info.name();
+        let name = message_name(&resource_name)?;
+
+        let buffers = info.type_id().and_then(|type_id| {
+            let inspector = world
+                .get_resource::<MessageInspectionRegistry>()?
+                .inspectors
+                .get(&type_id)?;
+            inspector(world, settings)
+        });
+
+        Some(Self {
+            name: DebugName::owned(name.to_string()),
+            component_id,
+            buffers,
+        })
+    }
+}
+
+/// Extracts the name of the message type from the name of a [`Messages`] resource.
+///
+/// Returns `None` if the `resource_name` is not the name of a [`Messages`] resource.
+// Bevy does not expose the list of registered message types,
+// so `Messages` resources are found by their type name instead.
+fn message_name(resource_name: &str) -> Option<&str> {
+    let messages_name = type_name::<Messages<AppExit>>();
+    let (prefix, _) = messages_name.split_once('<')?;
+    resource_name
+        .strip_prefix(prefix)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
+
+/// A plugin which registers Bevy's common input and window messages
+/// in the [`MessageInspectionRegistry`] resource.
+///
+/// When upstreamed, this plugin should not be necessary,
+/// as each plugin can register its own messages when adding them.
+pub struct MessageInspectionPlugin;
+
+impl Plugin for MessageInspectionPlugin {
+    fn build(&self, app: &mut App) {
+        let mut registry = app
+            .world_mut()
+            .get_resource_or_init::<MessageInspectionRegistry>();
+
+        // `AppExit` does not implement `Reflect`
+        registry.register::<AppExit>();
+
+        registry.register_reflected::<KeyboardInput>();
+        registry.register_reflected::<MouseButtonInput>();
+        registry.register_reflected::<MouseMotion>();
+        registry.register_reflected::<MouseWheel>();
+
+        registry.register_reflected::<CursorMoved>();
+        registry.register_reflected::<WindowResized>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension_methods::{AppInspectionExtensionTrait, WorldInspectionExtensionTrait};
+
+    #[derive(Message, Reflect)]
+    struct Damage {
+        amount: u32,
+    }
+
+    #[derive(Message)]
+    struct Unreflected;
+
+    #[test]
+    fn message_buffers_are_split_by_update() {
+        let mut world = World::new();
+        world.init_resource::<MessageInspectionRegistry>();
+        world.init_resource::<Messages<Damage>>();
+        world
+            .resource_mut::<MessageInspectionRegistry>()
+            .register_reflected::<Damage>();
+
+        world.write_message(Damage { amount: 1 });
+        world.resource_mut::<Messages<Damage>>().update();
+        world.write_message(Damage { amount: 2 });
+        world.write_message(Damage { amount: 3 });
+
+        let inspection = world
+            .inspect_messages(MessageInspectionSettings::default())
+            .into_iter()
+            .find(|inspection| inspection.name.shortname().to_string() == "Damage")
+            .unwrap();
+        let buffers = inspection.buffers.unwrap();
+        assert_eq!(buffers.current_len, 2);
+        assert_eq!(buffers.previous_len, 1);
+        assert_eq!(buffers.total_written, 3);
+
+        let pending = buffers.pending.unwrap();
+        assert_eq!(pending.len(), 3);
+        assert!(pending[0].contains("amount: 1"));
+        assert!(pending[2].contains("amount: 3"));
+    }
+
+    #[test]
+    fn unregistered_messages_are_listed_without_buffers() {
+        let mut world = World::new();
+        world.init_resource::<MessageInspectionRegistry>();
+        world.init_resource::<Messages<Unreflected>>();
+        world.init_resource::<Messages<Damage>>();
+        world
+            .resource_mut::<MessageInspectionRegistry>()
+            .register::<Damage>();
+        world.write_message(Damage { amount: 1 });
+
+        let inspections = world.inspect_messages(MessageInspectionSettings::default());
+        assert_eq!(inspections.len(), 2);
+
+        let unreflected = inspections
+            .iter()
+            .find(|inspection| inspection.name.shortname().to_string() == "Unreflected")
+            .unwrap();
+        assert!(unreflected.buffers.is_none());
+
+        let damage = inspections
+            .iter()
+            .find(|inspection| inspection.name.shortname().to_string() == "Damage")
+            .unwrap();
+        let buffers = damage.buffers.as_ref().unwrap();
+        assert_eq!(buffers.current_len, 1);
+        assert!(buffers.pending.is_none());
+    }
+
+    #[test]
+    fn inspected_messages_are_added_and_registered() {
+        let mut app = App::new();
+        app.add_inspected_message::<Unreflected>();
+        app.world_mut().write_message(Unreflected);
+
+        let inspection = app
+            .world()
+            .inspect_messages(MessageInspectionSettings::default())
+            .into_iter()
+            .find(|inspection| inspection.name.shortname().to_string() == "Unreflected")
+            .unwrap();
+        let buffers = inspection.buffers.unwrap();
+        assert_eq!(buffers.current_len, 1);
+        assert!(buffers.pending.is_none());
+    }
+}
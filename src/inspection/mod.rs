@@ -1,6 +1,22 @@
 //! Tools to inspect ECS data at runtime.
 
+pub mod archetype_inspection;
+pub mod asset_inspection;
+pub mod change_profiler;
 pub mod component_inspection;
+pub mod component_trace;
 pub mod entity_inspection;
+pub mod entity_lifecycle;
+pub mod entity_query;
+pub mod graph_export;
+pub mod message_inspection;
+pub mod observer_inspection;
+pub mod relationship_inspection;
 pub mod resource_inspection;
+pub mod schedule_inspection;
+pub mod state_inspection;
+pub mod summary_history;
+pub mod system_inspection;
+pub mod type_registry_inspection;
+pub mod value_predicate;
 pub mod world_summary;
@@ -0,0 +1,241 @@
+//! Types for inspecting [`Observer`]s.
+//!
+//! Observers are stored as entities with an [`Observer`] component,
+//! which describes the events, components and entities that the observer watches.
+//! An observer only runs when all of these match the triggered event,
+//! so inspecting them is a good first step when an observer does not fire as expected.
+
+use bevy::{
+    ecs::{component::ComponentId, entity::EntityNotSpawnedError, event::EventKey},
+    prelude::*,
+};
+use core::fmt::Display;
+use thiserror::Error;
+
+/// The result of inspecting an [`Observer`].
+///
+/// Log this using the [`Display`] trait to see what the observer watches.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObserverInspection {
+    /// The entity that holds the [`Observer`] component.
+    pub entity: Entity,
+    /// The name of the system that runs when the observer is triggered.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub system_name: DebugName,
+    /// The type names of the events that trigger the observer.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::vec_debug_name")
+    )]
+    pub events: Vec<DebugName>,
+    /// The components that the observer is limited to.
+    ///
+    /// If empty, the observer is not limited to any components.
+    pub components: Vec<ObservedComponent>,
+    /// The entities that the observer is limited to.
+    ///
+    /// If empty, the observer watches all entities.
+    pub watched_entities: Vec<Entity>,
+    /// The source location that spawned the observer.
+    ///
+    /// This is only available when Bevy's `track_location` feature is enabled.
+    pub spawned_by: Option<String>,
+    /// The tick on which the observer was spawned.
+    pub spawn_tick: u32,
+}
+
+impl Display for ObserverInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Observer {} ({})",
+            self.system_name.shortname(),
+            self.entity
+        )?;
+
+        let events: Vec<String> = self
+            .events
+            .iter()
+            .map(|event| event.shortname().to_string())
+            .collect();
+        write!(f, "\nEvents: [{}]", events.join(", "))?;
+
+        if !self.components.is_empty() {
+            let components: Vec<String> = self
+                .components
+                .iter()
+                .map(|component| component.name.shortname().to_string())
+                .collect();
+            write!(f, "\nComponents: [{}]", components.join(", "))?;
+        }
+
+        if self.watched_entities.is_empty() {
+            write!(f, "\nWatched entities: all")?;
+        } else {
+            let entities: Vec<String> = self
+                .watched_entities
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            write!(f, "\nWatched entities: [{}]", entities.join(", "))?;
+        }
+
+        match &self.spawned_by {
+            Some(location) => write!(
+                f,
+                "\nSpawned by: {location} on system tick {}",
+                self.spawn_tick
+            ),
+            None => write!(f, "\nSpawned on system tick {}", self.spawn_tick),
+        }
+    }
+}
+
+/// A component that an [`Observer`] is limited to.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObservedComponent {
+    /// The [`ComponentId`] of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+}
+
+/// An error that can occur when attempting to inspect an observer.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObserverInspectionError {
+    /// The entity does not exist in the world.
+    #[error("Entity not found: {0}")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    EntityNotFound(EntityNotSpawnedError),
+    /// The entity exists, but does not have an [`Observer`] component.
+    #[error("Entity {0} is not an observer")]
+    NotAnObserver(Entity),
+}
+
+impl ObserverInspection {
+    /// Inspects the [`Observer`] component on the provided `entity`.
+    pub fn new(world: &World, entity: Entity) -> Result<Self, ObserverInspectionError> {
+        let entity_ref = world
+            .get_entity(entity)
+            .map_err(ObserverInspectionError::EntityNotFound)?;
+        let observer = entity_ref
+            .get::<Observer>()
+            .ok_or(ObserverInspectionError::NotAnObserver(entity))?;
+        let descriptor = observer.descriptor();
+
+        let events = descriptor
+            .event_keys()
+            .iter()
+            .map(|event_key| event_name(world, *event_key))
+            .collect();
+        let components = descriptor
+            .components()
+            .iter()
+            .map(|component_id| ObservedComponent {
+                component_id: *component_id,
+                name: world
+                    .components()
+                    .get_name(*component_id)
+                    .unwrap_or_else(|| DebugName::owned(format!("{component_id:?}"))),
+            })
+            .collect();
+
+        Ok(Self {
+            entity,
+            system_name: observer.system_name(),
+            events,
+            components,
+            watched_entities: descriptor.entities().to_vec(),
+            spawned_by: entity_ref
+                .spawned_by()
+                .into_option()
+                .map(|location| location.to_string()),
+            spawn_tick: entity_ref.spawn_tick().get(),
+        })
+    }
+}
+
+/// Finds the type name of the event identified by the `event_key`.
+///
+/// Each event type is backed by an internal `EventWrapperComponent<E>` component,
+/// so the event's name is the generic parameter of that component's name.
+fn event_name(world: &World, event_key: EventKey) -> DebugName {
+    let Some(wrapper_name) = world.components().get_name(event_key.component_id()) else {
+        return DebugName::owned(format!("{event_key:?}"));
+    };
+
+    let wrapper_name = wrapper_name.to_string();
+    let event_name = wrapper_name
+        .split_once('<')
+        .and_then(|(_, rest)| rest.strip_suffix('>'))
+        .unwrap_or(&wrapper_name);
+    DebugName::owned(event_name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(EntityEvent)]
+    struct Explode {
+        entity: Entity,
+    }
+
+    #[derive(Component)]
+    struct Bomb;
+
+    #[test]
+    fn observers_report_what_they_watch() {
+        let mut world = World::new();
+        let bomb = world.spawn(Bomb).id();
+        let observer = world
+            .spawn(Observer::new(|_: On<Explode>| {}).with_entity(bomb))
+            .id();
+
+        let inspection = ObserverInspection::new(&world, observer).unwrap();
+
+        assert_eq!(inspection.events.len(), 1);
+        assert_eq!(inspection.events[0].shortname().to_string(), "Explode");
+        assert_eq!(inspection.watched_entities, vec![bomb]);
+        assert!(inspection.components.is_empty());
+    }
+
+    #[test]
+    fn lifecycle_observers_report_components() {
+        let mut world = World::new();
+        let observer = world.add_observer(|_: On<Add, Bomb>| {}).id();
+        let bomb = world.register_component::<Bomb>();
+
+        let inspection = ObserverInspection::new(&world, observer).unwrap();
+
+        assert_eq!(inspection.events[0].shortname().to_string(), "Add");
+        assert_eq!(inspection.components.len(), 1);
+        assert_eq!(inspection.components[0].component_id, bomb);
+        assert!(inspection.watched_entities.is_empty());
+    }
+
+    #[test]
+    fn non_observers_are_rejected() {
+        let mut world = World::new();
+        let entity = world.spawn(Bomb).id();
+
+        assert!(matches!(
+            ObserverInspection::new(&world, entity),
+            Err(ObserverInspectionError::NotAnObserver(_))
+        ));
+    }
+}
@@ -0,0 +1,169 @@
+//! Types for inspecting the relationships between entities.
+//!
+//! Relationships are stored as ordinary components:
+//! a [`Relationship`](bevy::ecs::relationship::Relationship) component (like [`ChildOf`])
+//! points at a single target entity, while the matching
+//! [`RelationshipTarget`](bevy::ecs::relationship::RelationshipTarget) component (like [`Children`])
+//! collects all of the entities that point at it.
+//!
+//! Every component type that Bevy knows to be part of a relationship is inspected,
+//! so custom relationships show up automatically.
+
+use bevy::{
+    ecs::{
+        component::{ComponentId, ComponentInfo},
+        relationship::RelationshipAccessor,
+    },
+    prelude::*,
+};
+use core::fmt::Display;
+
+/// Which side of a relationship a component type represents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RelationshipKind {
+    /// The component points at a single target entity, like [`ChildOf`].
+    Relationship,
+    /// The component collects all entities that point at this entity, like [`Children`].
+    RelationshipTarget,
+}
+
+impl RelationshipKind {
+    /// Returns which side of a relationship the component type is, if any.
+    pub fn of(component_info: &ComponentInfo) -> Option<Self> {
+        match component_info.relationship_accessor()? {
+            RelationshipAccessor::Relationship { .. } => Some(Self::Relationship),
+            RelationshipAccessor::RelationshipTarget { .. } => Some(Self::RelationshipTarget),
+        }
+    }
+}
+
+/// The result of inspecting a single relationship component on an entity.
+///
+/// Log this using the [`Display`] trait to see the related entities.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationshipInspection {
+    /// The [`ComponentId`] of the relationship component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the relationship component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// Which side of the relationship this component represents.
+    pub kind: RelationshipKind,
+    /// The related entities.
+    ///
+    /// For a [`RelationshipKind::Relationship`], this is the single target entity.
+    /// For a [`RelationshipKind::RelationshipTarget`], these are the entities that point at this one,
+    /// in the order stored by the component.
+    pub entities: Vec<Entity>,
+}
+
+impl Display for RelationshipInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shortname = self.name.shortname();
+
+        match self.kind {
+            RelationshipKind::Relationship => write!(f, "{shortname} -> ")?,
+            RelationshipKind::RelationshipTarget => write!(f, "{shortname} <- ")?,
+        }
+
+        let entities: Vec<String> = self.entities.iter().map(ToString::to_string).collect();
+        write!(f, "[{}]", entities.join(", "))
+    }
+}
+
+/// Returns the entities related to the `entity` through its relationship component described by `component_info`,
+/// in the order stored by the component.
+///
+/// The relationship is read through the component's [`RelationshipAccessor`],
+/// so any other [`Entity`] data stored in a custom relationship component is ignored.
+///
+/// Returns `None` if the component type is not part of a relationship,
+/// or if the entity does not have it.
+pub fn related_entities(entity: EntityRef, component_info: &ComponentInfo) -> Option<Vec<Entity>> {
+    let accessor = component_info.relationship_accessor()?;
+    let ptr = entity.get_by_id(component_info.id()).ok()?;
+
+    let entities = match accessor {
+        RelationshipAccessor::Relationship {
+            entity_field_offset,
+            ..
+        } => {
+            // SAFETY: the accessor was registered for this component type,
+            // so the offset points to an aligned `Entity` within the component value.
+            let target: Entity = unsafe { *ptr.byte_add(*entity_field_offset).deref() };
+            vec![target]
+        }
+        // SAFETY: the pointer holds a value of the component type the accessor was registered for.
+        RelationshipAccessor::RelationshipTarget { iter, .. } => unsafe { iter(ptr).collect() },
+    };
+    Some(entities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    #[relationship(relationship_target = Owners)]
+    struct OwnedBy {
+        previous_owner: Option<Entity>,
+        #[relationship]
+        owner: Entity,
+    }
+
+    #[derive(Component)]
+    #[relationship_target(relationship = OwnedBy)]
+    struct Owners(Vec<Entity>);
+
+    #[test]
+    fn only_the_relationship_field_is_read() {
+        let mut world = World::new();
+        let previous_owner = world.spawn_empty().id();
+        let owner = world.spawn_empty().id();
+        let item = world
+            .spawn(OwnedBy {
+                previous_owner: Some(previous_owner),
+                owner,
+            })
+            .id();
+        let stored_previous_owner = world.get::<OwnedBy>(item).unwrap().previous_owner;
+        assert_eq!(stored_previous_owner, Some(previous_owner));
+
+        let related = |entity: Entity, component_id| {
+            let component_info = world.components().get_info(component_id).unwrap();
+            related_entities(world.entity(entity), component_info)
+        };
+        let owned_by = world.components().component_id::<OwnedBy>().unwrap();
+        let owners = world.components().component_id::<Owners>().unwrap();
+
+        assert_eq!(related(item, owned_by), Some(vec![owner]));
+        assert_eq!(related(owner, owners), Some(vec![item]));
+        assert_eq!(related(previous_owner, owners), None);
+    }
+
+    #[test]
+    fn relationship_kinds_are_detected() {
+        let mut world = World::new();
+        let child_of = world.register_component::<ChildOf>();
+        let children = world.register_component::<Children>();
+        let name = world.register_component::<Name>();
+
+        let kind_of = |id| RelationshipKind::of(world.components().get_info(id).unwrap());
+
+        assert_eq!(kind_of(child_of), Some(RelationshipKind::Relationship));
+        assert_eq!(
+            kind_of(children),
+            Some(RelationshipKind::RelationshipTarget)
+        );
+        assert_eq!(kind_of(name), None);
+    }
+}
@@ -1,10 +1,12 @@
 //! Types and traits for inspecting Bevy resources.
 
-use crate::memory_size::MemorySize;
+use crate::inspection::component_inspection::ChangeDetails;
+use crate::memory_size::{MemoryDetailLevel, MemoryEstimate};
 use bevy::reflect::TypeRegistration;
 use bevy::{ecs::component::ComponentId, prelude::*};
 use core::any::TypeId;
 use core::fmt::{Debug, Display};
+use std::thread::ThreadId;
 use thiserror::Error;
 
 /// The result of inspecting a resource.
@@ -26,6 +28,9 @@ pub struct ResourceInspection {
         serde(with = "crate::serde_conversions::debug_name")
     )]
     pub name: DebugName,
+    /// Whether the resource is a `Send` resource, stored on a resource entity,
+    /// or a non-`Send` resource, which can only be accessed from the thread it was inserted on.
+    pub is_send: bool,
     /// The value of the resource as a string.
     ///
     /// This information is gathered via reflection,
@@ -36,11 +41,17 @@ pub struct ResourceInspection {
     /// Note that dynamic types will not have a [`TypeId`].
     #[cfg_attr(feature = "serde", serde(skip))]
     pub type_id: Option<TypeId>,
-    /// The shallow size of the resource in memory.
+    /// The size of the resource in memory.
     ///
-    /// Note that this may differ from the size of the resource type
-    /// if it is a dynamically-sized: heap-allocated data is not included.
-    pub memory_size: MemorySize,
+    /// By default, only the shallow size is measured: heap-allocated data is not included.
+    /// Set [`ResourceInspectionSettings::memory_detail_level`] to [`MemoryDetailLevel::Deep`]
+    /// to also estimate the heap memory owned by the resource.
+    pub memory_size: MemoryEstimate,
+    /// When the resource was added and last changed, and by which code.
+    ///
+    /// This is `None` if the resource is not present in the world,
+    /// or is a non-`Send` resource.
+    pub change_details: Option<ChangeDetails>,
     /// The type information of the resource.
     ///
     /// This contains metadata about the resource's type,
@@ -60,7 +71,15 @@ pub struct ResourceInspection {
 impl Display for ResourceInspection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let short_name = self.name.shortname();
-        write!(f, "{} ({}): {}", short_name, self.memory_size, self.value)?;
+        write!(f, "{short_name}")?;
+        if !self.is_send {
+            write!(f, " [non-Send]")?;
+        }
+        write!(f, " ({}", self.memory_size)?;
+        if let Some(change_details) = &self.change_details {
+            write!(f, "; {change_details}")?;
+        }
+        write!(f, "): {}", self.value)?;
 
         Ok(())
     }
@@ -91,4 +110,97 @@ pub struct ResourceInspectionSettings {
     ///
     /// Defaults to `false`.
     pub full_type_names: bool,
+    /// How thoroughly the memory used by the resource should be measured.
+    ///
+    /// Defaults to [`MemoryDetailLevel::Shallow`].
+    pub memory_detail_level: MemoryDetailLevel,
+}
+
+/// The thread that non-`Send` resources are inserted on, which is the only thread they can be read from.
+///
+/// [`World`] does not expose which thread owns its non-`Send` resources,
+/// and reading them from any other thread panics.
+/// Instead, the inspector plugins record the thread they were built on,
+/// which is the main thread for apps that are run normally.
+///
+/// Non-`Send` resources inserted on a different thread,
+/// or worlds that are moved to a different thread after the app is built,
+/// are not detected, and reading their values will panic.
+/// When this resource is missing, the values of non-`Send` resources are never read.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NonSendThread(pub ThreadId);
+
+impl NonSendThread {
+    /// Records the current thread as the one that owns the non-`Send` resources.
+    pub fn current() -> Self {
+        Self(std::thread::current().id())
+    }
+
+    /// Returns whether the current thread is the one that owns the non-`Send` resources.
+    pub fn is_current(&self) -> bool {
+        self.0 == std::thread::current().id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension_methods::WorldInspectionExtensionTrait;
+
+    #[derive(Reflect)]
+    struct WindowHandle {
+        raw: u32,
+    }
+
+    fn world_with_non_send_resource() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<WindowHandle>();
+        world.insert_non_send(WindowHandle { raw: 7 });
+        world
+    }
+
+    #[test]
+    fn non_send_resources_are_listed_with_their_size() {
+        let world = world_with_non_send_resource();
+
+        let inspections = world.inspect_all_non_send_resources(default());
+        assert_eq!(inspections.len(), 1);
+        let inspection = &inspections[0];
+        assert_eq!(inspection.name.shortname().to_string(), "WindowHandle");
+        assert!(!inspection.is_send);
+        assert_eq!(
+            inspection.memory_size,
+            MemoryEstimate::Shallow(crate::memory_size::MemorySize::new(4))
+        );
+
+        // Send resources are not included
+        assert!(
+            world
+                .inspect_all_resources(default())
+                .iter()
+                .all(|inspection| inspection.is_send)
+        );
+    }
+
+    #[test]
+    fn non_send_resource_values_are_only_read_on_the_owning_thread() {
+        let mut world = world_with_non_send_resource();
+        let unread = world.inspect_all_non_send_resources(default()).remove(0);
+        assert!(!unread.value.contains("raw: 7"));
+
+        world.insert_resource(NonSendThread::current());
+        let inspection = world.inspect_all_non_send_resources(default()).remove(0);
+        assert!(inspection.value.contains("raw: 7"));
+
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                let inspection = world.inspect_all_non_send_resources(default()).remove(0);
+                assert!(!inspection.value.contains("raw: 7"));
+            });
+        });
+    }
 }
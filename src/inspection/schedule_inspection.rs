@@ -0,0 +1,565 @@
+//! Types for inspecting [`Schedule`]s and the systems inside them.
+//!
+//! Schedules are stored in the [`Schedules`] resource.
+//! Each schedule holds a graph of systems and system sets,
+//! which records how they are nested, how they are ordered and which run conditions apply to them.
+//!
+//! Note that Bevy temporarily removes a schedule from [`Schedules`] while it is running,
+//! so schedules which are currently running (such as [`Main`](bevy::app::Main)) are not inspected.
+
+use bevy::{
+    ecs::{
+        component::ComponentId,
+        query::FilteredAccessSet,
+        schedule::{
+            ConditionWithAccess, FlattenedDependencies, InternedScheduleLabel, NodeId,
+            ScheduleBuildError, ScheduleBuildPass, ScheduleGraph, SystemKey, SystemSetKey,
+            graph::{DiGraph, Direction},
+        },
+    },
+    platform::{collections::HashMap, hash::FixedHasher},
+    prelude::*,
+};
+use core::fmt::Display;
+use indexmap::IndexSet;
+
+use crate::inspection::system_inspection::{AccessKind, SystemAccessInspection, access_kind_by_id};
+
+/// The result of inspecting a [`Schedule`].
+///
+/// Log this using the [`Display`] trait to see the systems in the schedule.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduleInspection {
+    /// The [`ScheduleLabel`](bevy::ecs::schedule::ScheduleLabel) of the schedule, formatted using [`Debug`].
+    pub label: String,
+    /// Whether the schedule has been initialized, which typically happens when it is first run.
+    ///
+    /// System access is only known once the schedule has been initialized.
+    pub is_initialized: bool,
+    /// The systems in the schedule.
+    ///
+    /// Once the schedule is initialized, these are listed in the order in which they run.
+    pub systems: Vec<ScheduledSystemInspection>,
+    /// The named system sets in the schedule.
+    ///
+    /// Anonymous sets and the sets which Bevy creates for each system type are not listed.
+    pub system_sets: Vec<SystemSetInspection>,
+    /// The ordering constraints between systems and system sets.
+    pub ordering: Vec<OrderingInspection>,
+}
+
+impl Display for ScheduleInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Schedule {} ({} systems, {} sets)",
+            self.label,
+            self.systems.len(),
+            self.system_sets.len()
+        )?;
+        if !self.is_initialized {
+            write!(f, " [not initialized]")?;
+        }
+
+        for system_set in &self.system_sets {
+            write!(f, "\n  Set {system_set}")?;
+        }
+        for system in &self.systems {
+            write!(f, "\n  {system}")?;
+        }
+        for ordering in &self.ordering {
+            write!(f, "\n  {ordering}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A system inside a [`Schedule`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScheduledSystemInspection {
+    /// The name of the system.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// The named system sets that directly contain the system.
+    pub sets: Vec<String>,
+    /// The names of the run conditions added directly to the system.
+    ///
+    /// Run conditions of the containing sets are listed in [`SystemSetInspection::run_conditions`].
+    /// Once the schedule has been built, these are only known if recorded by the [`ScheduleInspectionPlugin`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::option_vec_debug_name")
+    )]
+    pub run_conditions: Option<Vec<DebugName>>,
+    /// The components and resources that the system reads and writes.
+    ///
+    /// This is only known once the schedule has been initialized,
+    /// and once it has been built, only if recorded by the [`ScheduleInspectionPlugin`].
+    pub access: Option<SystemAccessInspection>,
+}
+
+impl Display for ScheduledSystemInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name.shortname())?;
+        if !self.sets.is_empty() {
+            write!(f, " in [{}]", self.sets.join(", "))?;
+        }
+        if let Some(run_conditions) = &self.run_conditions
+            && !run_conditions.is_empty()
+        {
+            write!(f, " if [{}]", format_conditions(run_conditions))?;
+        }
+        match &self.access {
+            Some(access) => write!(f, ": {access}"),
+            None => write!(f, ": unknown access"),
+        }
+    }
+}
+
+/// A named system set inside a [`Schedule`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemSetInspection {
+    /// The name of the system set, formatted using [`Debug`].
+    pub name: String,
+    /// The named system sets that directly contain this set.
+    pub parent_sets: Vec<String>,
+    /// The names of the run conditions added to this set.
+    ///
+    /// Once the schedule has been built, these are only known if recorded by the [`ScheduleInspectionPlugin`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::option_vec_debug_name")
+    )]
+    pub run_conditions: Option<Vec<DebugName>>,
+}
+
+impl Display for SystemSetInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.parent_sets.is_empty() {
+            write!(f, " in [{}]", self.parent_sets.join(", "))?;
+        }
+        if let Some(run_conditions) = &self.run_conditions
+            && !run_conditions.is_empty()
+        {
+            write!(f, " if [{}]", format_conditions(run_conditions))?;
+        }
+        Ok(())
+    }
+}
+
+/// An ordering constraint between two systems or system sets,
+/// as added using [`before`](IntoScheduleConfigs::before) or [`after`](IntoScheduleConfigs::after).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderingInspection {
+    /// The name of the system or set that runs first.
+    pub before: String,
+    /// The name of the system or set that runs second.
+    pub after: String,
+}
+
+impl Display for OrderingInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {}", self.before, self.after)
+    }
+}
+
+impl ScheduleInspection {
+    /// Inspects the `schedule`, resolving the names of accessed types using the `world`.
+    ///
+    /// Bevy does not expose the access of systems in a [`Schedule`],
+    /// and moves their run conditions out of the [`ScheduleGraph`] once it has been built,
+    /// so both are only known if they were recorded by the [`ScheduleInspectionPlugin`].
+    pub fn new(world: &World, schedule: &Schedule) -> Self {
+        let graph = schedule.graph();
+        let record = world
+            .get_resource::<ScheduleBuildRecords>()
+            .and_then(|records| records.schedules.get(&schedule.label()));
+
+        // Initialized schedules know the order in which their systems run
+        let is_initialized = schedule.systems().is_ok();
+        let system_names: Vec<(SystemKey, DebugName)> = match schedule.systems() {
+            Ok(systems) => systems.map(|(key, system)| (key, system.name())).collect(),
+            Err(_) => graph
+                .systems
+                .iter()
+                .map(|(key, system, _)| (key, system.name()))
+                .collect(),
+        };
+        let names: HashMap<SystemKey, DebugName> = system_names.iter().cloned().collect();
+
+        // Hierarchy edges point from each set to its members
+        let mut parents: HashMap<NodeId, Vec<String>> = HashMap::default();
+        for (parent, child) in graph.hierarchy().graph().all_edges() {
+            if is_named_set(graph, parent) {
+                parents
+                    .entry(child)
+                    .or_default()
+                    .push(node_name(graph, &names, parent));
+            }
+        }
+
+        let systems = system_names
+            .into_iter()
+            .map(|(key, name)| {
+                let (access, run_conditions) =
+                    match record.and_then(|record| record.systems.get(&key)) {
+                        Some((access, run_conditions)) => (
+                            Some(SystemAccessInspection::new(world, access)),
+                            Some(run_conditions.clone()),
+                        ),
+                        // Systems which have not been built yet are still stored in the graph
+                        None => (None, graph.systems.get_conditions(key).map(condition_names)),
+                    };
+
+                ScheduledSystemInspection {
+                    name,
+                    sets: parents
+                        .get(&NodeId::System(key))
+                        .cloned()
+                        .unwrap_or_default(),
+                    run_conditions,
+                    access,
+                }
+            })
+            .collect();
+
+        let system_sets = graph
+            .system_sets
+            .iter()
+            .filter(|(key, ..)| is_named_set(graph, NodeId::Set(*key)))
+            .map(|(key, set, conditions)| SystemSetInspection {
+                name: format!("{set:?}"),
+                parent_sets: parents.get(&NodeId::Set(key)).cloned().unwrap_or_default(),
+                run_conditions: if is_initialized {
+                    record.and_then(|record| record.set_conditions.get(&key).cloned())
+                } else {
+                    Some(condition_names(conditions))
+                },
+            })
+            .collect();
+
+        let ordering = graph
+            .dependency()
+            .graph()
+            .all_edges()
+            .map(|(before, after)| OrderingInspection {
+                before: node_name(graph, &names, before),
+                after: node_name(graph, &names, after),
+            })
+            .collect();
+
+        Self {
+            label: format!("{:?}", schedule.label()),
+            is_initialized,
+            systems,
+            system_sets,
+            ordering,
+        }
+    }
+}
+
+/// Returns true if the `node` is a system set that was named by the user,
+/// rather than an anonymous set or a set created for a system type.
+fn is_named_set(graph: &ScheduleGraph, node: NodeId) -> bool {
+    let NodeId::Set(key) = node else {
+        return false;
+    };
+    graph
+        .system_sets
+        .get(key)
+        .is_some_and(|set| !set.is_anonymous() && set.system_type().is_none())
+}
+
+/// A short, human-readable name for a system or system set.
+///
+/// System names are looked up in `names`, as built systems are no longer stored in the `graph`.
+fn node_name(graph: &ScheduleGraph, names: &HashMap<SystemKey, DebugName>, node: NodeId) -> String {
+    match node {
+        NodeId::System(key) => names
+            .get(&key)
+            .map(|name| name.shortname().to_string())
+            .unwrap_or_else(|| format!("{key:?}")),
+        NodeId::Set(key) => match graph.system_sets.get(key) {
+            // Anonymous sets are named after their members, like Bevy does in its error messages
+            Some(set) if set.is_anonymous() => {
+                let members: Vec<String> = graph
+                    .hierarchy()
+                    .graph()
+                    .neighbors_directed(node, Direction::Outgoing)
+                    .map(|member| node_name(graph, names, member))
+                    .collect();
+                format!("({})", members.join(", "))
+            }
+            Some(set) => format!("{set:?}"),
+            None => format!("{key:?}"),
+        },
+    }
+}
+
+/// Joins the short names of the run `conditions`.
+pub(crate) fn format_conditions(conditions: &[DebugName]) -> String {
+    let names: Vec<String> = conditions
+        .iter()
+        .map(|condition| condition.shortname().to_string())
+        .collect();
+    names.join(", ")
+}
+
+/// The names of the run `conditions`.
+fn condition_names(conditions: &[ConditionWithAccess]) -> Vec<DebugName> {
+    conditions
+        .iter()
+        .map(|condition| condition.condition.name())
+        .collect()
+}
+
+/// The system access and run conditions recorded by the [`ScheduleInspectionPlugin`]
+/// when each schedule was last built.
+#[derive(Resource, Default, Debug)]
+pub struct ScheduleBuildRecords {
+    schedules: HashMap<InternedScheduleLabel, ScheduleBuildRecord>,
+}
+
+impl ScheduleBuildRecords {
+    /// Returns how the system identified by `key` in the schedule with the provided `label`
+    /// accesses the component or resource with the provided `component_id`.
+    ///
+    /// This only checks the recorded access of that system, rather than inspecting the whole schedule.
+    /// Returns `None` if the system's access was not recorded, if it does not access the type,
+    /// or if it has exclusive world access.
+    pub fn access_kind(
+        &self,
+        label: InternedScheduleLabel,
+        key: SystemKey,
+        component_id: ComponentId,
+    ) -> Option<AccessKind> {
+        let (access, _) = self.schedules.get(&label)?.systems.get(&key)?;
+        access_kind_by_id(access, component_id)
+    }
+}
+
+/// The details of a single schedule, recorded by the [`ScheduleRecordingPass`].
+#[derive(Default, Debug)]
+struct ScheduleBuildRecord {
+    /// The access and run conditions of each system.
+    systems: HashMap<SystemKey, (FilteredAccessSet, Vec<DebugName>)>,
+    /// The run conditions of each system set.
+    set_conditions: HashMap<SystemSetKey, Vec<DebugName>>,
+}
+
+/// A [`ScheduleBuildPass`] which records the access and run conditions of each system
+/// in the [`ScheduleBuildRecords`] resource, before Bevy moves them out of the [`ScheduleGraph`].
+///
+/// Bevy keeps the access of each system private, so it is recorded by initializing the system again.
+/// Schedules are built right after their new systems are initialized,
+/// so this returns the same access without affecting the system's change detection.
+/// Systems which were recorded by an earlier build keep their recorded access.
+#[derive(Debug)]
+struct ScheduleRecordingPass {
+    label: InternedScheduleLabel,
+}
+
+impl ScheduleBuildPass for ScheduleRecordingPass {
+    type EdgeOptions = ();
+
+    fn add_dependency(&mut self, _from: NodeId, _to: NodeId, _options: Option<&Self::EdgeOptions>) {
+    }
+
+    fn collapse_set(
+        &mut self,
+        _set: SystemSetKey,
+        _systems: &IndexSet<SystemKey, FixedHasher>,
+        _dependency_flattening: &DiGraph<NodeId>,
+    ) -> impl Iterator<Item = (NodeId, NodeId)> {
+        core::iter::empty()
+    }
+
+    fn build(
+        &mut self,
+        world: &mut World,
+        graph: &mut ScheduleGraph,
+        _dependency_flattened: FlattenedDependencies<'_>,
+    ) -> Result<(), ScheduleBuildError> {
+        let mut previous_systems = world
+            .get_resource_mut::<ScheduleBuildRecords>()
+            .and_then(|mut records| records.schedules.remove(&self.label))
+            .map(|record| record.systems)
+            .unwrap_or_default();
+
+        let keys: Vec<SystemKey> = graph.systems.iter().map(|(key, _, _)| key).collect();
+        let mut systems = HashMap::default();
+        for key in keys {
+            let access = match previous_systems.remove(&key) {
+                Some((access, _)) => access,
+                None => match graph.systems.get_mut(key) {
+                    Some(system) => system.initialize(world),
+                    None => continue,
+                },
+            };
+            let run_conditions = graph
+                .systems
+                .get_conditions(key)
+                .map(condition_names)
+                .unwrap_or_default();
+            systems.insert(key, (access, run_conditions));
+        }
+
+        let record = ScheduleBuildRecord {
+            systems,
+            set_conditions: graph
+                .system_sets
+                .iter()
+                .map(|(key, _, conditions)| (key, condition_names(conditions)))
+                .collect(),
+        };
+
+        world
+            .get_resource_or_init::<ScheduleBuildRecords>()
+            .schedules
+            .insert(self.label, record);
+        Ok(())
+    }
+}
+
+/// A plugin which records the access and run conditions of the systems in every schedule,
+/// so that they can be reported by [`ScheduleInspection`] after the schedule has been built.
+///
+/// Only schedules which exist when the app is finished being built are recorded.
+///
+/// When upstreamed, this plugin should not be necessary,
+/// as [`Schedule`] can expose this information directly.
+pub struct ScheduleInspectionPlugin;
+
+impl Plugin for ScheduleInspectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScheduleBuildRecords>();
+    }
+
+    fn finish(&self, app: &mut App) {
+        let mut schedules = app.world_mut().resource_mut::<Schedules>();
+        for (_, schedule) in schedules.iter_mut() {
+            let label = schedule.label();
+            schedule.add_build_pass(ScheduleRecordingPass { label });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::schedule::ScheduleLabel;
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Simulation;
+
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Physics;
+
+    #[derive(Resource, Default)]
+    struct Gravity;
+
+    #[derive(Component)]
+    struct Velocity;
+
+    fn apply_gravity(_gravity: Res<Gravity>, _velocities: Query<&mut Velocity>) {}
+
+    fn detect_collisions(_velocities: Query<&Velocity>) {}
+
+    fn simulation_enabled() -> bool {
+        true
+    }
+
+    /// Builds a world with the `Simulation` schedule,
+    /// optionally recording its details using the [`ScheduleRecordingPass`].
+    fn simulation_world(record: bool) -> World {
+        let mut world = World::new();
+        world.init_resource::<Gravity>();
+
+        let mut schedule = Schedule::new(Simulation);
+        if record {
+            schedule.add_build_pass(ScheduleRecordingPass {
+                label: Simulation.intern(),
+            });
+        }
+        schedule.configure_sets(Physics.run_if(simulation_enabled));
+        schedule
+            .add_systems((apply_gravity, detect_collisions.after(apply_gravity)).in_set(Physics));
+        schedule.initialize(&mut world).unwrap();
+
+        world.add_schedule(schedule);
+        world
+    }
+
+    fn inspect_simulation(world: &World) -> ScheduleInspection {
+        let schedule = world.resource::<Schedules>().get(Simulation).unwrap();
+        ScheduleInspection::new(world, schedule)
+    }
+
+    #[test]
+    fn schedules_list_systems_in_run_order() {
+        let world = simulation_world(false);
+        let inspection = inspect_simulation(&world);
+
+        assert_eq!(inspection.label, "Simulation");
+        assert!(inspection.is_initialized);
+        let names: Vec<String> = inspection
+            .systems
+            .iter()
+            .map(|system| system.name.shortname().to_string())
+            .collect();
+        assert_eq!(names, vec!["apply_gravity", "detect_collisions"]);
+        assert_eq!(inspection.systems[0].sets, vec!["Physics".to_string()]);
+        assert!(
+            inspection
+                .ordering
+                .iter()
+                .any(|ordering| ordering.after == "detect_collisions")
+        );
+        // Without the recording pass, built schedules do not expose access
+        assert!(inspection.systems[0].access.is_none());
+    }
+
+    #[test]
+    fn recorded_schedules_report_run_conditions() {
+        let world = simulation_world(true);
+        let inspection = inspect_simulation(&world);
+
+        assert_eq!(inspection.system_sets.len(), 1);
+        let physics = &inspection.system_sets[0];
+        assert_eq!(physics.name, "Physics");
+        let run_conditions = physics.run_conditions.as_ref().unwrap();
+        assert_eq!(run_conditions.len(), 1);
+        assert!(run_conditions[0].to_string().contains("simulation_enabled"));
+    }
+
+    #[test]
+    fn recorded_schedules_report_access() {
+        let world = simulation_world(true);
+        let inspection = inspect_simulation(&world);
+
+        let access = inspection.systems[0].access.as_ref().unwrap();
+        let gravity = world.component_id::<Gravity>().unwrap();
+        let velocity = world.component_id::<Velocity>().unwrap();
+        assert!(
+            access
+                .reads
+                .iter()
+                .any(|read| read.component_id == gravity && read.is_resource)
+        );
+        assert!(
+            access
+                .writes
+                .iter()
+                .any(|write| write.component_id == velocity)
+        );
+    }
+}
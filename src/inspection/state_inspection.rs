@@ -0,0 +1,551 @@
+//! Types for inspecting and controlling [`States`].
+//!
+//! The value of each state type is stored in a [`State<S>`] resource,
+//! while transitions are queued by writing to its [`NextState<S>`] resource.
+//! These resources are found through reflection, so that every state type can be inspected
+//! without knowing its type.
+//!
+//! States should be registered using [`register_type_mutable_state`],
+//! or [`register_type_state`] for states which can't be set directly, such as [`ComputedStates`].
+//! The [`StateInspectionPlugin`] records the history of transitions for each state type.
+//!
+//! [`register_type_mutable_state`]: AppExtStates::register_type_mutable_state
+//! [`register_type_state`]: AppExtStates::register_type_state
+
+use bevy::{
+    diagnostic::FrameCount,
+    ecs::component::ComponentId,
+    platform::collections::HashMap,
+    prelude::*,
+    reflect::{
+        ReflectFromReflect, ReflectRef, TypeInfo, TypeRegistry,
+        enums::{DynamicEnum, DynamicVariant, VariantInfo},
+    },
+    state::reflect::{ReflectFreelyMutableState, ReflectState},
+};
+use core::any::TypeId;
+use core::fmt::Display;
+use std::collections::VecDeque;
+use thiserror::Error;
+
+use crate::reflection_tools::reflected_value_to_string;
+
+/// The result of inspecting a single state type.
+///
+/// Log this using the [`Display`] trait to see the current and pending values of the state.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateInspection {
+    /// The type name of the state.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// The current value of the state, stored in its [`State<S>`] resource.
+    ///
+    /// This is `None` if the state does not currently exist,
+    /// such as a [`SubStates`] whose source state does not match.
+    pub current: Option<String>,
+    /// The name of the current enum variant of the state.
+    ///
+    /// This is `None` if the state does not currently exist, or is not an enum.
+    pub current_variant: Option<String>,
+    /// The value the state will transition to, stored in its [`NextState<S>`] resource.
+    ///
+    /// This is `None` if no transition is queued.
+    pub pending: Option<String>,
+    /// The names of the unit variants of the state,
+    /// which can be passed to [`queue_state_transition`].
+    pub variants: Vec<String>,
+    /// Whether or not transitions of this state can be queued using [`queue_state_transition`].
+    ///
+    /// This requires the state to be registered using [`register_type_mutable_state`],
+    /// and to have a [`NextState<S>`] resource.
+    ///
+    /// [`register_type_mutable_state`]: AppExtStates::register_type_mutable_state
+    pub can_queue_transitions: bool,
+    /// The most recent transitions of the state, oldest first.
+    ///
+    /// This is empty unless the [`StateInspectionPlugin`] is added.
+    pub history: Vec<StateTransitionRecord>,
+}
+
+impl Display for StateInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: ", self.name.shortname())?;
+        match &self.current {
+            Some(current) => write!(f, "{current}")?,
+            None => write!(f, "<not present>")?,
+        }
+        if let Some(pending) = &self.pending {
+            write!(f, " (transitioning to {pending})")?;
+        }
+        if !self.variants.is_empty() {
+            write!(f, "\n  Variants: {}", self.variants.join(", "))?;
+        }
+        for transition in &self.history {
+            write!(f, "\n  {transition}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A transition of a state type, as recorded by the [`StateInspectionPlugin`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateTransitionRecord {
+    /// The [`FrameCount`] when the transition was observed.
+    pub frame: u32,
+    /// The value of the state before the transition,
+    /// or `None` if the state did not exist.
+    pub exited: Option<String>,
+    /// The value of the state after the transition,
+    /// or `None` if the state was removed.
+    pub entered: Option<String>,
+}
+
+impl Display for StateTransitionRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Frame {}: {} -> {}",
+            self.frame,
+            self.exited.as_deref().unwrap_or("<none>"),
+            self.entered.as_deref().unwrap_or("<none>")
+        )
+    }
+}
+
+/// Settings that can be used to customize state inspection,
+/// changing how [`StateInspection`] is generated and displayed.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StateInspectionSettings {
+    /// Whether or not full type names should be displayed.
+    ///
+    /// Defaults to `false`.
+    pub full_type_names: bool,
+}
+
+/// An error that occurs when queuing a state transition using [`queue_state_transition`].
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StateTransitionError {
+    /// No state type with the provided name is registered using [`ReflectState`].
+    #[error("No state type named {0} is registered for reflection")]
+    StateNotFound(String),
+    /// The state type can't be set directly,
+    /// as it is not registered using [`register_type_mutable_state`] or has no [`NextState<S>`] resource.
+    ///
+    /// [`register_type_mutable_state`]: AppExtStates::register_type_mutable_state
+    #[error("State {0} can't be set directly")]
+    NotFreelyMutable(String),
+    /// The state type has no unit variant with the provided name.
+    #[error("State {state} has no unit variant named {variant}, expected one of: {}", expected.join(", "))]
+    UnknownVariant {
+        /// The type name of the state.
+        state: String,
+        /// The requested variant.
+        variant: String,
+        /// The names of the state's unit variants.
+        expected: Vec<String>,
+    },
+    /// The state type has no [`ReflectFromReflect`] type data,
+    /// so a value of it can't be constructed from the variant name.
+    #[error("State {0} can't be constructed through reflection")]
+    NotConstructible(String),
+    /// The [`AppTypeRegistry`] resource is missing, so no state types can be found.
+    #[error("The `AppTypeRegistry` resource is missing")]
+    TypeRegistryMissing,
+}
+
+/// The [`State<S>`] and [`NextState<S>`] resources of a state type.
+#[derive(Default)]
+struct StateResources {
+    name: Option<&'static str>,
+    state: Option<ComponentId>,
+    next_state: Option<ComponentId>,
+}
+
+impl StateInspection {
+    /// Inspects every state type, sorted by name.
+    ///
+    /// This includes every type registered with [`ReflectState`] type data,
+    /// as well as any other reflected [`State<S>`] or [`NextState<S>`] resources.
+    ///
+    /// Returns an empty list if the [`AppTypeRegistry`] resource is missing.
+    pub fn inspect_all(world: &World, settings: StateInspectionSettings) -> Vec<Self> {
+        let Some(type_registry) = world.get_resource::<AppTypeRegistry>() else {
+            return Vec::new();
+        };
+        let type_registry = type_registry.read();
+        let history = world.get_resource::<StateTransitionHistory>();
+
+        let mut inspections: Vec<Self> = find_state_resources(world, &type_registry)
+            .into_iter()
+            .map(|(state_type, resources)| {
+                let registration = type_registry.get(state_type);
+                let name = registration
+                    .map(|registration| registration.type_info().type_path())
+                    .or(resources.name)
+                    .unwrap_or("<Unknown State>");
+
+                let current = resources
+                    .state
+                    .and_then(|component_id| reflect_resource(world, component_id))
+                    .and_then(|state| match state.reflect_ref() {
+                        ReflectRef::TupleStruct(state) => state.field(0),
+                        _ => None,
+                    });
+                let current_variant = current.and_then(|value| match value.reflect_ref() {
+                    ReflectRef::Enum(value) => Some(value.variant_name().to_string()),
+                    _ => None,
+                });
+
+                let pending = resources
+                    .next_state
+                    .and_then(|component_id| reflect_resource(world, component_id))
+                    .and_then(|next_state| match next_state.reflect_ref() {
+                        // `NextState::Unchanged` has no fields
+                        ReflectRef::Enum(next_state) => next_state.field_at(0),
+                        _ => None,
+                    });
+
+                let can_queue_transitions = resources.next_state.is_some()
+                    && registration.is_some_and(|registration| {
+                        registration.contains::<ReflectFreelyMutableState>()
+                    });
+
+                Self {
+                    name: DebugName::owned(name.to_string()),
+                    current: current
+                        .map(|value| reflected_value_to_string(value, settings.full_type_names)),
+                    current_variant,
+                    pending: pending
+                        .map(|value| reflected_value_to_string(value, settings.full_type_names)),
+                    variants: registration
+                        .map(|registration| unit_variants(registration.type_info()))
+                        .unwrap_or_default(),
+                    can_queue_transitions,
+                    history: history
+                        .map(|history| history.transitions(state_type).cloned().collect())
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        inspections.sort_by_cached_key(|inspection| inspection.name.shortname().to_string());
+        inspections
+    }
+}
+
+/// Queues a transition of the state type named `state` to its unit variant named `variant`,
+/// as if [`NextState::set`] were called.
+///
+/// The `state` can be either the full or the short type name of the state type.
+/// The transition happens the next time the [`StateTransition`] schedule runs.
+pub fn queue_state_transition(
+    world: &mut World,
+    state: &str,
+    variant: &str,
+) -> Result<(), StateTransitionError> {
+    let app_type_registry = world
+        .get_resource::<AppTypeRegistry>()
+        .ok_or(StateTransitionError::TypeRegistryMissing)?
+        .clone();
+    let type_registry = app_type_registry.read();
+
+    let registration = type_registry
+        .iter_with_data::<ReflectState>()
+        .map(|(registration, _)| registration)
+        .find(|registration| {
+            let type_path = registration.type_info().type_path();
+            type_path == state || ShortName::from(type_path).to_string() == state
+        })
+        .ok_or_else(|| StateTransitionError::StateNotFound(state.to_string()))?;
+    let type_info = registration.type_info();
+    let state_name = type_info.type_path().to_string();
+
+    let has_next_state = find_state_resources(world, &type_registry)
+        .get(&registration.type_id())
+        .is_some_and(|resources| resources.next_state.is_some());
+    let reflect_mutable_state = registration
+        .data::<ReflectFreelyMutableState>()
+        .filter(|_| has_next_state)
+        .ok_or_else(|| StateTransitionError::NotFreelyMutable(state_name.clone()))?;
+
+    let expected = unit_variants(type_info);
+    if !expected.iter().any(|expected| expected == variant) {
+        return Err(StateTransitionError::UnknownVariant {
+            state: state_name,
+            variant: variant.to_string(),
+            expected,
+        });
+    }
+
+    let mut dynamic_state = DynamicEnum::new(variant, DynamicVariant::Unit);
+    dynamic_state.set_represented_type(Some(type_info));
+    let new_state = registration
+        .data::<ReflectFromReflect>()
+        .and_then(|reflect_from_reflect| reflect_from_reflect.from_reflect(&dynamic_state))
+        .ok_or(StateTransitionError::NotConstructible(state_name))?;
+
+    reflect_mutable_state.set_next_state(world, new_state.as_ref(), &type_registry);
+    Ok(())
+}
+
+/// Finds the [`State<S>`] and [`NextState<S>`] resources of each state type, keyed by the [`TypeId`] of `S`.
+///
+/// Every type registered with [`ReflectState`] is included, even if its resources do not exist.
+// Bevy does not keep a list of state types, so reflected resources are matched by their type path instead.
+fn find_state_resources(
+    world: &World,
+    type_registry: &TypeRegistry,
+) -> HashMap<TypeId, StateResources> {
+    let mut states: HashMap<TypeId, StateResources> = type_registry
+        .iter_with_data::<ReflectState>()
+        .map(|(registration, _)| (registration.type_id(), StateResources::default()))
+        .collect();
+
+    for (component_info, _ptr) in world.iter_resources() {
+        let Some(type_info) = component_info
+            .type_id()
+            .and_then(|type_id| type_registry.get_type_info(type_id))
+        else {
+            continue;
+        };
+
+        let type_path_table = type_info.type_path_table();
+        if type_path_table.crate_name() != Some("bevy_state") {
+            continue;
+        }
+        let is_next_state = match type_path_table.ident() {
+            Some("State") => false,
+            Some("NextState") => true,
+            _ => continue,
+        };
+        let Some(state_type) = type_info.generics().get_named("S") else {
+            continue;
+        };
+
+        let resources = states.entry(state_type.type_id()).or_default();
+        resources.name = Some(state_type.ty().path());
+        if is_next_state {
+            resources.next_state = Some(component_info.id());
+        } else {
+            resources.state = Some(component_info.id());
+        }
+    }
+
+    states
+}
+
+/// Reflects the resource identified by `component_id`.
+fn reflect_resource(world: &World, component_id: ComponentId) -> Option<&dyn Reflect> {
+    let type_id = world.components().get_info(component_id)?.type_id()?;
+    let entity = world.resource_entities().get(component_id)?;
+    world.get_reflect(entity, type_id).ok()
+}
+
+/// Returns the names of the unit variants of a state type.
+///
+/// This is empty if the state type is not an enum.
+fn unit_variants(type_info: &TypeInfo) -> Vec<String> {
+    let Ok(enum_info) = type_info.as_enum() else {
+        return Vec::new();
+    };
+    enum_info
+        .iter()
+        .filter(|variant| matches!(variant, VariantInfo::Unit(_)))
+        .map(|variant| variant.name().to_string())
+        .collect()
+}
+
+/// The recent transitions of each state type, recorded by the [`StateInspectionPlugin`].
+#[derive(Resource, Debug)]
+pub struct StateTransitionHistory {
+    /// The maximum number of transitions recorded for each state type.
+    ///
+    /// Defaults to 32.
+    pub capacity: usize,
+    /// Keyed by the [`TypeId`] of the state type.
+    states: HashMap<TypeId, RecordedState>,
+}
+
+impl Default for StateTransitionHistory {
+    fn default() -> Self {
+        Self {
+            capacity: 32,
+            states: HashMap::default(),
+        }
+    }
+}
+
+/// The history of a single state type.
+#[derive(Debug, Default)]
+struct RecordedState {
+    /// The value of the state when it was last observed.
+    last_seen: Option<String>,
+    transitions: VecDeque<StateTransitionRecord>,
+}
+
+impl StateTransitionHistory {
+    /// Returns the recorded transitions of the state type identified by `state_type`, oldest first.
+    pub fn transitions(
+        &self,
+        state_type: TypeId,
+    ) -> impl Iterator<Item = &StateTransitionRecord> + '_ {
+        self.states
+            .get(&state_type)
+            .into_iter()
+            .flat_map(|state| state.transitions.iter())
+    }
+
+    /// Records a transition if the `value` of the state has changed since it was last observed.
+    fn observe(&mut self, state_type: TypeId, value: Option<String>, frame: u32) {
+        let is_new = !self.states.contains_key(&state_type);
+        let state = self.states.entry(state_type).or_default();
+        if state.last_seen == value || (is_new && value.is_none()) {
+            state.last_seen = value;
+            return;
+        }
+
+        state.transitions.push_back(StateTransitionRecord {
+            frame,
+            exited: state.last_seen.take(),
+            entered: value.clone(),
+        });
+        state.last_seen = value;
+        while state.transitions.len() > self.capacity {
+            state.transitions.pop_front();
+        }
+    }
+}
+
+/// Exclusive system that records changes to the value of each state type in the [`StateTransitionHistory`].
+pub fn record_state_transitions(world: &mut World) {
+    let frame = world
+        .get_resource::<FrameCount>()
+        .map_or(0, |frame_count| frame_count.0);
+
+    let values: Vec<(TypeId, Option<String>)> = {
+        let Some(type_registry) = world.get_resource::<AppTypeRegistry>() else {
+            return;
+        };
+        let type_registry = type_registry.read();
+        type_registry
+            .iter_with_data::<ReflectState>()
+            .map(|(registration, reflect_state)| {
+                let value = reflect_state
+                    .reflect(world)
+                    .map(|value| reflected_value_to_string(value.as_partial_reflect(), false));
+                (registration.type_id(), value)
+            })
+            .collect()
+    };
+
+    let mut history = world.resource_mut::<StateTransitionHistory>();
+    for (state_type, value) in values {
+        history.observe(state_type, value, frame);
+    }
+}
+
+/// A plugin which records the transitions of every reflected state type
+/// in the [`StateTransitionHistory`] resource.
+///
+/// Transitions are detected by comparing the value of each state at the end of every frame,
+/// so several transitions during a single frame are recorded as one,
+/// and transitions to the same value are not recorded.
+///
+/// When upstreamed, this plugin should not be necessary,
+/// as the history can be recorded from each state's [`StateTransitionEvent`] messages.
+pub struct StateInspectionPlugin;
+
+impl Plugin for StateInspectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StateTransitionHistory>()
+            .add_systems(Last, record_state_transitions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension_methods::WorldInspectionExtensionTrait;
+    use bevy::state::app::StatesPlugin;
+
+    #[derive(States, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    enum GameState {
+        #[default]
+        Menu,
+        Playing,
+    }
+
+    fn app_with_state() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, StateInspectionPlugin))
+            .init_state::<GameState>()
+            .register_type_mutable_state::<GameState>();
+        app.update();
+        app
+    }
+
+    fn inspect_game_state(world: &World) -> StateInspection {
+        world
+            .inspect_states(StateInspectionSettings::default())
+            .into_iter()
+            .find(|inspection| inspection.name.shortname().to_string() == "GameState")
+            .unwrap()
+    }
+
+    #[test]
+    fn states_show_current_and_pending_values() {
+        let mut app = app_with_state();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+
+        let inspection = inspect_game_state(app.world());
+        assert_eq!(inspection.current.as_deref(), Some("GameState::Menu"));
+        assert_eq!(inspection.current_variant.as_deref(), Some("Menu"));
+        assert_eq!(inspection.pending.as_deref(), Some("GameState::Playing"));
+        assert_eq!(inspection.variants, vec!["Menu", "Playing"]);
+        assert!(inspection.can_queue_transitions);
+    }
+
+    #[test]
+    fn queued_transitions_are_applied_and_recorded() {
+        let mut app = app_with_state();
+        queue_state_transition(app.world_mut(), "GameState", "Playing").unwrap();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Playing
+        );
+
+        let history = inspect_game_state(app.world()).history;
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].exited, None);
+        assert_eq!(history[1].exited.as_deref(), Some("GameState::Menu"));
+        assert_eq!(history[1].entered.as_deref(), Some("GameState::Playing"));
+
+        assert!(matches!(
+            queue_state_transition(app.world_mut(), "GameState", "Paused"),
+            Err(StateTransitionError::UnknownVariant { .. })
+        ));
+    }
+
+    #[test]
+    fn worlds_without_a_type_registry_have_no_states() {
+        let mut world = World::new();
+        assert!(
+            StateInspection::inspect_all(&world, StateInspectionSettings::default()).is_empty()
+        );
+        assert!(matches!(
+            queue_state_transition(&mut world, "GameState", "Playing"),
+            Err(StateTransitionError::TypeRegistryMissing)
+        ));
+    }
+}
@@ -0,0 +1,432 @@
+//! Tracking how the [`World`] changes over time, by periodically sampling its [`WorldSummary`].
+//!
+//! The [`SummaryHistoryPlugin`] records a [`SummarySample`] at a regular interval
+//! into the [`SummaryHistory`] ring buffer.
+//! [`SummaryHistory::trend`] then compares these samples,
+//! reporting how quickly entities are being spawned, which archetypes were created,
+//! and which component counts have changed.
+//!
+//! Steady entity growth across many samples is flagged as a [`SummaryTrend::possible_leak`]:
+//! entities that are spawned but never despawned are a common source of slowdowns in long-running apps.
+//!
+//! [`WorldSummary`]: crate::inspection::world_summary::WorldSummary
+
+use std::{cmp::Reverse, collections::VecDeque, time::Duration};
+
+use bevy::{
+    diagnostic::FrameCount, ecs::component::ComponentId, platform::collections::HashMap, prelude::*,
+};
+use core::fmt::Display;
+
+use crate::inspection::world_summary::{SummarySettings, WorldSummaryExt};
+
+/// A snapshot of the size of the [`World`], recorded by the [`SummaryHistoryPlugin`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SummarySample {
+    /// The [`FrameCount`] when the sample was recorded.
+    pub frame: u32,
+    /// The real time elapsed since the app started when the sample was recorded.
+    pub elapsed: Duration,
+    /// The number of entities.
+    pub total_entities: u32,
+    /// The number of archetypes, empty or not.
+    pub total_archetypes: usize,
+    /// The number of entities with each component type, sorted by [`ComponentId`].
+    ///
+    /// Component types without any entities are not included.
+    pub component_counts: Vec<ComponentCount>,
+}
+
+impl SummarySample {
+    /// Samples the current size of the `world`.
+    pub fn new(world: &World, frame: u32, elapsed: Duration) -> Self {
+        let summary = world.summarize(SummarySettings {
+            include_component_names: false,
+            include_empty_archetypes: false,
+            max_archetype_rows: None,
+            ..default()
+        });
+
+        let mut counts: HashMap<ComponentId, usize> = HashMap::default();
+        for archetype_summary in &summary.archetype_summaries {
+            for component_id in &archetype_summary.component_ids {
+                *counts.entry(*component_id).or_default() += archetype_summary.entity_count;
+            }
+        }
+        let mut component_counts: Vec<ComponentCount> = counts
+            .into_iter()
+            .filter_map(|(component_id, count)| {
+                Some(ComponentCount {
+                    component_id,
+                    name: world.components().get_name(component_id)?,
+                    count,
+                })
+            })
+            .collect();
+        component_counts.sort_by_key(|component_count| component_count.component_id.index());
+
+        Self {
+            frame,
+            elapsed,
+            total_entities: summary.total_entities,
+            total_archetypes: summary.total_archetypes,
+            component_counts,
+        }
+    }
+}
+
+/// The number of entities with a component type, as part of a [`SummarySample`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComponentCount {
+    /// The id of the component type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// How many entities have this component.
+    pub count: usize,
+}
+
+/// How the [`World`] has changed across the samples in the [`SummaryHistory`].
+///
+/// Log this using the [`Display`] trait to see a report of the trend.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SummaryTrend {
+    /// The number of samples the trend was computed from.
+    pub sample_count: usize,
+    /// The real time between the oldest and the latest sample.
+    pub duration: Duration,
+    /// The change in the number of entities since the previous sample.
+    pub entity_delta: i64,
+    /// The change in the number of entities since the oldest sample.
+    pub entity_delta_over_history: i64,
+    /// The average change in the number of entities per second, since the oldest sample.
+    pub entities_per_second: f64,
+    /// The number of archetypes created since the previous sample.
+    ///
+    /// Archetypes are never removed, so this is never negative.
+    pub new_archetypes: usize,
+    /// The component types whose count changed since the previous sample,
+    /// sorted by the size of the change, largest first.
+    pub component_deltas: Vec<ComponentCountDelta>,
+    /// Whether the number of entities grew in each of the last
+    /// [`SummaryHistory::leak_detection_samples`] samples.
+    ///
+    /// This is a heuristic: apps that are still loading, or that legitimately accumulate entities,
+    /// will also be flagged.
+    pub possible_leak: bool,
+}
+
+impl Display for SummaryTrend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Entities: {:+} since last sample, {:+} over {:.1}s ({:+.2}/s)",
+            self.entity_delta,
+            self.entity_delta_over_history,
+            self.duration.as_secs_f64(),
+            self.entities_per_second
+        )?;
+        if self.new_archetypes > 0 {
+            write!(f, "\nNew archetypes: {}", self.new_archetypes)?;
+        }
+        for component_delta in &self.component_deltas {
+            write!(f, "\n{component_delta}")?;
+        }
+        if self.possible_leak {
+            write!(
+                f,
+                "\nWarning: the entity count has grown steadily, which may indicate an entity leak"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The change in the number of entities with a component type between two samples.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComponentCountDelta {
+    /// The id of the component type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// The number of entities with this component in the previous sample.
+    pub previous: usize,
+    /// The number of entities with this component in the latest sample.
+    pub current: usize,
+    /// The change in the number of entities with this component.
+    pub delta: i64,
+}
+
+impl Display for ComponentCountDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {} ({:+})",
+            self.name.shortname(),
+            self.previous,
+            self.current,
+            self.delta
+        )
+    }
+}
+
+/// The recent [`SummarySample`]s of the [`World`], recorded by the [`SummaryHistoryPlugin`].
+///
+/// To change the sampling interval or capacity,
+/// insert this resource using [`SummaryHistory::new`] before adding the plugin.
+#[derive(Resource, Debug)]
+pub struct SummaryHistory {
+    /// The maximum number of samples kept, after which the oldest samples are discarded.
+    ///
+    /// Defaults to 120.
+    pub capacity: usize,
+    /// The number of consecutive samples the entity count must grow in
+    /// for the [`SummaryTrend::possible_leak`] flag to be set.
+    ///
+    /// Defaults to 10.
+    pub leak_detection_samples: usize,
+    timer: Timer,
+    samples: VecDeque<SummarySample>,
+}
+
+impl Default for SummaryHistory {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), 120)
+    }
+}
+
+impl SummaryHistory {
+    /// Creates an empty history, which records a sample every `sample_interval`
+    /// and keeps up to `capacity` samples.
+    pub fn new(sample_interval: Duration, capacity: usize) -> Self {
+        Self {
+            capacity,
+            leak_detection_samples: 10,
+            timer: Timer::new(sample_interval, TimerMode::Repeating),
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the interval between samples.
+    pub fn sample_interval(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Sets the interval between samples.
+    pub fn set_sample_interval(&mut self, sample_interval: Duration) {
+        self.timer.set_duration(sample_interval);
+    }
+
+    /// Returns the recorded samples, oldest first.
+    pub fn samples(&self) -> impl ExactSizeIterator<Item = &SummarySample> + '_ {
+        self.samples.iter()
+    }
+
+    /// Returns the most recent sample, if any.
+    pub fn latest(&self) -> Option<&SummarySample> {
+        self.samples.back()
+    }
+
+    /// Records a sample, discarding the oldest samples if the history is full.
+    pub fn push(&mut self, sample: SummarySample) {
+        while self.samples.len() >= self.capacity.max(1) {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Removes all recorded samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Compares the recorded samples, to report how the world is changing.
+    ///
+    /// Returns `None` if fewer than two samples have been recorded.
+    pub fn trend(&self) -> Option<SummaryTrend> {
+        let sample_count = self.samples.len();
+        if sample_count < 2 {
+            return None;
+        }
+        let oldest = self.samples.front()?;
+        let previous = &self.samples[sample_count - 2];
+        let latest = self.samples.back()?;
+
+        let duration = latest.elapsed.saturating_sub(oldest.elapsed);
+        let entity_delta_over_history = latest.total_entities as i64 - oldest.total_entities as i64;
+        let entities_per_second = if duration.is_zero() {
+            0.0
+        } else {
+            entity_delta_over_history as f64 / duration.as_secs_f64()
+        };
+
+        let possible_leak = self.leak_detection_samples > 0
+            && sample_count > self.leak_detection_samples
+            && self
+                .samples
+                .iter()
+                .skip(sample_count - self.leak_detection_samples - 1)
+                .zip(
+                    self.samples
+                        .iter()
+                        .skip(sample_count - self.leak_detection_samples),
+                )
+                .all(|(before, after)| after.total_entities > before.total_entities);
+
+        Some(SummaryTrend {
+            sample_count,
+            duration,
+            entity_delta: latest.total_entities as i64 - previous.total_entities as i64,
+            entity_delta_over_history,
+            entities_per_second,
+            new_archetypes: latest
+                .total_archetypes
+                .saturating_sub(previous.total_archetypes),
+            component_deltas: component_deltas(previous, latest),
+            possible_leak,
+        })
+    }
+}
+
+/// Lists the component types whose count differs between the `previous` and `latest` samples.
+fn component_deltas(previous: &SummarySample, latest: &SummarySample) -> Vec<ComponentCountDelta> {
+    let mut counts: HashMap<ComponentId, (DebugName, usize, usize)> = HashMap::default();
+    for component_count in &previous.component_counts {
+        counts.insert(
+            component_count.component_id,
+            (component_count.name.clone(), component_count.count, 0),
+        );
+    }
+    for component_count in &latest.component_counts {
+        counts
+            .entry(component_count.component_id)
+            .or_insert_with(|| (component_count.name.clone(), 0, 0))
+            .2 = component_count.count;
+    }
+
+    let mut deltas: Vec<ComponentCountDelta> = counts
+        .into_iter()
+        .filter(|(_, (_, previous, current))| previous != current)
+        .map(
+            |(component_id, (name, previous, current))| ComponentCountDelta {
+                component_id,
+                name,
+                previous,
+                current,
+                delta: current as i64 - previous as i64,
+            },
+        )
+        .collect();
+    deltas.sort_by_key(|delta| {
+        (
+            Reverse(delta.delta.unsigned_abs()),
+            delta.component_id.index(),
+        )
+    });
+    deltas
+}
+
+/// Exclusive system that records a [`SummarySample`] in the [`SummaryHistory`]
+/// each time its sampling interval elapses.
+pub fn record_summary_samples(world: &mut World) {
+    let Some(time) = world.get_resource::<Time<Real>>() else {
+        return;
+    };
+    let (delta, elapsed) = (time.delta(), time.elapsed());
+    let frame = world
+        .get_resource::<FrameCount>()
+        .map_or(0, |frame_count| frame_count.0);
+
+    world.resource_scope(|world, mut history: Mut<SummaryHistory>| {
+        history.timer.tick(delta);
+        if history.timer.just_finished() {
+            history.push(SummarySample::new(world, frame, elapsed));
+        }
+    });
+}
+
+/// A plugin which periodically samples the [`World`] into the [`SummaryHistory`] resource,
+/// so that [`SummaryHistory::trend`] can report how it changes over time.
+///
+/// Samples are recorded at the end of the frame, using real time,
+/// so that pausing virtual time does not stop sampling.
+pub struct SummaryHistoryPlugin;
+
+impl Plugin for SummaryHistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SummaryHistory>()
+            .add_systems(Last, record_summary_samples);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Bullet;
+
+    fn sample(world: &World, seconds: u64) -> SummarySample {
+        SummarySample::new(world, seconds as u32, Duration::from_secs(seconds))
+    }
+
+    #[test]
+    fn trend_reports_entity_and_component_deltas() {
+        let mut world = World::new();
+        let mut history = SummaryHistory::new(Duration::from_secs(1), 8);
+        history.push(sample(&world, 0));
+        assert!(history.trend().is_none());
+
+        world.spawn_batch((0..4).map(|_| Bullet));
+        history.push(sample(&world, 2));
+
+        let trend = history.trend().unwrap();
+        assert_eq!(trend.entity_delta, 4);
+        assert_eq!(trend.entities_per_second, 2.0);
+        assert_eq!(trend.new_archetypes, 1);
+        assert_eq!(trend.component_deltas.len(), 1);
+        assert_eq!(
+            trend.component_deltas[0].name.shortname().to_string(),
+            "Bullet"
+        );
+        assert_eq!(trend.component_deltas[0].delta, 4);
+        assert!(!trend.possible_leak);
+    }
+
+    #[test]
+    fn steady_growth_is_flagged_as_a_possible_leak() {
+        let mut world = World::new();
+        let mut history = SummaryHistory::new(Duration::from_secs(1), 4);
+        history.leak_detection_samples = 3;
+
+        for seconds in 0..6 {
+            world.spawn(Bullet);
+            history.push(sample(&world, seconds));
+        }
+        assert_eq!(history.samples().len(), 4);
+        assert!(history.trend().unwrap().possible_leak);
+
+        history.push(sample(&world, 6));
+        assert!(!history.trend().unwrap().possible_leak);
+    }
+}
@@ -0,0 +1,533 @@
+//! Types for inspecting one-shot systems.
+//!
+//! One-shot systems are registered using [`World::register_system`],
+//! and are stored on an entity marked with [`SystemIdMarker`].
+//! Bevy only keeps track of their input and output types,
+//! so systems registered using [`OneShotSystemExtensionTrait::register_inspected_system`](crate::extension_methods::OneShotSystemExtensionTrait::register_inspected_system)
+//! also record their name, their data access and how often they have run
+//! in a [`OneShotSystemMetadata`] component.
+
+use bevy::{
+    ecs::{
+        component::ComponentId,
+        entity::EntityNotSpawnedError,
+        query::{ComponentAccessKind, FilteredAccessSet},
+        system::SystemIdMarker,
+    },
+    prelude::*,
+};
+use core::fmt::Display;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU32, Ordering},
+};
+use thiserror::Error;
+
+/// The result of inspecting a one-shot system.
+///
+/// Log this using the [`Display`] trait to see details about the system.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemInspection {
+    /// The entity that stores the system, as returned by [`SystemId::entity`](bevy::ecs::system::SystemId::entity).
+    pub entity: Entity,
+    /// The type name of the system.
+    ///
+    /// This is only known if the system has [`OneShotSystemMetadata`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::option_debug_name")
+    )]
+    pub name: Option<DebugName>,
+    /// The type name of the system's input, like `()` or `In<u32>`.
+    pub input_type: String,
+    /// The type name of the system's output, like `()`.
+    pub output_type: String,
+    /// The components and resources that the system reads and writes.
+    ///
+    /// This is only known if the system has [`OneShotSystemMetadata`].
+    pub access: Option<SystemAccessInspection>,
+    /// The number of times the system has run.
+    ///
+    /// This is only known if the system has [`OneShotSystemMetadata`].
+    pub run_count: Option<u32>,
+}
+
+impl SystemInspection {
+    /// Returns true if the system can be run without providing any input,
+    /// using [`OneShotSystemExtensionTrait::run_one_shot_system`](crate::extension_methods::OneShotSystemExtensionTrait::run_one_shot_system).
+    pub fn is_runnable(&self) -> bool {
+        self.input_type == "()" && self.output_type == "()"
+    }
+}
+
+impl Display for SystemInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "System {} ({})", name.shortname(), self.entity)?,
+            None => write!(f, "System ({})", self.entity)?,
+        }
+        write!(
+            f,
+            "\nSignature: {} -> {}",
+            self.input_type, self.output_type
+        )?;
+
+        if let Some(access) = &self.access {
+            write!(f, "\nAccess: {access}")?;
+        }
+        if let Some(run_count) = self.run_count {
+            write!(f, "\nRuns: {run_count}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The data accessed by a system, resolved to type names.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemAccessInspection {
+    /// The components and resources that the system only reads.
+    pub reads: Vec<AccessedType>,
+    /// The components and resources that the system writes.
+    pub writes: Vec<AccessedType>,
+    /// Whether the system reads every component and resource,
+    /// such as systems with a `Query<EntityRef>` or taking `&World`.
+    ///
+    /// In this case, [`Self::reads`] is empty, while [`Self::writes`] still lists the written types.
+    pub reads_all: bool,
+    /// Whether the system has access to the whole world,
+    /// such as exclusive systems taking `&mut World`.
+    ///
+    /// In this case, [`Self::reads`] and [`Self::writes`] are empty.
+    pub is_exclusive: bool,
+}
+
+impl Display for SystemAccessInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_exclusive {
+            return write!(f, "exclusive world access");
+        }
+
+        let names = |types: &[AccessedType]| -> String {
+            let names: Vec<String> = types.iter().map(ToString::to_string).collect();
+            names.join(", ")
+        };
+        if self.reads_all {
+            write!(f, "reads everything, writes [{}]", names(&self.writes))
+        } else {
+            write!(
+                f,
+                "reads [{}], writes [{}]",
+                names(&self.reads),
+                names(&self.writes)
+            )
+        }
+    }
+}
+
+/// How a system accesses a component or resource type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccessKind {
+    /// The type is only read.
+    Read,
+    /// The type is written.
+    Write,
+}
+
+/// A component or resource type accessed by a system.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccessedType {
+    /// The [`ComponentId`] of the type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// Whether the type is a resource, rather than a component.
+    pub is_resource: bool,
+}
+
+impl Display for AccessedType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_resource {
+            write!(f, "Res<{}>", self.name.shortname())
+        } else {
+            write!(f, "{}", self.name.shortname())
+        }
+    }
+}
+
+/// Information about a one-shot system which Bevy does not record itself.
+///
+/// This is added to the system's entity by
+/// [`OneShotSystemExtensionTrait::register_inspected_system`](crate::extension_methods::OneShotSystemExtensionTrait::register_inspected_system).
+#[derive(Component, Clone, Debug)]
+pub struct OneShotSystemMetadata {
+    /// The type name of the system.
+    pub name: DebugName,
+    /// The components and resources that the system only reads.
+    pub reads: Vec<ComponentId>,
+    /// The components and resources that the system writes.
+    pub writes: Vec<ComponentId>,
+    /// Whether the system reads every component and resource.
+    pub reads_all: bool,
+    /// Whether the system has access to the whole world.
+    pub is_exclusive: bool,
+    /// The number of times the system has run.
+    ///
+    /// This is shared with the registered system, which increments it after each run.
+    pub run_count: Arc<AtomicU32>,
+}
+
+impl OneShotSystemMetadata {
+    /// Records the data access of an initialized system.
+    pub fn new(name: DebugName, access: &FilteredAccessSet, run_count: Arc<AtomicU32>) -> Self {
+        let SplitAccess {
+            reads,
+            writes,
+            reads_all,
+            is_exclusive,
+        } = split_access(access);
+
+        Self {
+            name,
+            reads,
+            writes,
+            reads_all,
+            is_exclusive,
+            run_count,
+        }
+    }
+
+    /// Resolves the recorded access into type names.
+    pub fn access(&self, world: &World) -> SystemAccessInspection {
+        SystemAccessInspection {
+            reads: resolve_accessed_types(world, &self.reads),
+            writes: resolve_accessed_types(world, &self.writes),
+            reads_all: self.reads_all,
+            is_exclusive: self.is_exclusive,
+        }
+    }
+
+    /// Returns how the system accesses the component or resource with the provided `component_id`,
+    /// or `None` if it is not accessed.
+    ///
+    /// See [`SystemAccessInspection::access_kind`] for more details.
+    pub fn access_kind(&self, component_id: ComponentId) -> Option<AccessKind> {
+        if self.writes.contains(&component_id) {
+            Some(AccessKind::Write)
+        } else if self.reads_all || self.reads.contains(&component_id) {
+            Some(AccessKind::Read)
+        } else {
+            None
+        }
+    }
+}
+
+impl SystemAccessInspection {
+    /// Resolves the access returned by [`System::initialize`] into type names.
+    pub fn new(world: &World, access: &FilteredAccessSet) -> Self {
+        let SplitAccess {
+            reads,
+            writes,
+            reads_all,
+            is_exclusive,
+        } = split_access(access);
+
+        Self {
+            reads: resolve_accessed_types(world, &reads),
+            writes: resolve_accessed_types(world, &writes),
+            reads_all,
+            is_exclusive,
+        }
+    }
+
+    /// Returns how the system accesses the component or resource with the provided `component_id`,
+    /// or `None` if it is not accessed.
+    ///
+    /// Systems which read every component and resource return [`AccessKind::Read`] for any type they do not write.
+    /// Systems with exclusive world access always return `None`, as their access is not tracked per type.
+    pub fn access_kind(&self, component_id: ComponentId) -> Option<AccessKind> {
+        let contains = |types: &[AccessedType]| {
+            types
+                .iter()
+                .any(|accessed| accessed.component_id == component_id)
+        };
+
+        if contains(&self.writes) {
+            Some(AccessKind::Write)
+        } else if self.reads_all || contains(&self.reads) {
+            Some(AccessKind::Read)
+        } else {
+            None
+        }
+    }
+}
+
+/// The access of a system, split by how each type is accessed.
+struct SplitAccess {
+    /// The ids that are only read.
+    reads: Vec<ComponentId>,
+    /// The ids that are written.
+    writes: Vec<ComponentId>,
+    /// Whether every component and resource is read, in which case `reads` is empty.
+    reads_all: bool,
+    /// Whether the access covers the whole world, in which case `reads` and `writes` are empty.
+    is_exclusive: bool,
+}
+
+/// Splits the `access` into the ids that are only read and the ids that are written.
+fn split_access(access: &FilteredAccessSet) -> SplitAccess {
+    let combined_access = access.combined_access();
+    let mut split = SplitAccess {
+        reads: Vec::new(),
+        writes: Vec::new(),
+        reads_all: false,
+        is_exclusive: combined_access.has_write_all(),
+    };
+    if split.is_exclusive {
+        return split;
+    }
+
+    match combined_access.try_iter_access() {
+        Ok(accesses) => {
+            for access in accesses {
+                match access {
+                    ComponentAccessKind::Shared(component_id) => split.reads.push(component_id),
+                    ComponentAccessKind::Exclusive(component_id) => split.writes.push(component_id),
+                    // Archetypal access does not read any data
+                    ComponentAccessKind::Archetypal(_) => {}
+                }
+            }
+        }
+        // Unbounded access, such as `Query<EntityRef>`, reads every component
+        Err(_) => {
+            split.reads_all = true;
+            if let Ok(writes) = combined_access.try_writes() {
+                split.writes.extend(writes.iter());
+            }
+        }
+    }
+    split
+}
+
+/// Returns how a system with the provided `access` accesses the component or resource
+/// with the provided `component_id`, without splitting or resolving the rest of its access.
+///
+/// As with [`SystemAccessInspection::access_kind`], systems with exclusive world access return `None`.
+pub(crate) fn access_kind_by_id(
+    access: &FilteredAccessSet,
+    component_id: ComponentId,
+) -> Option<AccessKind> {
+    let combined_access = access.combined_access();
+    if combined_access.has_write_all() {
+        None
+    } else if combined_access.has_write(component_id) {
+        Some(AccessKind::Write)
+    } else if combined_access.has_read(component_id) {
+        Some(AccessKind::Read)
+    } else {
+        None
+    }
+}
+
+/// Looks up the name of each of the `component_ids`.
+fn resolve_accessed_types(world: &World, component_ids: &[ComponentId]) -> Vec<AccessedType> {
+    component_ids
+        .iter()
+        .map(|component_id| AccessedType {
+            component_id: *component_id,
+            name: world
+                .components()
+                .get_name(*component_id)
+                .unwrap_or_else(|| DebugName::owned(format!("{component_id:?}"))),
+            is_resource: world.resource_entities().get(*component_id).is_some(),
+        })
+        .collect()
+}
+
+/// An error that can occur when attempting to inspect a one-shot system.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SystemInspectionError {
+    /// The entity does not exist in the world.
+    #[error("Entity not found: {0}")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    EntityNotFound(EntityNotSpawnedError),
+    /// The entity exists, but is not a one-shot system.
+    #[error("Entity {0} is not a one-shot system")]
+    NotASystem(Entity),
+}
+
+/// An error that can occur when running a one-shot system on demand.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RunOneShotSystemError {
+    /// The system could not be inspected.
+    #[error(transparent)]
+    Inspection(#[from] SystemInspectionError),
+    /// Only systems without input or output can be run on demand.
+    #[error("Cannot run system with signature {input_type} -> {output_type}: expected () -> ()")]
+    UnsupportedSignature {
+        /// The type name of the system's input.
+        input_type: String,
+        /// The type name of the system's output.
+        output_type: String,
+    },
+    /// The system failed to run, for example because it is already running.
+    #[error("Failed to run system: {0}")]
+    Failed(String),
+}
+
+impl SystemInspection {
+    /// Inspects the one-shot system stored on the provided `entity`.
+    pub fn new(world: &World, entity: Entity) -> Result<Self, SystemInspectionError> {
+        let entity_ref = world
+            .get_entity(entity)
+            .map_err(SystemInspectionError::EntityNotFound)?;
+        if !entity_ref.contains::<SystemIdMarker>() {
+            return Err(SystemInspectionError::NotASystem(entity));
+        }
+
+        // The system is stored in a `RegisteredSystem<I, O>` component, whose type name records the signature.
+        // This component is briefly removed while the system is running.
+        let (input_type, output_type) = entity_ref
+            .archetype()
+            .components()
+            .iter()
+            .filter_map(|component_id| world.components().get_name(*component_id))
+            .find_map(|name| registered_system_signature(&name.to_string()))
+            .unwrap_or_else(|| ("?".to_string(), "?".to_string()));
+
+        let metadata = entity_ref.get::<OneShotSystemMetadata>();
+        Ok(Self {
+            entity,
+            name: metadata.map(|metadata| metadata.name.clone()),
+            input_type,
+            output_type,
+            access: metadata.map(|metadata| metadata.access(world)),
+            run_count: metadata.map(|metadata| metadata.run_count.load(Ordering::Relaxed)),
+        })
+    }
+}
+
+/// Extracts the input and output type names from the type name of a `RegisteredSystem<I, O>` component.
+fn registered_system_signature(type_name: &str) -> Option<(String, String)> {
+    let generics = type_name
+        .split_once("RegisteredSystem<")?
+        .1
+        .strip_suffix('>')?;
+
+    // Split on the comma that is not nested inside of another type
+    let mut depth = 0i32;
+    for (index, c) in generics.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                let input = ShortName::from(generics[..index].trim()).to_string();
+                let output = ShortName::from(generics[index + 1..].trim()).to_string();
+                return Some((input, output));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension_methods::OneShotSystemExtensionTrait;
+    use bevy::ecs::resource::IsResource;
+
+    #[derive(Resource, Default)]
+    struct Score(u32);
+
+    #[derive(Component)]
+    struct Player;
+
+    fn add_score(mut score: ResMut<Score>, players: Query<&Player>) {
+        score.0 += players.iter().count() as u32;
+    }
+
+    #[test]
+    fn signatures_are_parsed_from_type_names() {
+        assert_eq!(
+            registered_system_signature(
+                "bevy_ecs::system::system_registry::RegisteredSystem<(), ()>"
+            ),
+            Some(("()".to_string(), "()".to_string()))
+        );
+        assert_eq!(
+            registered_system_signature(
+                "bevy_ecs::system::system_registry::RegisteredSystem<bevy_ecs::system::input::In<(u32, f32)>, core::option::Option<u8>>"
+            ),
+            Some(("In<(u32, f32)>".to_string(), "Option<u8>".to_string()))
+        );
+    }
+
+    #[test]
+    fn inspected_systems_record_access_and_runs() {
+        let mut world = World::new();
+        world.init_resource::<Score>();
+        world.spawn(Player);
+        let system = world.register_inspected_system(add_score);
+
+        world.run_one_shot_system(system.entity()).unwrap();
+        world.run_one_shot_system(system.entity()).unwrap();
+
+        let inspection = SystemInspection::new(&world, system.entity()).unwrap();
+        let access = inspection.access.as_ref().unwrap();
+        assert!(inspection.is_runnable());
+        assert_eq!(inspection.run_count, Some(2));
+        assert_eq!(access.reads.len(), 1);
+        assert_eq!(access.reads[0].name.shortname().to_string(), "Player");
+        assert_eq!(access.writes.len(), 1);
+        assert!(access.writes[0].is_resource);
+        assert_eq!(world.resource::<Score>().0, 2);
+    }
+
+    #[test]
+    fn systems_reading_every_entity_are_not_exclusive() {
+        let mut world = World::new();
+        let player = world.register_component::<Player>();
+        let system = world.register_inspected_system(
+            |entities: Query<EntityRef, Without<IsResource>>, mut score: ResMut<Score>| {
+                score.0 += entities.iter().count() as u32;
+            },
+        );
+
+        let inspection = SystemInspection::new(&world, system.entity()).unwrap();
+        let access = inspection.access.as_ref().unwrap();
+        assert!(access.reads_all);
+        assert!(!access.is_exclusive);
+        assert_eq!(access.access_kind(player), Some(AccessKind::Read));
+        let score = world.component_id::<Score>().unwrap();
+        assert_eq!(access.access_kind(score), Some(AccessKind::Write));
+    }
+
+    #[test]
+    fn systems_with_input_cannot_be_run() {
+        let mut world = World::new();
+        let system = world.register_system(|In(_): In<u32>| {});
+
+        let inspection = SystemInspection::new(&world, system.entity()).unwrap();
+        assert_eq!(inspection.input_type, "In<u32>");
+        assert!(inspection.name.is_none());
+        assert!(matches!(
+            world.run_one_shot_system(system.entity()),
+            Err(RunOneShotSystemError::UnsupportedSignature { .. })
+        ));
+    }
+}
@@ -0,0 +1,348 @@
+//! Inspecting the [`TypeRegistry`](bevy::reflect::TypeRegistry), and auditing which types in the world are missing from it.
+//!
+//! Most of this crate relies on reflection to read and edit values,
+//! so component and resource types that are not registered in the [`AppTypeRegistry`]
+//! can only be shown by name: their [`ComponentTypeMetadata::type_registration`] is `None`.
+//!
+//! [`inspect_registered_types`] lists every registered type and the reflected type data it provides,
+//! while [`find_missing_registrations`] lists the component and resource types present in the world
+//! that have no registration, along with a hint on how to register them.
+//!
+//! [`ComponentTypeMetadata::type_registration`]: crate::inspection::component_inspection::ComponentTypeMetadata::type_registration
+
+use core::any::TypeId;
+use core::fmt::Display;
+
+use bevy::{
+    ecs::{
+        component::ComponentId,
+        reflect::{ReflectEvent, ReflectFromWorld, ReflectMapEntities},
+        resource::IsResource,
+    },
+    platform::collections::HashMap,
+    prelude::*,
+    reflect::{
+        ReflectDeserialize, ReflectFromPtr, ReflectFromReflect, ReflectSerialize, TypeData,
+        TypeRegistration,
+    },
+};
+
+/// The result of inspecting a single type in the [`TypeRegistry`](bevy::reflect::TypeRegistry).
+///
+/// Log this using the [`Display`] trait to see the type and its type data.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisteredTypeInspection {
+    /// The full path of the type.
+    pub type_path: String,
+    /// The path of the type, without its module path.
+    pub short_type_path: String,
+    /// The kind of the type, such as `Struct` or `Enum`.
+    pub kind: String,
+    /// The names of the well-known reflected type data registered for the type,
+    /// such as `ReflectComponent` or `ReflectDefault`.
+    pub type_data: Vec<String>,
+    /// The number of registered type data which are not listed in [`Self::type_data`],
+    /// typically from other crates.
+    pub other_type_data: usize,
+}
+
+impl RegisteredTypeInspection {
+    /// Inspects a single `registration` from the [`TypeRegistry`](bevy::reflect::TypeRegistry).
+    pub fn new(registration: &TypeRegistration) -> Self {
+        let type_info = registration.type_info();
+        let type_data: Vec<String> = known_type_data()
+            .into_iter()
+            .filter(|(type_id, _)| registration.contains_by_id(*type_id))
+            .map(|(_, name)| name.to_string())
+            .collect();
+
+        Self {
+            type_path: type_info.type_path().to_string(),
+            short_type_path: type_info.type_path_table().short_path().to_string(),
+            kind: format!("{:?}", type_info.kind()),
+            other_type_data: registration.len() - type_data.len(),
+            type_data,
+        }
+    }
+
+    /// Returns `true` if the type registers the type data with the given name, such as `ReflectComponent`.
+    pub fn has_type_data(&self, name: &str) -> bool {
+        self.type_data.iter().any(|type_data| type_data == name)
+    }
+}
+
+impl Display for RegisteredTypeInspection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.short_type_path, self.kind)?;
+        if !self.type_data.is_empty() {
+            write!(f, " [{}]", self.type_data.join(", "))?;
+        }
+        if self.other_type_data > 0 {
+            write!(f, " +{} more", self.other_type_data)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the well-known type data listed by name in [`RegisteredTypeInspection::type_data`].
+fn known_type_data() -> [(TypeId, &'static str); 10] {
+    fn named<T: TypeData>(name: &'static str) -> (TypeId, &'static str) {
+        (TypeId::of::<T>(), name)
+    }
+
+    [
+        named::<ReflectComponent>("ReflectComponent"),
+        named::<ReflectResource>("ReflectResource"),
+        named::<ReflectEvent>("ReflectEvent"),
+        named::<ReflectDefault>("ReflectDefault"),
+        named::<ReflectFromWorld>("ReflectFromWorld"),
+        named::<ReflectSerialize>("ReflectSerialize"),
+        named::<ReflectDeserialize>("ReflectDeserialize"),
+        named::<ReflectFromReflect>("ReflectFromReflect"),
+        named::<ReflectFromPtr>("ReflectFromPtr"),
+        named::<ReflectMapEntities>("ReflectMapEntities"),
+    ]
+}
+
+/// Inspects every type in the [`AppTypeRegistry`], sorted by type path.
+///
+/// Returns an empty list if the world has no [`AppTypeRegistry`].
+pub fn inspect_registered_types(world: &World) -> Vec<RegisteredTypeInspection> {
+    let Some(app_type_registry) = world.get_resource::<AppTypeRegistry>() else {
+        return Vec::new();
+    };
+    let mut inspections: Vec<RegisteredTypeInspection> = app_type_registry
+        .read()
+        .iter()
+        .map(RegisteredTypeInspection::new)
+        .collect();
+    inspections.sort_by(|a, b| a.type_path.cmp(&b.type_path));
+    inspections
+}
+
+/// How a type missing from the [`TypeRegistry`](bevy::reflect::TypeRegistry) is stored in the world.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MissingRegistrationKind {
+    /// The type is a component on at least one entity.
+    Component,
+    /// The type is a resource.
+    Resource,
+    /// The type is a non-`Send` resource.
+    NonSendResource,
+}
+
+/// A component or resource type present in the world which is not registered in the [`TypeRegistry`](bevy::reflect::TypeRegistry),
+/// found by [`find_missing_registrations`].
+///
+/// Log this using the [`Display`] trait to see the type and how to register it.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MissingRegistration {
+    /// The id of the component or resource type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the component or resource.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// How the type is stored in the world.
+    pub kind: MissingRegistrationKind,
+    /// The number of entities with this component.
+    ///
+    /// This is always 1 for resources.
+    pub entity_count: usize,
+    /// Whether the type is generic.
+    ///
+    /// Generic types are not registered automatically,
+    /// and must be registered for each set of type parameters that is used.
+    pub is_generic: bool,
+    /// A suggestion on how to register the type.
+    pub hint: String,
+}
+
+impl MissingRegistration {
+    fn new(
+        component_id: ComponentId,
+        name: DebugName,
+        kind: MissingRegistrationKind,
+        entity_count: usize,
+    ) -> Self {
+        let type_name = name.shortname().to_string();
+        let is_generic = type_name.contains('<');
+        let reflect_trait = match kind {
+            MissingRegistrationKind::Component => "Component",
+            MissingRegistrationKind::Resource | MissingRegistrationKind::NonSendResource => {
+                "Resource"
+            }
+        };
+        let hint = if is_generic {
+            format!(
+                "Derive `Reflect` with `#[reflect({reflect_trait})]`, \
+                then call `app.register_type::<{type_name}>()`, \
+                as generic types are not registered automatically"
+            )
+        } else {
+            format!(
+                "Derive `Reflect` with `#[reflect({reflect_trait})]` on `{type_name}`, \
+                or call `app.register_type::<{type_name}>()` if automatic registration is disabled"
+            )
+        };
+
+        Self {
+            component_id,
+            name,
+            kind,
+            entity_count,
+            is_generic,
+            hint,
+        }
+    }
+}
+
+impl Display for MissingRegistration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            MissingRegistrationKind::Component => {
+                format!("component on {} entities", self.entity_count)
+            }
+            MissingRegistrationKind::Resource => "resource".to_string(),
+            MissingRegistrationKind::NonSendResource => "non-Send resource".to_string(),
+        };
+        write!(f, "{} ({kind}): {}", self.name.shortname(), self.hint)
+    }
+}
+
+/// Lists every component and resource type present in the world
+/// which is not registered in the [`AppTypeRegistry`], sorted by name.
+///
+/// Types without a Rust type, such as dynamic components, cannot be registered and are not listed.
+pub fn find_missing_registrations(world: &World) -> Vec<MissingRegistration> {
+    let app_type_registry = world.get_resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.map(|registry| registry.read());
+    let is_registered = |component_id: ComponentId| {
+        let type_id = world
+            .components()
+            .get_info(component_id)
+            .and_then(|info| info.type_id());
+        match type_id {
+            Some(type_id) => type_registry
+                .as_ref()
+                .is_some_and(|registry| registry.contains(type_id)),
+            // Dynamic components have nothing to register
+            None => true,
+        }
+    };
+
+    // Resources are stored as components on entities marked with `IsResource`
+    let resource_component_id = world.components().component_id::<IsResource>();
+    let mut counts: HashMap<ComponentId, (MissingRegistrationKind, usize)> = HashMap::default();
+    for archetype in world.archetypes().iter().filter(|a| !a.is_empty()) {
+        let is_resource =
+            resource_component_id.is_some_and(|component_id| archetype.contains(component_id));
+        for &component_id in archetype.components() {
+            if Some(component_id) == resource_component_id {
+                continue;
+            }
+            let kind = if is_resource {
+                MissingRegistrationKind::Resource
+            } else {
+                MissingRegistrationKind::Component
+            };
+            counts.entry(component_id).or_insert((kind, 0)).1 += archetype.len() as usize;
+        }
+    }
+    for (component_id, _data) in world.storages().non_sends.iter() {
+        if world.contains_non_send_by_id(component_id) {
+            counts.insert(component_id, (MissingRegistrationKind::NonSendResource, 1));
+        }
+    }
+
+    let mut missing: Vec<MissingRegistration> = counts
+        .into_iter()
+        .filter(|(component_id, _)| !is_registered(*component_id))
+        .filter_map(|(component_id, (kind, entity_count))| {
+            let name = world.components().get_name(component_id)?;
+            Some(MissingRegistration::new(
+                component_id,
+                name,
+                kind,
+                entity_count,
+            ))
+        })
+        .collect();
+    missing.sort_by_cached_key(|missing| missing.name.to_string());
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Registered;
+
+    #[derive(Component)]
+    struct Unregistered;
+
+    #[derive(Component)]
+    struct Wrapper<T>(T);
+
+    #[derive(Resource)]
+    struct UnregisteredResource;
+
+    fn test_world() -> World {
+        let mut world = World::new();
+        let app_type_registry = AppTypeRegistry::default();
+        app_type_registry.write().register::<Registered>();
+        world.insert_resource(app_type_registry);
+        world
+    }
+
+    #[test]
+    fn registered_types_list_their_type_data() {
+        let world = test_world();
+        let inspections = inspect_registered_types(&world);
+        let registered = inspections
+            .iter()
+            .find(|inspection| inspection.short_type_path == "Registered")
+            .unwrap();
+        assert!(registered.has_type_data("ReflectComponent"));
+        assert!(registered.has_type_data("ReflectDefault"));
+        assert!(!registered.has_type_data("ReflectResource"));
+    }
+
+    #[test]
+    fn unregistered_components_and_resources_are_audited() {
+        let mut world = test_world();
+        world.spawn((Registered, Unregistered));
+        world.spawn((Unregistered, Wrapper(1_u32)));
+        world.insert_resource(UnregisteredResource);
+
+        let missing = find_missing_registrations(&world);
+        let find = |short_name: &str| {
+            missing
+                .iter()
+                .find(|missing| missing.name.shortname().to_string() == short_name)
+        };
+        assert!(find("Registered").is_none());
+
+        let unregistered = find("Unregistered").unwrap();
+        assert_eq!(unregistered.kind, MissingRegistrationKind::Component);
+        assert_eq!(unregistered.entity_count, 2);
+        assert!(!unregistered.is_generic);
+
+        assert!(find("Wrapper<u32>").unwrap().is_generic);
+        assert_eq!(
+            find("UnregisteredResource").unwrap().kind,
+            MissingRegistrationKind::Resource
+        );
+    }
+}
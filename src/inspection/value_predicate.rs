@@ -0,0 +1,458 @@
+//! Predicates over the reflected values of components, like `Transform.translation.y < 0`.
+//!
+//! A [`ValuePredicate`] follows a [reflection path](bevy::reflect::GetPath) into a component,
+//! and compares the value it finds there with a literal [`PredicateValue`].
+//! Entities that do not have the component, or whose component is not reflected,
+//! never match a predicate.
+//!
+//! Predicates can be added to [`MultipleEntityInspectionSettings::value_predicates`](crate::inspection::entity_inspection::MultipleEntityInspectionSettings::value_predicates) directly,
+//! or written in an [`EntityQuery`](crate::inspection::entity_query::EntityQuery) using the `where:` filter.
+//! In textual form, a predicate is a component name, followed by a path, an operator and a value:
+//!
+//! ```text
+//! Transform.translation.y < 0
+//! Health.0 == 0
+//! Visibility == Hidden
+//! Name == "Duck"
+//! ```
+
+use bevy::{
+    ecs::component::ComponentId,
+    prelude::*,
+    reflect::{ParsedPath, PartialReflect, ReflectPath, ReflectRef},
+};
+use core::cmp::Ordering;
+use core::fmt::Display;
+use thiserror::Error;
+
+/// A comparison between a reflected field of a component and a literal value.
+///
+/// Construct this using [`ValuePredicate::new`], which checks that the path is valid,
+/// or by resolving a [`ValuePredicateExpression`] as part of an [`EntityQuery`](crate::inspection::entity_query::EntityQuery).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValuePredicate {
+    /// The [`ComponentId`] of the component to read.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The reflection path to the compared field, like `.translation.y`.
+    ///
+    /// An empty path compares the component itself.
+    pub path: String,
+    /// How the field is compared to [`Self::value`].
+    pub operator: ComparisonOperator,
+    /// The value to compare the field with.
+    pub value: PredicateValue,
+}
+
+impl ValuePredicate {
+    /// Creates a new [`ValuePredicate`], checking that `path` is a valid reflection path.
+    pub fn new(
+        component_id: ComponentId,
+        path: impl Into<String>,
+        operator: ComparisonOperator,
+        value: PredicateValue,
+    ) -> Result<Self, ValuePredicateParseError> {
+        let path = path.into();
+        validate_path(&path)?;
+
+        Ok(Self {
+            component_id,
+            path,
+            operator,
+            value,
+        })
+    }
+
+    /// Checks whether the given entity has the component, and its field satisfies this predicate.
+    pub fn matches(&self, world: &World, entity: Entity) -> bool {
+        let Some(type_id) = world
+            .components()
+            .get_info(self.component_id)
+            .and_then(|info| info.type_id())
+        else {
+            return false;
+        };
+
+        world
+            .get_reflect(entity, type_id)
+            .is_ok_and(|reflected| self.evaluate(reflected.as_partial_reflect()))
+    }
+
+    /// Checks whether the reflected component value satisfies this predicate.
+    ///
+    /// Returns `false` if the path does not exist, or the field cannot be compared with [`Self::value`].
+    pub fn evaluate(&self, component: &dyn PartialReflect) -> bool {
+        let Ok(field) = self.path.as_str().reflect_element(component) else {
+            return false;
+        };
+
+        self.value
+            .compare(field)
+            .is_some_and(|ordering| self.operator.accepts(ordering))
+    }
+}
+
+/// How a reflected field is compared to a [`PredicateValue`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComparisonOperator {
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    LessThan,
+    /// `<=`
+    LessThanOrEqual,
+    /// `>`
+    GreaterThan,
+    /// `>=`
+    GreaterThanOrEqual,
+}
+
+impl ComparisonOperator {
+    /// All operators, with longer symbols first so that they can be matched greedily.
+    const ALL: [Self; 6] = [
+        Self::Equal,
+        Self::NotEqual,
+        Self::LessThanOrEqual,
+        Self::GreaterThanOrEqual,
+        Self::LessThan,
+        Self::GreaterThan,
+    ];
+
+    /// The symbol used for this operator in textual predicates.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+        }
+    }
+
+    /// Returns true if `field.cmp(value)` returning `ordering` satisfies this operator.
+    pub fn accepts(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Equal => ordering.is_eq(),
+            Self::NotEqual => ordering.is_ne(),
+            Self::LessThan => ordering.is_lt(),
+            Self::LessThanOrEqual => ordering.is_le(),
+            Self::GreaterThan => ordering.is_gt(),
+            Self::GreaterThanOrEqual => ordering.is_ge(),
+        }
+    }
+}
+
+impl Display for ComparisonOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// A literal value that reflected fields are compared with.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PredicateValue {
+    /// Compared with any primitive number type.
+    Number(f64),
+    /// Compared with `bool` fields.
+    Bool(bool),
+    /// Compared with strings, [`Name`]s, and the variant names of enums.
+    Text(String),
+}
+
+impl PredicateValue {
+    /// Parses a literal: `true` and `false` are booleans, anything that looks like a number is a number,
+    /// and everything else (optionally wrapped in double quotes) is text.
+    pub fn parse(literal: &str) -> Self {
+        let literal = literal.trim();
+        if let Some(text) = literal
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+        {
+            return Self::Text(text.replace("\\\"", "\""));
+        }
+
+        match literal {
+            "true" => Self::Bool(true),
+            "false" => Self::Bool(false),
+            _ => literal
+                .parse()
+                .map_or_else(|_| Self::Text(literal.to_string()), Self::Number),
+        }
+    }
+
+    /// Compares the reflected `field` with this value.
+    ///
+    /// Returns `None` if the field is of an incompatible type.
+    pub fn compare(&self, field: &dyn PartialReflect) -> Option<Ordering> {
+        match self {
+            Self::Number(value) => reflected_number(field)?.partial_cmp(value),
+            Self::Bool(value) => Some(field.try_downcast_ref::<bool>()?.cmp(value)),
+            Self::Text(value) => Some(reflected_text(field)?.cmp(value.as_str())),
+        }
+    }
+}
+
+impl Display for PredicateValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            // Text that would otherwise be parsed as a number or a boolean must be quoted
+            Self::Text(value)
+                if !value.is_empty() && Self::parse(value) == Self::Text(value.clone()) =>
+            {
+                write!(f, "{value}")
+            }
+            Self::Text(value) => write!(f, "\"{}\"", value.replace('"', "\\\"")),
+        }
+    }
+}
+
+/// Reads any primitive number type as an [`f64`].
+fn reflected_number(field: &dyn PartialReflect) -> Option<f64> {
+    macro_rules! try_number {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = field.try_downcast_ref::<$ty>() {
+                    return Some(*value as f64);
+                }
+            )*
+        };
+    }
+
+    try_number!(
+        f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+    );
+    None
+}
+
+/// Reads strings, [`Name`]s and enum variant names as text.
+fn reflected_text(field: &dyn PartialReflect) -> Option<&str> {
+    if let Some(value) = field.try_downcast_ref::<String>() {
+        return Some(value);
+    }
+    if let Some(value) = field.try_downcast_ref::<Name>() {
+        return Some(value.as_str());
+    }
+    if let ReflectRef::Enum(dyn_enum) = field.reflect_ref() {
+        return Some(dyn_enum.variant_name());
+    }
+    None
+}
+
+fn validate_path(path: &str) -> Result<(), ValuePredicateParseError> {
+    ParsedPath::parse(path)
+        .map(|_| ())
+        .map_err(|error| ValuePredicateParseError::InvalidPath {
+            path: path.to_string(),
+            reason: error.to_string(),
+        })
+}
+
+/// A textual [`ValuePredicate`] whose component name has not yet been resolved to a [`ComponentId`].
+///
+/// This is produced when parsing the `where:` filter of an [`EntityQuery`](crate::inspection::entity_query::EntityQuery).
+/// The [`Display`] implementation produces a string that parses back into the same expression.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValuePredicateExpression {
+    /// The name of the component to read, like `Transform`.
+    pub component: String,
+    /// The reflection path to the compared field, like `.translation.y`.
+    pub path: String,
+    /// How the field is compared to the value.
+    pub operator: ComparisonOperator,
+    /// The value to compare the field with.
+    pub value: PredicateValue,
+}
+
+impl ValuePredicateExpression {
+    /// Parses an expression like `Transform.translation.y < 0`.
+    ///
+    /// The component name ends at the first `.`, `[` or `#`, where the reflection path starts.
+    pub fn parse(expression: &str) -> Result<Self, ValuePredicateParseError> {
+        let operator_start = expression
+            .find(['=', '!', '<', '>'])
+            .ok_or(ValuePredicateParseError::MissingOperator)?;
+        let (lhs, rest) = expression.split_at(operator_start);
+        let operator = ComparisonOperator::ALL
+            .into_iter()
+            .find(|operator| rest.starts_with(operator.symbol()))
+            .ok_or(ValuePredicateParseError::MissingOperator)?;
+        let literal = rest[operator.symbol().len()..].trim();
+        if literal.is_empty() {
+            return Err(ValuePredicateParseError::MissingValue);
+        }
+
+        let lhs = lhs.trim();
+        let (component, path) = lhs.split_at(lhs.find(['.', '[', '#']).unwrap_or(lhs.len()));
+        if component.is_empty() {
+            return Err(ValuePredicateParseError::MissingComponent);
+        }
+        validate_path(path)?;
+
+        Ok(Self {
+            component: component.to_string(),
+            path: path.to_string(),
+            operator,
+            value: PredicateValue::parse(literal),
+        })
+    }
+
+    /// Creates a [`ValuePredicate`] for the component that the name was resolved to.
+    pub fn resolve(&self, component_id: ComponentId) -> ValuePredicate {
+        ValuePredicate {
+            component_id,
+            path: self.path.clone(),
+            operator: self.operator,
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl Display for ValuePredicateExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{} {} {}",
+            self.component, self.path, self.operator, self.value
+        )
+    }
+}
+
+/// An error encountered while parsing a [`ValuePredicateExpression`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValuePredicateParseError {
+    /// None of `==`, `!=`, `<`, `<=`, `>` or `>=` was found.
+    #[error("Expected a comparison operator (`==`, `!=`, `<`, `<=`, `>` or `>=`)")]
+    MissingOperator,
+    /// Nothing came after the operator.
+    #[error("Expected a value after the comparison operator")]
+    MissingValue,
+    /// Nothing came before the path.
+    #[error("Expected a component name before the field path")]
+    MissingComponent,
+    /// The field path is not a valid reflection path.
+    #[error("Invalid field path `{path}`: {reason}")]
+    InvalidPath {
+        /// The path that failed to parse.
+        path: String,
+        /// Why the path is invalid.
+        reason: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect)]
+    struct Health(u32);
+
+    #[test]
+    fn expressions_are_parsed() {
+        let expression = ValuePredicateExpression::parse("Transform.translation.y < 0").unwrap();
+
+        assert_eq!(expression.component, "Transform");
+        assert_eq!(expression.path, ".translation.y");
+        assert_eq!(expression.operator, ComparisonOperator::LessThan);
+        assert_eq!(expression.value, PredicateValue::Number(0.0));
+
+        let expression = ValuePredicateExpression::parse("Name==\"true\"").unwrap();
+        assert_eq!(expression.path, "");
+        assert_eq!(expression.value, PredicateValue::Text("true".to_string()));
+        assert_eq!(
+            ValuePredicateExpression::parse(&expression.to_string()).unwrap(),
+            expression
+        );
+    }
+
+    #[test]
+    fn invalid_expressions_are_rejected() {
+        assert_eq!(
+            ValuePredicateExpression::parse("Health.0"),
+            Err(ValuePredicateParseError::MissingOperator)
+        );
+        assert_eq!(
+            ValuePredicateExpression::parse("Health.0 >= "),
+            Err(ValuePredicateParseError::MissingValue)
+        );
+        assert_eq!(
+            ValuePredicateExpression::parse(".0 == 1"),
+            Err(ValuePredicateParseError::MissingComponent)
+        );
+        assert!(matches!(
+            ValuePredicateExpression::parse("Health[x] == 1"),
+            Err(ValuePredicateParseError::InvalidPath { .. })
+        ));
+    }
+
+    #[test]
+    fn predicates_compare_reflected_fields() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut type_registry = world.resource::<AppTypeRegistry>().write();
+            type_registry.register::<Transform>();
+            type_registry.register::<Visibility>();
+        }
+        let transform_id = world.register_component::<Transform>();
+        let visibility_id = world.register_component::<Visibility>();
+        let below = world
+            .spawn((Transform::from_xyz(0.0, -1.0, 0.0), Visibility::Hidden))
+            .id();
+        let above = world.spawn(Transform::from_xyz(0.0, 1.0, 0.0)).id();
+
+        let below_ground = ValuePredicate::new(
+            transform_id,
+            ".translation.y",
+            ComparisonOperator::LessThan,
+            PredicateValue::Number(0.0),
+        )
+        .unwrap();
+        assert!(below_ground.matches(&world, below));
+        assert!(!below_ground.matches(&world, above));
+
+        let hidden = ValuePredicate::new(
+            visibility_id,
+            "",
+            ComparisonOperator::Equal,
+            PredicateValue::parse("Hidden"),
+        )
+        .unwrap();
+        assert!(hidden.matches(&world, below));
+        // Entities without the component never match
+        assert!(!hidden.matches(&world, above));
+    }
+
+    #[test]
+    fn incompatible_types_never_match() {
+        let predicate = ValuePredicate::new(
+            ComponentId::new(0),
+            ".0",
+            ComparisonOperator::NotEqual,
+            PredicateValue::Bool(true),
+        )
+        .unwrap();
+
+        assert!(!predicate.evaluate(&Health(3)));
+        assert!(
+            ValuePredicate {
+                value: PredicateValue::Number(3.0),
+                operator: ComparisonOperator::Equal,
+                ..predicate
+            }
+            .evaluate(&Health(3))
+        );
+    }
+}
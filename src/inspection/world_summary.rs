@@ -3,8 +3,19 @@
 use std::cmp::Reverse;
 
 use bevy::{
-    ecs::{archetype::ArchetypeId, component::ComponentId},
+    ecs::{
+        archetype::{Archetype, ArchetypeId},
+        component::{ComponentId, StorageType},
+        resource::IsResource,
+    },
+    platform::collections::HashMap,
     prelude::*,
+    reflect::TypeRegistry,
+};
+
+use crate::{
+    memory_size::{MemoryDetailLevel, MemoryEstimate, MemorySize, estimate_heap_size},
+    reflection_tools::reflect_component_by_id,
 };
 
 /// Settings for [`WorldSummary`].
@@ -18,6 +29,16 @@ pub struct SummarySettings {
     pub include_empty_archetypes: bool,
     /// Optional output limit for archetype listing.
     pub max_archetype_rows: Option<usize>,
+    /// How thoroughly to measure memory usage, if at all.
+    ///
+    /// When this is `None`, memory is not measured and [`WorldSummary::memory`] is `None`.
+    /// [`MemoryDetailLevel::Deep`] walks every reflected component value in the world,
+    /// which is very slow for large worlds.
+    pub memory_detail_level: Option<MemoryDetailLevel>,
+    /// Whether to sort archetypes by their memory usage, largest first, instead of by entity count.
+    ///
+    /// Has no effect unless [`Self::memory_detail_level`] is set.
+    pub sort_by_memory: bool,
 }
 
 impl Default for SummarySettings {
@@ -27,6 +48,8 @@ impl Default for SummarySettings {
             include_component_names: true,
             include_empty_archetypes: false,
             max_archetype_rows: Some(DEFAULT_ARCHETYPE_ROWS),
+            memory_detail_level: None,
+            sort_by_memory: false,
         }
     }
 }
@@ -57,6 +80,10 @@ pub struct ArchetypeSummary {
         serde(with = "crate::serde_conversions::option_vec_debug_name")
     )]
     pub component_names: Option<Vec<DebugName>>,
+    /// The memory used by the components of the entities in this archetype.
+    ///
+    /// Optional value determined by [`SummarySettings::memory_detail_level`].
+    pub memory: Option<MemoryEstimate>,
 }
 
 impl ArchetypeSummary {
@@ -82,7 +109,11 @@ impl std::fmt::Display for ArchetypeSummary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut display = self.signature_short();
         let entity_count = self.entity_count;
-        display.push_str(&format!(" ({entity_count} entities)"));
+        display.push_str(&format!(" ({entity_count} entities"));
+        if let Some(memory) = self.memory {
+            display.push_str(&format!(", {memory}"));
+        }
+        display.push(')');
         display.push('\n');
         write!(f, "{display}")
     }
@@ -104,6 +135,10 @@ pub struct WorldSummary {
     pub total_non_send_resources: usize,
     /// Information about archetypes.
     pub archetype_summaries: Vec<ArchetypeSummary>,
+    /// A breakdown of the memory used by the world.
+    ///
+    /// Optional value determined by [`SummarySettings::memory_detail_level`].
+    pub memory: Option<MemorySummary>,
     /// Limit of displayed archetypes.
     max_archetype_rows: Option<usize>,
 }
@@ -142,11 +177,104 @@ impl std::fmt::Display for WorldSummary {
         if self.archetype_summaries.len() > archetype_display_limit {
             let remaining_archetypes = self.archetype_summaries.len() - archetype_display_limit;
             display.push_str(&format!("... and {remaining_archetypes} more archetypes."));
+            display.push('\n');
+        }
+        if let Some(memory) = &self.memory {
+            display.push_str(&format!("Memory: {}", memory.total));
+            display.push('\n');
+            display.push_str(&format!(
+                "Table components: {}, sparse set components: {}, resources: {}",
+                memory.table_components, memory.sparse_set_components, memory.resources
+            ));
+            display.push('\n');
+            display.push_str("Component types:");
+            display.push('\n');
+            for (i, component_type) in memory
+                .component_types
+                .iter()
+                .take(archetype_display_limit)
+                .enumerate()
+            {
+                let position = i + 1;
+                display.push_str(&format!("{position}. {component_type}"));
+                display.push('\n');
+            }
+            if memory.component_types.len() > archetype_display_limit {
+                let remaining_types = memory.component_types.len() - archetype_display_limit;
+                display.push_str(&format!("... and {remaining_types} more component types."));
+            }
         }
         write!(f, "{display}")
     }
 }
 
+/// A breakdown of the memory used by a [`World`], as part of a [`WorldSummary`].
+///
+/// Resources are stored on entities, but are counted separately from the components of other entities.
+/// Change detection ticks and the storage's own bookkeeping are not included.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemorySummary {
+    /// The memory used by all components and resources.
+    pub total: MemoryEstimate,
+    /// The memory used by components stored in tables, excluding resources.
+    pub table_components: MemoryEstimate,
+    /// The memory used by components stored in sparse sets, excluding resources.
+    pub sparse_set_components: MemoryEstimate,
+    /// The memory used by resources.
+    ///
+    /// Non-`Send` resources are always measured shallowly,
+    /// as they can only be reflected on the thread they were inserted on.
+    pub resources: MemoryEstimate,
+    /// The memory used by each component type, sorted by size with the largest first.
+    ///
+    /// Resources are not included.
+    pub component_types: Vec<ComponentTypeMemory>,
+}
+
+/// The memory used by all instances of a component type.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComponentTypeMemory {
+    /// The id of the component type.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::component_id")
+    )]
+    pub component_id: ComponentId,
+    /// The type name of the component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::debug_name")
+    )]
+    pub name: DebugName,
+    /// How the component is stored.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_conversions::storage_type")
+    )]
+    pub storage_type: StorageType,
+    /// How many entities have this component.
+    pub instance_count: usize,
+    /// The memory used by all instances of this component.
+    ///
+    /// This is the number of instances times the size of the component's layout,
+    /// plus the heap memory they own when measured deeply.
+    pub memory: MemoryEstimate,
+}
+
+impl std::fmt::Display for ComponentTypeMemory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} ({} instances)",
+            self.name.shortname(),
+            self.memory,
+            self.instance_count
+        )
+    }
+}
+
 /// Adds summary methods to [`World`].
 pub trait WorldSummaryExt {
     /// Summarizes data about this [`World`].
@@ -180,18 +308,37 @@ impl WorldSummaryExt for World {
                         })
                         .collect(),
                 ),
+                memory: None,
             })
             .filter(|archetype_summary| {
                 settings.include_empty_archetypes || archetype_summary.entity_count > 0
             })
             .collect();
-        archetype_summaries.sort_by_key(|archetype_summary| {
-            (
-                Reverse(archetype_summary.entity_count),
-                archetype_summary.component_ids.len(),
-                archetype_summary.archetype_id.index(),
-            )
+        let memory = settings.memory_detail_level.map(|detail_level| {
+            let (memory, archetype_memory) = summarize_memory(self, detail_level);
+            for archetype_summary in &mut archetype_summaries {
+                archetype_summary.memory = archetype_memory
+                    .get(&archetype_summary.archetype_id)
+                    .copied();
+            }
+            memory
         });
+        if settings.sort_by_memory && memory.is_some() {
+            archetype_summaries.sort_by_key(|archetype_summary| {
+                (
+                    Reverse(archetype_summary.memory.and_then(|memory| memory.total())),
+                    archetype_summary.archetype_id.index(),
+                )
+            });
+        } else {
+            archetype_summaries.sort_by_key(|archetype_summary| {
+                (
+                    Reverse(archetype_summary.entity_count),
+                    archetype_summary.component_ids.len(),
+                    archetype_summary.archetype_id.index(),
+                )
+            });
+        }
         let empty_archetypes = if settings.include_empty_archetypes {
             archetype_summaries
                 .iter()
@@ -207,11 +354,136 @@ impl WorldSummaryExt for World {
             total_send_resources,
             total_non_send_resources,
             archetype_summaries,
+            memory,
             max_archetype_rows: settings.max_archetype_rows,
         }
     }
 }
 
+/// Measures the memory used by every archetype and resource in the `world`.
+///
+/// Returns the [`MemorySummary`], along with the memory used by each archetype.
+fn summarize_memory(
+    world: &World,
+    detail_level: MemoryDetailLevel,
+) -> (MemorySummary, HashMap<ArchetypeId, MemoryEstimate>) {
+    let app_type_registry = world.get_resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry
+        .filter(|_| detail_level == MemoryDetailLevel::Deep)
+        .map(|app_type_registry| app_type_registry.read());
+    let resource_component_id = world.components().component_id::<IsResource>();
+
+    let zero = MemoryEstimate::Shallow(MemorySize(0));
+    let mut table_components = zero;
+    let mut sparse_set_components = zero;
+    let mut resources = zero;
+    let mut component_types: HashMap<ComponentId, ComponentTypeMemory> = HashMap::default();
+    let mut archetype_memory = HashMap::default();
+
+    for archetype in world.archetypes().iter() {
+        let is_resource =
+            resource_component_id.is_some_and(|component_id| archetype.contains(component_id));
+        let mut total = zero;
+        for (component_id, storage_type, memory) in
+            measure_archetype(world, archetype, type_registry.as_deref())
+        {
+            total = total + memory;
+            if is_resource {
+                resources = resources + memory;
+                continue;
+            }
+            match storage_type {
+                StorageType::Table => table_components = table_components + memory,
+                StorageType::SparseSet => sparse_set_components = sparse_set_components + memory,
+            }
+            let Some(name) = world.components().get_name(component_id) else {
+                continue;
+            };
+            let component_type =
+                component_types
+                    .entry(component_id)
+                    .or_insert_with(|| ComponentTypeMemory {
+                        component_id,
+                        name,
+                        storage_type,
+                        instance_count: 0,
+                        memory: zero,
+                    });
+            component_type.instance_count += archetype.len() as usize;
+            component_type.memory = component_type.memory + memory;
+        }
+        archetype_memory.insert(archetype.id(), total);
+    }
+
+    for (component_id, _data) in world.storages().non_sends.iter() {
+        if !world.contains_non_send_by_id(component_id) {
+            continue;
+        }
+        if let Some(info) = world.components().get_info(component_id) {
+            resources = resources + MemoryEstimate::Shallow(MemorySize::new(info.layout().size()));
+        }
+    }
+
+    let mut component_types: Vec<ComponentTypeMemory> = component_types.into_values().collect();
+    component_types.sort_by_key(|component_type| {
+        (
+            Reverse(component_type.memory.total()),
+            component_type.component_id.index(),
+        )
+    });
+
+    let memory = MemorySummary {
+        total: table_components + sparse_set_components + resources,
+        table_components,
+        sparse_set_components,
+        resources,
+        component_types,
+    };
+    (memory, archetype_memory)
+}
+
+/// Measures the memory used by each component of the entities in the `archetype`.
+///
+/// Heap memory is only estimated when a `type_registry` is provided,
+/// and falls back to the shallow size for components that can't be reflected.
+fn measure_archetype(
+    world: &World,
+    archetype: &Archetype,
+    type_registry: Option<&TypeRegistry>,
+) -> Vec<(ComponentId, StorageType, MemoryEstimate)> {
+    archetype
+        .components()
+        .iter()
+        .filter_map(|&component_id| {
+            let info = world.components().get_info(component_id)?;
+            let shallow = MemorySize::new(info.layout().size() * archetype.len() as usize);
+            let heap = type_registry.and_then(|type_registry| {
+                archetype
+                    .entities()
+                    .iter()
+                    .map(|archetype_entity| {
+                        let reflected = reflect_component_by_id(
+                            world,
+                            archetype_entity.id(),
+                            component_id,
+                            type_registry,
+                        )?;
+                        Some(estimate_heap_size(
+                            reflected.as_partial_reflect(),
+                            type_registry,
+                        ))
+                    })
+                    .sum::<Option<MemorySize>>()
+            });
+            let memory = match heap {
+                Some(heap) => MemoryEstimate::Deep { shallow, heap },
+                None => MemoryEstimate::Shallow(shallow),
+            };
+            Some((component_id, info.storage_type(), memory))
+        })
+        .collect()
+}
+
 /// Adds summary methods for [`Commands`].
 pub trait CommandsSummaryExt {
     /// Summarizes data about the [`World`].
@@ -226,3 +498,69 @@ impl CommandsSummaryExt for Commands<'_, '_> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Large(#[expect(dead_code, reason = "only the size is measured")] [u64; 4]);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct Small(#[expect(dead_code, reason = "only the size is measured")] u8);
+
+    #[test]
+    fn memory_is_not_measured_by_default() {
+        let mut world = World::new();
+        world.spawn(Large([0; 4]));
+
+        let summary = world.summarize(SummarySettings::default());
+        assert!(summary.memory.is_none());
+        assert!(
+            summary
+                .archetype_summaries
+                .iter()
+                .all(|archetype_summary| archetype_summary.memory.is_none())
+        );
+    }
+
+    #[test]
+    fn memory_is_broken_down_by_component_type_and_storage() {
+        let mut world = World::new();
+        world.spawn(Large([0; 4]));
+        world.spawn(Large([0; 4]));
+        world.spawn((Large([0; 4]), Small(0)));
+
+        let summary = world.summarize(SummarySettings {
+            memory_detail_level: Some(MemoryDetailLevel::Shallow),
+            sort_by_memory: true,
+            ..default()
+        });
+        let memory = summary.memory.unwrap();
+
+        let large = &memory.component_types[0];
+        assert_eq!(large.name.shortname().to_string(), "Large");
+        assert_eq!(large.instance_count, 3);
+        assert_eq!(large.memory, MemoryEstimate::Shallow(MemorySize(96)));
+
+        let small = memory
+            .component_types
+            .iter()
+            .find(|component_type| component_type.name.shortname().to_string() == "Small")
+            .unwrap();
+        assert_eq!(small.storage_type, StorageType::SparseSet);
+        assert_eq!(
+            memory.sparse_set_components,
+            MemoryEstimate::Shallow(MemorySize(1))
+        );
+
+        // The archetype with two entities uses the most memory
+        let largest_archetype = &summary.archetype_summaries[0];
+        assert_eq!(largest_archetype.entity_count, 2);
+        assert_eq!(
+            largest_archetype.memory,
+            Some(MemoryEstimate::Shallow(MemorySize(64)))
+        );
+    }
+}
@@ -0,0 +1,43 @@
+//! Identifies the entities that belong to the inspector itself,
+//! such as its window, camera and observers.
+//!
+//! World-wide diagnostics, like the [entity lifecycle history](crate::inspection::entity_lifecycle)
+//! and the [change profiler](crate::inspection::change_profiler),
+//! use these checks to avoid reporting on the inspector's own activity.
+
+use bevy::ecs::archetype::Archetype;
+use bevy::prelude::*;
+use bevy::ui::ComputedUiTargetCamera;
+
+/// Marker component for inspector-internal entities that should not appear in the entity list.
+/// Applied to cameras, observers, and other internal entities.
+#[derive(Component)]
+pub struct InspectorInternal;
+
+/// Returns whether the `entity` belongs to the inspector itself,
+/// so that it can be left out of world-wide diagnostics.
+///
+/// This includes entities marked with [`InspectorInternal`],
+/// as well as UI nodes rendered by a camera marked with [`InspectorInternal`],
+/// such as the contents of the inspector window.
+pub fn is_inspector_entity(world: &World, entity: &EntityRef) -> bool {
+    entity.contains::<InspectorInternal>()
+        || entity
+            .get::<ComputedUiTargetCamera>()
+            .and_then(ComputedUiTargetCamera::get)
+            .is_some_and(|camera| world.get::<InspectorInternal>(camera).is_some())
+}
+
+/// Returns whether any entity in the `archetype` could be an [inspector entity](is_inspector_entity).
+///
+/// When this returns `false`, none of the archetype's entities need to be checked individually.
+pub fn may_contain_inspector_entities(world: &World, archetype: &Archetype) -> bool {
+    let components = world.components();
+    [
+        components.component_id::<InspectorInternal>(),
+        components.component_id::<ComputedUiTargetCamera>(),
+    ]
+    .into_iter()
+    .flatten()
+    .any(|id| archetype.contains(id))
+}
@@ -12,11 +12,13 @@
 
 #[cfg(feature = "remote")]
 pub mod brp;
+pub mod entity_categories;
 pub mod entity_grouping;
 pub mod entity_name_resolution;
 pub mod extension_methods;
 pub mod gui;
 pub mod inspection;
+pub mod inspector_entities;
 pub mod memory_size;
 pub mod reflection_tools;
 
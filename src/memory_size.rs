@@ -1,6 +1,14 @@
 //! Types for representing the size of objects in memory.
+//!
+//! Sizes come in two flavors:
+//! the "shallow" size reported by a type's [`Layout`](core::alloc::Layout),
+//! and the "deep" size, which also includes an estimate of the heap memory owned by the value.
+//! Deep sizes are gathered by walking the reflected value with [`estimate_heap_size`].
 
+use bevy::prelude::*;
+use bevy::reflect::{PartialReflect, ReflectRef, TypeRegistry, reflect_trait};
 use core::fmt::Display;
+use std::borrow::Cow;
 
 /// The size of an object in memory, in bytes.
 ///
@@ -97,6 +105,254 @@ impl Display for MemorySize {
     }
 }
 
+impl core::ops::Add for MemorySize {
+    type Output = MemorySize;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        MemorySize(self.0 + rhs.0)
+    }
+}
+
+impl core::iter::Sum for MemorySize {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        // Sum in u64 to avoid overflow for extremely large values on 32-bit systems.
+        MemorySize(iter.map(|size| size.0).sum())
+    }
+}
+
+/// How thoroughly memory usage should be measured during inspection.
+///
+/// Deep measurement requires walking the reflected value,
+/// which is much more expensive than reading the type's layout.
+/// It is recommended to only use it when inspecting a small number of objects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoryDetailLevel {
+    /// Only the inline size of the value, as reported by its [`Layout`](core::alloc::Layout).
+    #[default]
+    Shallow,
+    /// The inline size of the value, plus an estimate of the heap memory it owns.
+    ///
+    /// See [`estimate_heap_size`] for how this estimate is computed.
+    Deep,
+}
+
+/// The measured size of an object in memory.
+///
+/// Unlike a bare [`MemorySize`], this records *how* the size was measured,
+/// so that "this takes 0 bytes" can be distinguished from "we don't know how large this is".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoryEstimate {
+    /// The size of the object could not be determined.
+    Unknown,
+    /// Only the inline size is known: heap allocations owned by the object are not included.
+    Shallow(MemorySize),
+    /// The inline size, plus an estimate of the heap memory owned by the object.
+    Deep {
+        /// The inline size of the object.
+        shallow: MemorySize,
+        /// The estimated number of heap bytes owned by the object.
+        heap: MemorySize,
+    },
+}
+
+impl MemoryEstimate {
+    /// Returns the best available estimate of the total size, if known.
+    ///
+    /// For [`MemoryEstimate::Deep`], this is the inline size plus the heap size.
+    pub fn total(&self) -> Option<MemorySize> {
+        match self {
+            MemoryEstimate::Unknown => None,
+            MemoryEstimate::Shallow(shallow) => Some(*shallow),
+            MemoryEstimate::Deep { shallow, heap } => Some(*shallow + *heap),
+        }
+    }
+
+    /// Returns the inline size, if known.
+    pub fn shallow(&self) -> Option<MemorySize> {
+        match self {
+            MemoryEstimate::Unknown => None,
+            MemoryEstimate::Shallow(shallow) | MemoryEstimate::Deep { shallow, .. } => {
+                Some(*shallow)
+            }
+        }
+    }
+
+    /// Returns the estimated heap size, if deep measurement was performed.
+    pub fn heap(&self) -> Option<MemorySize> {
+        match self {
+            MemoryEstimate::Deep { heap, .. } => Some(*heap),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the size of the object is known.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, MemoryEstimate::Unknown)
+    }
+}
+
+/// Combines two estimates, such as when summing the components of an entity.
+///
+/// [`Unknown`](MemoryEstimate::Unknown) is contagious: if either side is unknown, so is the result.
+/// Adding a [`Shallow`](MemoryEstimate::Shallow) estimate to a [`Deep`](MemoryEstimate::Deep) one
+/// yields a deep estimate whose heap size is a lower bound.
+impl core::ops::Add for MemoryEstimate {
+    type Output = MemoryEstimate;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        use MemoryEstimate::*;
+
+        match (self, rhs) {
+            (Unknown, _) | (_, Unknown) => Unknown,
+            (Shallow(a), Shallow(b)) => Shallow(a + b),
+            (Shallow(a), Deep { shallow, heap }) | (Deep { shallow, heap }, Shallow(a)) => Deep {
+                shallow: shallow + a,
+                heap,
+            },
+            (
+                Deep {
+                    shallow: shallow_a,
+                    heap: heap_a,
+                },
+                Deep {
+                    shallow: shallow_b,
+                    heap: heap_b,
+                },
+            ) => Deep {
+                shallow: shallow_a + shallow_b,
+                heap: heap_a + heap_b,
+            },
+        }
+    }
+}
+
+impl Display for MemoryEstimate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MemoryEstimate::Unknown => write!(f, "? B"),
+            MemoryEstimate::Shallow(shallow) => write!(f, "{shallow}"),
+            MemoryEstimate::Deep { shallow, heap } => {
+                write!(f, "{} ({shallow} + {heap} heap)", *shallow + *heap)
+            }
+        }
+    }
+}
+
+/// Reports the heap memory owned by a value, for types that cannot be walked using reflection.
+///
+/// [`estimate_heap_size`] can see inside of lists, maps, sets and strings on its own,
+/// but opaque types (such as handles to external resources or custom collections)
+/// are treated as owning no heap memory unless they implement this trait.
+///
+/// To make the implementation visible to the inspector, reflect the trait and register it:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use feathers_inspector::memory_size::{HeapSize, ReflectHeapSize};
+/// #[derive(Reflect, Clone)]
+/// #[reflect(opaque, HeapSize)]
+/// struct Blob(Box<[u8]>);
+///
+/// impl HeapSize for Blob {
+///     fn heap_size(&self) -> usize {
+///         self.0.len()
+///     }
+/// }
+///
+/// App::new().register_type::<Blob>();
+/// ```
+///
+/// For foreign types, register the type data manually with
+/// `app.register_type_data::<T, ReflectHeapSize>()`.
+#[reflect_trait]
+pub trait HeapSize {
+    /// Returns the number of heap bytes owned by this value.
+    ///
+    /// This should not include the inline size of the value itself.
+    fn heap_size(&self) -> usize;
+}
+
+/// Estimates the number of heap bytes owned by a reflected value.
+///
+/// The value is walked recursively:
+///
+/// - Structs, tuples, arrays and enums own the heap memory of their fields.
+/// - Lists, maps and sets own the inline size of each of their elements, plus the heap memory of those elements.
+/// - [`String`]s and owned [`Cow<str>`]s own their bytes.
+/// - Opaque types own the value reported by their [`HeapSize`] implementation, if it is registered.
+///   Otherwise, they are assumed to own no heap memory.
+///
+/// This is an estimate: reflection only exposes the length of collections, not their capacity,
+/// so spare capacity is not counted. Shared allocations (e.g. behind an `Arc`) are not detected either.
+pub fn estimate_heap_size(value: &dyn PartialReflect, type_registry: &TypeRegistry) -> MemorySize {
+    MemorySize::new(heap_bytes(value, type_registry))
+}
+
+fn heap_bytes(value: &dyn PartialReflect, type_registry: &TypeRegistry) -> usize {
+    match value.reflect_ref() {
+        ReflectRef::Struct(dyn_struct) => (0..dyn_struct.field_len())
+            .filter_map(|i| dyn_struct.field_at(i))
+            .map(|field| heap_bytes(field, type_registry))
+            .sum(),
+        ReflectRef::TupleStruct(tuple_struct) => (0..tuple_struct.field_len())
+            .filter_map(|i| tuple_struct.field(i))
+            .map(|field| heap_bytes(field, type_registry))
+            .sum(),
+        ReflectRef::Tuple(tuple) => (0..tuple.field_len())
+            .filter_map(|i| tuple.field(i))
+            .map(|field| heap_bytes(field, type_registry))
+            .sum(),
+        ReflectRef::Array(array) => (0..array.len())
+            .filter_map(|i| array.get(i))
+            .map(|item| heap_bytes(item, type_registry))
+            .sum(),
+        ReflectRef::Enum(dyn_enum) => (0..dyn_enum.field_len())
+            .filter_map(|i| dyn_enum.field_at(i))
+            .map(|field| heap_bytes(field, type_registry))
+            .sum(),
+        ReflectRef::List(list) => (0..list.len())
+            .filter_map(|i| list.get(i))
+            .map(|item| owned_bytes(item, type_registry))
+            .sum(),
+        ReflectRef::Map(map) => map
+            .iter()
+            .map(|(key, value)| owned_bytes(key, type_registry) + owned_bytes(value, type_registry))
+            .sum(),
+        ReflectRef::Set(set) => set
+            .iter()
+            .map(|item| owned_bytes(item, type_registry))
+            .sum(),
+        ReflectRef::Opaque(opaque) => opaque_heap_bytes(opaque, type_registry),
+    }
+}
+
+/// The inline size of an element stored in a heap-allocated collection, plus its own heap memory.
+fn owned_bytes(value: &dyn PartialReflect, type_registry: &TypeRegistry) -> usize {
+    size_of_val(value) + heap_bytes(value, type_registry)
+}
+
+fn opaque_heap_bytes(opaque: &dyn PartialReflect, type_registry: &TypeRegistry) -> usize {
+    if let Some(string) = opaque.try_downcast_ref::<String>() {
+        return string.len();
+    }
+    if let Some(cow) = opaque.try_downcast_ref::<Cow<'static, str>>() {
+        return match cow {
+            Cow::Borrowed(_) => 0,
+            Cow::Owned(string) => string.len(),
+        };
+    }
+
+    let Some(reflected) = opaque.try_as_reflect() else {
+        return 0;
+    };
+    type_registry
+        .get_type_data::<ReflectHeapSize>(reflected.type_id())
+        .and_then(|reflect_heap_size| reflect_heap_size.get(reflected))
+        .map_or(0, HeapSize::heap_size)
+}
+
 /// Common units for representing memory size.
 ///
 /// Used for determining the most appropriate unit to display a [`MemorySize`].
@@ -113,3 +369,69 @@ pub enum MemoryUnit {
     /// 1 terabyte = 1024 gigabytes
     Terabytes,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect)]
+    struct Buffer {
+        bytes: Vec<u8>,
+        label: String,
+    }
+
+    #[derive(Reflect, Clone)]
+    #[reflect(opaque, HeapSize)]
+    struct OpaqueBlob(usize);
+
+    impl HeapSize for OpaqueBlob {
+        fn heap_size(&self) -> usize {
+            self.0
+        }
+    }
+
+    #[test]
+    fn heap_size_of_nested_collections() {
+        let value = Buffer {
+            bytes: vec![0; 1000],
+            label: "hello".to_string(),
+        };
+        let heap = estimate_heap_size(&value, &TypeRegistry::new());
+        assert_eq!(heap, MemorySize(1005));
+    }
+
+    #[test]
+    fn opaque_types_use_registered_heap_size() {
+        let mut registry = TypeRegistry::new();
+        assert_eq!(
+            estimate_heap_size(&OpaqueBlob(64), &registry),
+            MemorySize(0)
+        );
+
+        registry.register::<OpaqueBlob>();
+        assert_eq!(
+            estimate_heap_size(&OpaqueBlob(64), &registry),
+            MemorySize(64)
+        );
+    }
+
+    #[test]
+    fn unknown_is_contagious() {
+        let shallow = MemoryEstimate::Shallow(MemorySize(8));
+        let deep = MemoryEstimate::Deep {
+            shallow: MemorySize(16),
+            heap: MemorySize(100),
+        };
+
+        assert_eq!(shallow + MemoryEstimate::Unknown, MemoryEstimate::Unknown);
+        assert_eq!(
+            shallow + deep,
+            MemoryEstimate::Deep {
+                shallow: MemorySize(24),
+                heap: MemorySize(100),
+            }
+        );
+        assert_eq!(deep.total(), Some(MemorySize(116)));
+        assert_eq!(MemoryEstimate::Unknown.total(), None);
+    }
+}
@@ -1,9 +1,10 @@
 //! Code that makes working with Bevy's reflection system easier.
 
 use bevy::{
+    ecs::component::ComponentId,
     prelude::*,
     reflect::{
-        ReflectCloneError, ReflectRef, TypeInfo,
+        ReflectCloneError, ReflectFromPtr, ReflectRef, TypeInfo, TypeRegistry,
         array::Array,
         enums::{Enum, VariantType},
         list::List,
@@ -83,24 +84,108 @@ pub fn component_value_to_string(
     }
 }
 
+/// Reflects the non-`Send` resource identified by `component_id`.
+///
+/// Returns `None` if the resource is not present,
+/// or if its type is not registered with [`ReflectFromPtr`] type data.
+///
+/// # Panics
+///
+/// Panics if called from a thread other than the one the resource was inserted on,
+/// like [`World::get_non_send_by_id`].
+pub fn reflect_non_send_resource<'w>(
+    world: &'w World,
+    component_id: ComponentId,
+    type_registry: &TypeRegistry,
+) -> Option<&'w dyn Reflect> {
+    let type_id = world.components().get_info(component_id)?.type_id()?;
+    let reflect_from_ptr = type_registry.get_type_data::<ReflectFromPtr>(type_id)?;
+    let ptr = world.get_non_send_by_id(component_id)?;
+    // SAFETY: `ptr` points to a value of the type identified by `type_id`,
+    // which `reflect_from_ptr` was registered for.
+    Some(unsafe { reflect_from_ptr.as_reflect(ptr) })
+}
+
+/// Reflects the component identified by `component_id` on `entity`.
+///
+/// Unlike [`World::get_reflect`], this uses the provided `type_registry`
+/// rather than locking the [`AppTypeRegistry`], so it can be called in a loop.
+///
+/// Returns `None` if the entity does not have the component,
+/// or if its type is not registered with [`ReflectFromPtr`] type data.
+pub fn reflect_component_by_id<'w>(
+    world: &'w World,
+    entity: Entity,
+    component_id: ComponentId,
+    type_registry: &TypeRegistry,
+) -> Option<&'w dyn Reflect> {
+    let type_id = world.components().get_info(component_id)?.type_id()?;
+    let reflect_from_ptr = type_registry.get_type_data::<ReflectFromPtr>(type_id)?;
+    let ptr = world.get_by_id(entity, component_id)?;
+    // SAFETY: `ptr` points to a value of the type identified by `type_id`,
+    // which `reflect_from_ptr` was registered for.
+    Some(unsafe { reflect_from_ptr.as_reflect(ptr) })
+}
+
+/// Formats some reflected values in a custom way when used in a [`ValueFormat`],
+/// such as resolving [`Handle`]s to the asset they point to.
+pub trait ValueFormatter {
+    /// Returns the custom representation of the `reflected` value,
+    /// or `None` to format it as usual.
+    ///
+    /// This is called for every value nested within the formatted value, so it should be cheap.
+    fn format_value(&self, reflected: &dyn PartialReflect) -> Option<String>;
+}
+
+/// Controls how reflected values are formatted by [`format_reflected_value`].
+#[derive(Clone, Copy, Default)]
+pub struct ValueFormat<'a> {
+    /// Whether or not full type names should be displayed.
+    pub full_type_names: bool,
+    /// Formats some values in a custom way,
+    /// such as the [`HandleResolver`](crate::inspection::asset_inspection::HandleResolver).
+    ///
+    /// When this is `None`, every value is formatted based on its reflected structure.
+    pub formatter: Option<&'a dyn ValueFormatter>,
+}
+
+impl ValueFormat<'_> {
+    /// The format used by the `pretty_print_reflected_*` functions, which only configures type names.
+    fn plain(full_type_names: bool) -> Self {
+        Self {
+            full_type_names,
+            formatter: None,
+        }
+    }
+}
+
 /// Converts a reflected value to a string for debugging purposes.
+///
+/// This is a shorthand for [`format_reflected_value`], without a [`ValueFormatter`].
+pub fn reflected_value_to_string(reflected: &dyn PartialReflect, full_type_names: bool) -> String {
+    format_reflected_value(reflected, ValueFormat::plain(full_type_names))
+}
+
+/// Converts a reflected value to a string for debugging purposes, using the provided [`ValueFormat`].
 // When upstreamed, this should be a method on `PartialReflect`,
 // although much of it should be a `Display` impl on `ReflectRef`.
-pub fn reflected_value_to_string(reflected: &dyn PartialReflect, full_type_names: bool) -> String {
+pub fn format_reflected_value(reflected: &dyn PartialReflect, format: ValueFormat) -> String {
+    if let Some(formatter) = format.formatter
+        && let Some(formatted) = formatter.format_value(reflected)
+    {
+        return formatted;
+    }
+
     let reflect_ref = reflected.reflect_ref();
     match reflect_ref {
-        ReflectRef::Struct(dyn_struct) => {
-            pretty_print_reflected_struct(dyn_struct, full_type_names)
-        }
-        ReflectRef::TupleStruct(tuple_struct) => {
-            pretty_print_reflected_tuple_struct(tuple_struct, full_type_names)
-        }
-        ReflectRef::Tuple(tuple) => pretty_print_reflected_tuple(tuple, full_type_names),
-        ReflectRef::List(list) => pretty_print_reflected_list(list, full_type_names),
-        ReflectRef::Array(array) => pretty_print_reflected_array(array, full_type_names),
-        ReflectRef::Map(map) => pretty_print_reflected_map(map, full_type_names),
-        ReflectRef::Set(set) => pretty_print_reflected_set(set, full_type_names),
-        ReflectRef::Enum(dyn_enum) => pretty_print_reflected_enum(dyn_enum, full_type_names),
+        ReflectRef::Struct(dyn_struct) => format_struct(dyn_struct, format),
+        ReflectRef::TupleStruct(tuple_struct) => format_tuple_struct(tuple_struct, format),
+        ReflectRef::Tuple(tuple) => format_tuple(tuple, format),
+        ReflectRef::List(list) => format_list(list, format),
+        ReflectRef::Array(array) => format_array(array, format),
+        ReflectRef::Map(map) => format_map(map, format),
+        ReflectRef::Set(set) => format_set(set, format),
+        ReflectRef::Enum(dyn_enum) => format_enum(dyn_enum, format),
         ReflectRef::Opaque(opaque_partial_reflect) => {
             pretty_print_reflected_opaque(opaque_partial_reflect)
         }
@@ -108,16 +193,51 @@ pub fn reflected_value_to_string(reflected: &dyn PartialReflect, full_type_names
 }
 
 pub fn pretty_print_reflected_struct(dyn_struct: &dyn Struct, full_type_names: bool) -> String {
+    format_struct(dyn_struct, ValueFormat::plain(full_type_names))
+}
+
+pub fn pretty_print_reflected_tuple_struct(
+    dyn_tuple_struct: &dyn TupleStruct,
+    full_type_names: bool,
+) -> String {
+    format_tuple_struct(dyn_tuple_struct, ValueFormat::plain(full_type_names))
+}
+
+pub fn pretty_print_reflected_tuple(dyn_tuple: &dyn Tuple, full_type_names: bool) -> String {
+    format_tuple(dyn_tuple, ValueFormat::plain(full_type_names))
+}
+
+pub fn pretty_print_reflected_list(dyn_list: &dyn List, full_type_names: bool) -> String {
+    format_list(dyn_list, ValueFormat::plain(full_type_names))
+}
+
+pub fn pretty_print_reflected_array(dyn_array: &dyn Array, full_type_names: bool) -> String {
+    format_array(dyn_array, ValueFormat::plain(full_type_names))
+}
+
+pub fn pretty_print_reflected_map(dyn_map: &dyn Map, full_type_names: bool) -> String {
+    format_map(dyn_map, ValueFormat::plain(full_type_names))
+}
+
+pub fn pretty_print_reflected_set(dyn_set: &dyn Set, full_type_names: bool) -> String {
+    format_set(dyn_set, ValueFormat::plain(full_type_names))
+}
+
+pub fn pretty_print_reflected_enum(dyn_enum: &dyn Enum, full_type_names: bool) -> String {
+    format_enum(dyn_enum, ValueFormat::plain(full_type_names))
+}
+
+fn format_struct(dyn_struct: &dyn Struct, format: ValueFormat) -> String {
     let type_name = display_type_name(
         dyn_struct.get_represented_type_info(),
         "<Unknown Struct>",
-        full_type_names,
+        format.full_type_names,
     );
 
     let entries: Vec<String> = (0..dyn_struct.field_len())
         .map(|i| {
             let field_name = dyn_struct.name_at(i).unwrap_or("<Unknown Field>");
-            let field_value = get_value_string(dyn_struct.field_at(i), full_type_names);
+            let field_value = get_value_string(dyn_struct.field_at(i), format);
             format!("{field_name}: {field_value},")
         })
         .collect();
@@ -125,58 +245,50 @@ pub fn pretty_print_reflected_struct(dyn_struct: &dyn Struct, full_type_names: b
     format_block(&format!("{type_name} "), '{', &entries, '}')
 }
 
-pub fn pretty_print_reflected_tuple_struct(
-    dyn_tuple_struct: &dyn TupleStruct,
-    full_type_names: bool,
-) -> String {
+fn format_tuple_struct(dyn_tuple_struct: &dyn TupleStruct, format: ValueFormat) -> String {
     let type_name = display_type_name(
         dyn_tuple_struct.get_represented_type_info(),
         "<Unknown TupleStruct>",
-        full_type_names,
+        format.full_type_names,
     );
 
     let entries: Vec<String> = (0..dyn_tuple_struct.field_len())
-        .map(|i| {
-            format!(
-                "{},",
-                get_value_string(dyn_tuple_struct.field(i), full_type_names)
-            )
-        })
+        .map(|i| format!("{},", get_value_string(dyn_tuple_struct.field(i), format)))
         .collect();
 
     format_block(&type_name, '(', &entries, ')')
 }
 
-pub fn pretty_print_reflected_tuple(dyn_tuple: &dyn Tuple, full_type_names: bool) -> String {
+fn format_tuple(dyn_tuple: &dyn Tuple, format: ValueFormat) -> String {
     let entries: Vec<String> = (0..dyn_tuple.field_len())
-        .map(|i| format!("{},", get_value_string(dyn_tuple.field(i), full_type_names)))
+        .map(|i| format!("{},", get_value_string(dyn_tuple.field(i), format)))
         .collect();
 
     format_block("", '(', &entries, ')')
 }
 
-pub fn pretty_print_reflected_list(dyn_list: &dyn List, full_type_names: bool) -> String {
+fn format_list(dyn_list: &dyn List, format: ValueFormat) -> String {
     let entries: Vec<String> = (0..dyn_list.len())
-        .map(|i| format!("{},", get_value_string(dyn_list.get(i), full_type_names)))
+        .map(|i| format!("{},", get_value_string(dyn_list.get(i), format)))
         .collect();
 
     format_block("", '[', &entries, ']')
 }
 
-pub fn pretty_print_reflected_array(dyn_array: &dyn Array, full_type_names: bool) -> String {
+fn format_array(dyn_array: &dyn Array, format: ValueFormat) -> String {
     let entries: Vec<String> = (0..dyn_array.len())
-        .map(|i| format!("{},", get_value_string(dyn_array.get(i), full_type_names)))
+        .map(|i| format!("{},", get_value_string(dyn_array.get(i), format)))
         .collect();
 
     format_block("", '[', &entries, ']')
 }
 
-pub fn pretty_print_reflected_map(dyn_map: &dyn Map, full_type_names: bool) -> String {
+fn format_map(dyn_map: &dyn Map, format: ValueFormat) -> String {
     let entries: Vec<String> = dyn_map
         .iter()
         .map(|(key, value)| {
-            let key = reflected_value_to_string(key, full_type_names);
-            let value = reflected_value_to_string(value, full_type_names);
+            let key = format_reflected_value(key, format);
+            let value = format_reflected_value(value, format);
             format!("{key}: {value},")
         })
         .collect();
@@ -184,20 +296,20 @@ pub fn pretty_print_reflected_map(dyn_map: &dyn Map, full_type_names: bool) -> S
     format_block("", '{', &entries, '}')
 }
 
-pub fn pretty_print_reflected_set(dyn_set: &dyn Set, full_type_names: bool) -> String {
+fn format_set(dyn_set: &dyn Set, format: ValueFormat) -> String {
     let entries: Vec<String> = dyn_set
         .iter()
-        .map(|element| format!("{},", reflected_value_to_string(element, full_type_names)))
+        .map(|element| format!("{},", format_reflected_value(element, format)))
         .collect();
 
     format_block("", '{', &entries, '}')
 }
 
-pub fn pretty_print_reflected_enum(dyn_enum: &dyn Enum, full_type_names: bool) -> String {
+fn format_enum(dyn_enum: &dyn Enum, format: ValueFormat) -> String {
     let type_name = display_type_name(
         dyn_enum.get_represented_type_info(),
         "<Unknown Enum>",
-        full_type_names,
+        format.full_type_names,
     );
     let qualified = format!("{type_name}::{variant}", variant = dyn_enum.variant_name());
 
@@ -206,7 +318,7 @@ pub fn pretty_print_reflected_enum(dyn_enum: &dyn Enum, full_type_names: bool) -
             let entries: Vec<String> = (0..dyn_enum.field_len())
                 .map(|i| {
                     let field_name = dyn_enum.name_at(i).unwrap_or("<Unknown Field>");
-                    let field_value = get_value_string(dyn_enum.field_at(i), full_type_names);
+                    let field_value = get_value_string(dyn_enum.field_at(i), format);
                     format!("{field_name}: {field_value},")
                 })
                 .collect();
@@ -214,12 +326,7 @@ pub fn pretty_print_reflected_enum(dyn_enum: &dyn Enum, full_type_names: bool) -
         }
         VariantType::Tuple => {
             let entries: Vec<String> = (0..dyn_enum.field_len())
-                .map(|i| {
-                    format!(
-                        "{},",
-                        get_value_string(dyn_enum.field_at(i), full_type_names)
-                    )
-                })
+                .map(|i| format!("{},", get_value_string(dyn_enum.field_at(i), format)))
                 .collect();
             format_block(&qualified, '(', &entries, ')')
         }
@@ -243,9 +350,9 @@ pub fn pretty_print_reflected_opaque(opaque_partial_reflect: &dyn PartialReflect
     result
 }
 
-fn get_value_string(partial_reflect: Option<&dyn PartialReflect>, full_type_names: bool) -> String {
+fn get_value_string(partial_reflect: Option<&dyn PartialReflect>, format: ValueFormat) -> String {
     if let Some(value) = partial_reflect {
-        reflected_value_to_string(value, full_type_names)
+        format_reflected_value(value, format)
     } else {
         String::from("<Unknown Value>")
     }
@@ -66,6 +66,36 @@ pub mod archetype_id {
     }
 }
 
+/// Serde helper module to serialize [`TableId`] as its underlying integer index.
+///
+/// ## Usage
+///
+/// Add `#[serde(with = "crate::serde_conversions::table_id")]`
+/// to the struct's [`TableId`] field.
+///
+/// [`TableId`]: bevy::ecs::storage::TableId
+pub mod table_id {
+    use bevy::ecs::storage::TableId;
+    use serde::{Deserialize, Serialize};
+
+    /// Serializes a [`TableId`] into its index.
+    pub fn serialize<S>(id: &TableId, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        id.as_u32().serialize(serializer)
+    }
+
+    /// Deserializes the index of a [`TableId`].
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TableId, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let index = u32::deserialize(deserializer)?;
+        Ok(TableId::from_u32(index))
+    }
+}
+
 /// Serde helper module to serialize a slice of [`ComponentId`]s as a `Vec` of indexes.
 ///
 /// ## Usage
@@ -213,6 +243,36 @@ pub mod hash_map_component_id_component_type_metadata {
     }
 }
 
+/// Serde helper module to serialize an optional [`DebugName`] as an optional string.
+///
+/// ## Usage
+///
+/// Add `#[serde(with = "crate::serde_conversions::option_debug_name")]`
+/// to the struct's `Option<DebugName>` field.
+///
+/// [`DebugName`]: bevy::utils::prelude::DebugName
+pub mod option_debug_name {
+    use bevy::utils::prelude::DebugName;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes an `Option<DebugName>` into an `Option<String>`.
+    pub fn serialize<S>(name: &Option<DebugName>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        name.as_ref().map(ToString::to_string).serialize(serializer)
+    }
+
+    /// Deserializes an `Option<String>` into an `Option<DebugName>`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DebugName>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name: Option<String> = Option::deserialize(deserializer)?;
+        Ok(name.map(DebugName::from))
+    }
+}
+
 pub mod option_vec_debug_name {
     use bevy::utils::prelude::DebugName;
     use serde::{Deserialize, Deserializer, Serializer};
@@ -242,6 +302,40 @@ pub mod option_vec_debug_name {
     }
 }
 
+/// Serde helper module to serialize a `Vec` of [`DebugName`]s as strings.
+///
+/// ## Usage
+///
+/// Add `#[serde(with = "crate::serde_conversions::vec_debug_name")]`
+/// to the struct's `Vec<DebugName>` field.
+///
+/// [`DebugName`]: bevy::utils::prelude::DebugName
+pub mod vec_debug_name {
+    use bevy::utils::prelude::DebugName;
+    use serde::{Deserialize, Deserializer, Serializer, ser::SerializeSeq};
+
+    /// Serializes a `Vec<DebugName>` into a `Vec<String>`.
+    pub fn serialize<S>(debug_names: &[DebugName], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(debug_names.len()))?;
+        for debug_name in debug_names {
+            seq.serialize_element(&debug_name.to_string())?;
+        }
+        seq.end()
+    }
+
+    /// Deserializes a `Vec<String>` into a `Vec<DebugName>`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<DebugName>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let strings: Vec<String> = Vec::deserialize(deserializer)?;
+        Ok(strings.into_iter().map(DebugName::from).collect())
+    }
+}
+
 /// Serializes [`SpawnDetails`].
 pub fn serialize_spawn_details<S>(
     spawn_details: &SpawnDetails,